@@ -11,7 +11,7 @@ export class VoteController {
    */
   prepareCastVote = async (req: Request, res: Response): Promise<void> => {
     try {
-      const { electionId, votingToken, encryptedVote, proof, senderAddress } = req.body;
+      const { electionId, votingToken, tokenSignature, encryptedVote, proof, senderAddress } = req.body;
 
       logger.info('Preparing vote submission', {
         electionId,
@@ -19,7 +19,7 @@ export class VoteController {
       });
 
       // Vérifier d'abord que le token est valide
-      const isTokenValid = await multiversxService.isTokenValid(electionId, votingToken);
+      const isTokenValid = await multiversxService.isTokenValid(electionId, votingToken, tokenSignature);
 
       if (!isTokenValid) {
         res.status(400).json({
@@ -33,6 +33,7 @@ export class VoteController {
       const transaction = multiversxService.prepareCastVoteTransaction({
         electionId,
         votingToken,
+        tokenSignature,
         encryptedVote,
         proof,
         sender: senderAddress,
@@ -45,11 +45,11 @@ export class VoterController {
    */
   checkTokenValidity = async (req: Request, res: Response): Promise<void> => {
     try {
-      const { electionId, token } = req.body;
+      const { electionId, token, tokenSignature } = req.body;
 
       logger.info('Checking token validity', { electionId });
 
-      const isValid = await multiversxService.isTokenValid(electionId, token);
+      const isValid = await multiversxService.isTokenValid(electionId, token, tokenSignature);
 
       res.status(200).json({
         success: true,
@@ -428,9 +428,9 @@ export class MultiversXService {
   }
 
   /**
-   * Vérifier si un token de vote est valide
+   * Vérifier si un token de vote est valide (signature de l'autorité, non utilisé)
    */
-  async isTokenValid(electionId: number, token: string): Promise<boolean> {
+  async isTokenValid(electionId: number, token: string, tokenSignature: string): Promise<boolean> {
     try {
       const query = new SmartContractQuery({
         contract: this.voterRegistryAddress,
@@ -438,6 +438,7 @@ export class MultiversXService {
         arguments: [
           this.encodeU64(electionId),
           this.encodeHex(token),
+          this.encodeHex(tokenSignature),
         ],
       });
 
@@ -462,6 +463,7 @@ export class MultiversXService {
   async prepareCastVoteTransaction(params: {
     electionId: number;
     votingToken: string;
+    tokenSignature: string;
     encryptedVote: string;
     proof: string;
     sender: string;
@@ -488,6 +490,7 @@ export class MultiversXService {
           arguments: [
             new U64Value(params.electionId),
             BytesValue.fromHex(params.votingToken),
+            BytesValue.fromHex(params.tokenSignature),
             encryptedVoteStruct,
           ],
         }
@@ -689,46 +692,68 @@ export class MultiversXService {
   }
 
   /**
-   * Récupérer tous les votes chiffrés ElGamal d'une élection
+   * Interroger une vue paginée par curseur du contrat de vote.
+   * Chaque page retourne le curseur suivant (0 en fin de liste) puis les éléments,
+   * retournés ici en base64. Retourne null si la requête échoue.
    */
-  async getEncryptedVotes(electionId: number): Promise<Array<{
-    c1: string;
-    c2: string;
-    timestamp: number;
-  }>> {
-    try {
-      logger.info('Fetching encrypted votes from smart contract', { electionId });
-
-      // Use direct API call instead of SmartContractQuery to avoid SDK v15 bug
-      const gatewayUrl = process.env.MULTIVERSX_GATEWAY_URL || 'https://devnet-gateway.multiversx.com';
-      const contractAddress = this.votingContractAddress.toBech32();
+  private async queryAllPages(funcName: string, electionId: number): Promise<string[] | null> {
+    // Use direct API call instead of SmartContractQuery to avoid SDK v15 bug
+    const gatewayUrl = process.env.MULTIVERSX_GATEWAY_URL || 'https://devnet-gateway.multiversx.com';
+    const contractAddress = this.votingContractAddress.toBech32();
 
-      // Encode electionId as hex string for API (u64 = 8 bytes = 16 hex chars)
-      const electionIdHex = electionId.toString(16).padStart(16, '0');
+    // Encode electionId as hex string for API (u64 = 8 bytes = 16 hex chars)
+    const electionIdHex = electionId.toString(16).padStart(16, '0');
+    // Taille maximale d'une page côté contrat (MAX_PAGE_SIZE)
+    const pageSizeHex = (500).toString(16).padStart(16, '0');
 
-      const queryPayload = {
-        scAddress: contractAddress,
-        funcName: 'getEncryptedVotes',
-        args: [electionIdHex]
-      };
+    const items: string[] = [];
+    let cursor = 0;
 
+    do {
       const response = await fetch(`${gatewayUrl}/vm-values/query`, {
         method: 'POST',
         headers: { 'Content-Type': 'application/json' },
-        body: JSON.stringify(queryPayload)
+        body: JSON.stringify({
+          scAddress: contractAddress,
+          funcName,
+          args: [electionIdHex, cursor.toString(16).padStart(16, '0'), pageSizeHex]
+        })
       });
 
       const result: any = await response.json();
 
       // Check if query succeeded
       if (result.data?.data?.returnCode !== 'ok') {
-        logger.error('Query failed for getEncryptedVotes', { result });
-        return [];
+        logger.error(`Query failed for ${funcName}`, { result });
+        return null;
       }
 
-      // Get return data
-      const returnData = result.data?.data?.returnData;
-      if (!returnData || returnData.length === 0) {
+      const [nextCursor, ...page] = result.data?.data?.returnData || [];
+      items.push(...page);
+
+      const cursorHex = Buffer.from(nextCursor || '', 'base64').toString('hex');
+      cursor = cursorHex ? parseInt(cursorHex, 16) : 0;
+    } while (cursor !== 0);
+
+    return items;
+  }
+
+  /**
+   * Récupérer tous les votes chiffrés ElGamal d'une élection
+   */
+  async getEncryptedVotes(electionId: number): Promise<Array<{
+    c1: string;
+    c2: string;
+    timestamp: number;
+  }>> {
+    try {
+      logger.info('Fetching encrypted votes from smart contract', { electionId });
+
+      const returnData = await this.queryAllPages('getEncryptedVotes', electionId);
+      if (returnData === null) {
+        return [];
+      }
+      if (returnData.length === 0) {
         logger.info('No encrypted votes found for election', { electionId });
         return [];
       }
@@ -839,36 +864,11 @@ export class MultiversXService {
     try {
       logger.info('Fetching Option 2 encrypted votes with proof from smart contract', { electionId });
 
-      // Use direct API call instead of SmartContractQuery to avoid SDK v15 bug
-      const gatewayUrl = process.env.MULTIVERSX_GATEWAY_URL || 'https://devnet-gateway.multiversx.com';
-      const contractAddress = this.votingContractAddress.toBech32();
-
-      // Encode electionId as hex string for API (u64 = 8 bytes = 16 hex chars)
-      const electionIdHex = electionId.toString(16).padStart(16, '0');
-
-      const queryPayload = {
-        scAddress: contractAddress,
-        funcName: 'getEncryptedVotesWithProof',
-        args: [electionIdHex]
-      };
-
-      const response = await fetch(`${gatewayUrl}/vm-values/query`, {
-        method: 'POST',
-        headers: { 'Content-Type': 'application/json' },
-        body: JSON.stringify(queryPayload)
-      });
-
-      const result: any = await response.json();
-
-      // Check if query succeeded
-      if (result.data?.data?.returnCode !== 'ok') {
-        logger.error('Query failed for getEncryptedVotesWithProof', { result });
+      const returnData = await this.queryAllPages('getEncryptedVotesWithProof', electionId);
+      if (returnData === null) {
         return [];
       }
-
-      // Get return data
-      const returnData = result.data?.data?.returnData;
-      if (!returnData || returnData.length === 0) {
+      if (returnData.length === 0) {
         logger.info('No Option 2 encrypted votes found for election', { electionId });
         return [];
       }
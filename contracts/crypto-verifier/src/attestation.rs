@@ -0,0 +1,196 @@
+use core::marker::PhantomData;
+
+use multiversx_sc::{
+    api::VMApi,
+    contract_base::CryptoWrapper,
+    types::{ManagedAddress, ManagedBuffer},
+};
+
+use crate::ProofVerifier;
+
+/// Taille d'une signature Ed25519
+pub const ED25519_SIGNATURE_LEN: usize = 64;
+
+/// Taille d'un commitment de credential
+pub const CREDENTIAL_COMMITMENT_LEN: usize = 32;
+
+/// Vérificateur de production basé sur des attestations Ed25519
+///
+/// Ce vérificateur ne vérifie AUCUNE preuve : il vérifie uniquement la signature d'un
+/// attesteur de confiance. Les preuves (Groth16, preuves de chiffrement) sont vérifiées
+/// hors-chaîne par ce backend, qui signe le résultat ; un attesteur compromis peut donc
+/// faire accepter n'importe quelle donnée. Chaque preuve transmise au contrat se termine
+/// par cette signature de 64 bytes :
+///
+/// - éligibilité : `commitment (32) || sig(sha256(commitment || binding))`
+/// - zk-SNARK : `proof || sig(sha256(proof || public_inputs))`
+/// - vote chiffré : `validity_proof || sig(sha256(encrypted_vote || validity_proof))`
+///
+/// Une signature mal formée fait échouer la transaction (`verify_ed25519`).
+pub struct AttestationVerifier<A: VMApi> {
+    attestor: ManagedAddress<A>,
+    _phantom: PhantomData<A>,
+}
+
+impl<A: VMApi> AttestationVerifier<A> {
+    /// Sépare une preuve en `(contenu, signature)`
+    fn split_signature(&self, proof: &ManagedBuffer<A>) -> Option<(ManagedBuffer<A>, ManagedBuffer<A>)> {
+        let len = proof.len();
+        if len <= ED25519_SIGNATURE_LEN {
+            return None;
+        }
+
+        let body_len = len - ED25519_SIGNATURE_LEN;
+        let body = proof.copy_slice(0, body_len)?;
+        let signature = proof.copy_slice(body_len, ED25519_SIGNATURE_LEN)?;
+        Some((body, signature))
+    }
+
+    fn verify_attestation(&self, message: &ManagedBuffer<A>, signature: &ManagedBuffer<A>) -> bool {
+        if self.attestor.is_zero() {
+            return false;
+        }
+
+        CryptoWrapper::<A>::new().verify_ed25519(self.attestor.as_managed_buffer(), message, signature);
+        true
+    }
+
+    fn digest(&self, first: &ManagedBuffer<A>, second: &ManagedBuffer<A>) -> ManagedBuffer<A> {
+        let mut data = first.clone();
+        data.append(second);
+        CryptoWrapper::<A>::new().sha256(&data).as_managed_buffer().clone()
+    }
+}
+
+impl<A: VMApi> ProofVerifier<A> for AttestationVerifier<A> {
+    fn new(attestor: ManagedAddress<A>) -> Self {
+        AttestationVerifier {
+            attestor,
+            _phantom: PhantomData,
+        }
+    }
+
+    fn verify_voter_eligibility(&self, credential_proof: &ManagedBuffer<A>, binding: &ManagedBuffer<A>) -> bool {
+        if credential_proof.len() != CREDENTIAL_COMMITMENT_LEN + ED25519_SIGNATURE_LEN {
+            return false;
+        }
+
+        match self.split_signature(credential_proof) {
+            Some((commitment, signature)) => {
+                let message = self.digest(&commitment, binding);
+                self.verify_attestation(&message, &signature)
+            },
+            None => false,
+        }
+    }
+
+    fn verify_zk_snark_proof(&self, proof: &ManagedBuffer<A>, public_inputs: &ManagedBuffer<A>) -> bool {
+        match self.split_signature(proof) {
+            Some((snark_proof, signature)) => {
+                let message = self.digest(&snark_proof, public_inputs);
+                self.verify_attestation(&message, &signature)
+            },
+            None => false,
+        }
+    }
+
+    fn verify_encrypted_vote(&self, encrypted_vote: &ManagedBuffer<A>, proof: &ManagedBuffer<A>) -> bool {
+        if encrypted_vote.is_empty() {
+            return false;
+        }
+
+        match self.split_signature(proof) {
+            Some((validity_proof, signature)) => {
+                let message = self.digest(encrypted_vote, &validity_proof);
+                self.verify_attestation(&message, &signature)
+            },
+            None => false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use multiversx_sc_scenario::api::StaticApi;
+
+    // Clé de test 1 de la RFC 8032 (Ed25519)
+    const ATTESTOR_PUBLIC_KEY: &str = "d75a980182b10ab7d54bfed3c964073a0ee172f3daa62325af021a68f707511a";
+
+    // Signatures de la clé de test sur les messages attendus par le vérificateur
+    const ELIGIBILITY_SIGNATURE: &str = "ea58374d8a04c5cfdc17a79fbaa8903df34131d98be8297c685fb84a574d0a2e57fbeb37a30269342db2ef97f2a8e149c47a7c54ef1b7ab040798a403a95190d";
+    const ZK_SNARK_SIGNATURE: &str = "5eae2e2bb4cde3d32bab5996507372b08103e3068468e41e6d714b16a61ae27dc0eed4124f058a342377c4860e32cb20699ab73a7bceba7ceb4cadb020cc2506";
+    const ENCRYPTED_VOTE_SIGNATURE: &str = "788bc212d5bcab519a5e1cd089f2b44dbccf57beb5050917c12e81f726ba588facdf0ee7ea873a97afbd1622a7f5ffa91eb86eeed5bc62c42a91727ef8027b0d";
+
+    fn verifier() -> AttestationVerifier<StaticApi> {
+        AttestationVerifier::new(ManagedAddress::zero())
+    }
+
+    fn configured_verifier() -> AttestationVerifier<StaticApi> {
+        AttestationVerifier::new(ManagedAddress::from(from_hex::<32>(ATTESTOR_PUBLIC_KEY)))
+    }
+
+    fn from_hex<const N: usize>(hex: &str) -> [u8; N] {
+        let mut bytes = [0u8; N];
+        for (i, byte) in bytes.iter_mut().enumerate() {
+            *byte = u8::from_str_radix(&hex[2 * i..2 * i + 2], 16).unwrap();
+        }
+        bytes
+    }
+
+    /// `body || signature`, au format attendu par le vérificateur
+    fn attested(body: &[u8], signature: &str) -> ManagedBuffer<StaticApi> {
+        let mut proof = ManagedBuffer::<StaticApi>::from(body);
+        proof.append_bytes(&from_hex::<ED25519_SIGNATURE_LEN>(signature));
+        proof
+    }
+
+    #[test]
+    fn test_verify_voter_eligibility_with_attestation() {
+        let credential = attested(&[0x11; CREDENTIAL_COMMITMENT_LEN], ELIGIBILITY_SIGNATURE);
+        let binding = ManagedBuffer::<StaticApi>::from(b"blinded_token");
+
+        assert!(configured_verifier().verify_voter_eligibility(&credential, &binding));
+    }
+
+    #[test]
+    fn test_verify_zk_snark_proof_with_attestation() {
+        let proof = attested(b"groth16_proof", ZK_SNARK_SIGNATURE);
+        let public_inputs = ManagedBuffer::<StaticApi>::from(b"public_inputs");
+
+        assert!(configured_verifier().verify_zk_snark_proof(&proof, &public_inputs));
+    }
+
+    #[test]
+    fn test_verify_encrypted_vote_with_attestation() {
+        let encrypted = ManagedBuffer::<StaticApi>::from(b"encrypted_vote");
+        let proof = attested(b"validity_proof", ENCRYPTED_VOTE_SIGNATURE);
+
+        assert!(configured_verifier().verify_encrypted_vote(&encrypted, &proof));
+    }
+
+    #[test]
+    fn test_verify_voter_eligibility_wrong_length() {
+        let credential = ManagedBuffer::<StaticApi>::from(b"12345678901234567890123456789012");
+        let binding = ManagedBuffer::<StaticApi>::from(b"blinded_token");
+
+        assert!(!verifier().verify_voter_eligibility(&credential, &binding));
+    }
+
+    #[test]
+    fn test_verify_zk_snark_proof_without_attestation() {
+        let proof = ManagedBuffer::<StaticApi>::from(b"valid_proof_data");
+        let public_inputs = ManagedBuffer::<StaticApi>::from(b"public_inputs");
+
+        assert!(!verifier().verify_zk_snark_proof(&proof, &public_inputs));
+    }
+
+    #[test]
+    fn test_unconfigured_attestor_rejects_everything() {
+        let encrypted = ManagedBuffer::<StaticApi>::from(b"encrypted_data");
+        let proof = ManagedBuffer::<StaticApi>::from(&[1u8; 96][..]);
+
+        assert!(!verifier().verify_encrypted_vote(&encrypted, &proof));
+        assert!(!verifier().verify_voter_eligibility(&proof, &encrypted));
+    }
+}
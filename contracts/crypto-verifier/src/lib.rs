@@ -0,0 +1,61 @@
+#![no_std]
+
+//! Vérification cryptographique partagée par les contrats DEMOCRATIX
+//!
+//! Les contrats n'appellent jamais une implémentation directement : ils passent par
+//! le trait `ProofVerifier` et l'alias `DefaultVerifier`.
+//!
+//! - `AttestationVerifier` : implémentation de production. Elle ne vérifie pas les
+//!   preuves elles-mêmes, seulement la signature Ed25519 d'un attesteur de confiance
+//!   (backend) qui les a vérifiées hors-chaîne
+//! - `MockVerifier` : implémentation de test, compilée uniquement avec la feature `mock`
+
+#[cfg(all(feature = "mock", target_arch = "wasm32"))]
+compile_error!("La feature `mock` de crypto-verifier est réservée aux tests et ne peut pas être compilée en wasm");
+
+mod attestation;
+#[cfg(feature = "mock")]
+mod mock;
+
+pub use attestation::{AttestationVerifier, ED25519_SIGNATURE_LEN};
+#[cfg(feature = "mock")]
+pub use mock::MockVerifier;
+
+use multiversx_sc::{api::VMApi, types::{ManagedAddress, ManagedBuffer}};
+
+/// Vérificateur utilisé par les contrats
+#[cfg(not(feature = "mock"))]
+pub type DefaultVerifier<A> = AttestationVerifier<A>;
+
+/// Vérificateur utilisé par les contrats (tests uniquement)
+#[cfg(feature = "mock")]
+pub type DefaultVerifier<A> = MockVerifier<A>;
+
+/// Interface commune des vérificateurs de preuves
+pub trait ProofVerifier<A: VMApi> {
+    /// Construit le vérificateur à partir de l'adresse (clé publique Ed25519) du
+    /// vérificateur de confiance
+    fn new(attestor: ManagedAddress<A>) -> Self;
+
+    /// Vérifie une preuve d'éligibilité d'électeur
+    ///
+    /// # Arguments
+    /// * `credential_proof` - Preuve d'identité de l'électeur
+    /// * `binding` - Données liées à l'attestation (ex : token aveuglé), qu'un tiers ne
+    ///   peut pas remplacer en rejouant la preuve
+    fn verify_voter_eligibility(&self, credential_proof: &ManagedBuffer<A>, binding: &ManagedBuffer<A>) -> bool;
+
+    /// Vérifie une preuve zk-SNARK
+    ///
+    /// # Arguments
+    /// * `proof` - La preuve zk-SNARK à vérifier
+    /// * `public_inputs` - Les entrées publiques du circuit
+    fn verify_zk_snark_proof(&self, proof: &ManagedBuffer<A>, public_inputs: &ManagedBuffer<A>) -> bool;
+
+    /// Vérifie qu'un vote chiffré est bien formé
+    ///
+    /// # Arguments
+    /// * `encrypted_vote` - Vote chiffré
+    /// * `proof` - Preuve que le vote est valide
+    fn verify_encrypted_vote(&self, encrypted_vote: &ManagedBuffer<A>, proof: &ManagedBuffer<A>) -> bool;
+}
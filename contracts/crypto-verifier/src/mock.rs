@@ -0,0 +1,104 @@
+use core::marker::PhantomData;
+
+use multiversx_sc::{
+    api::VMApi,
+    types::{ManagedAddress, ManagedBuffer},
+};
+
+use crate::ProofVerifier;
+
+/// Vérificateur MOCK pour les tests
+///
+/// AVERTISSEMENT: compilé uniquement avec la feature `mock`, qui est refusée pour la
+/// cible wasm. Ne vérifie que la forme des données.
+pub struct MockVerifier<A: VMApi> {
+    _phantom: PhantomData<A>,
+}
+
+impl<A: VMApi> ProofVerifier<A> for MockVerifier<A> {
+    fn new(_attestor: ManagedAddress<A>) -> Self {
+        MockVerifier {
+            _phantom: PhantomData,
+        }
+    }
+
+    fn verify_voter_eligibility(&self, credential_proof: &ManagedBuffer<A>, _binding: &ManagedBuffer<A>) -> bool {
+        // MOCK: Accepte toute preuve d'au moins 32 bytes
+        credential_proof.len() >= 32
+    }
+
+    fn verify_zk_snark_proof(&self, proof: &ManagedBuffer<A>, _public_inputs: &ManagedBuffer<A>) -> bool {
+        // MOCK: Accepte toute preuve non-vide
+        !proof.is_empty()
+    }
+
+    fn verify_encrypted_vote(&self, encrypted_vote: &ManagedBuffer<A>, proof: &ManagedBuffer<A>) -> bool {
+        // MOCK: Vérifie juste que les données ne sont pas vides
+        !encrypted_vote.is_empty() && !proof.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use multiversx_sc_scenario::api::StaticApi;
+
+    fn verifier() -> MockVerifier<StaticApi> {
+        MockVerifier::new(ManagedAddress::zero())
+    }
+
+    #[test]
+    fn test_verify_zk_snark_proof_valid() {
+        let proof = ManagedBuffer::<StaticApi>::from(b"valid_proof_data");
+        let public_inputs = ManagedBuffer::<StaticApi>::from(b"public_inputs");
+
+        assert!(verifier().verify_zk_snark_proof(&proof, &public_inputs));
+    }
+
+    #[test]
+    fn test_verify_zk_snark_proof_empty() {
+        let proof = ManagedBuffer::<StaticApi>::new();
+        let public_inputs = ManagedBuffer::<StaticApi>::from(b"public_inputs");
+
+        assert!(!verifier().verify_zk_snark_proof(&proof, &public_inputs));
+    }
+
+    #[test]
+    fn test_verify_voter_eligibility_valid() {
+        // 32+ bytes credential
+        let credential = ManagedBuffer::<StaticApi>::from(b"12345678901234567890123456789012");
+
+        assert!(verifier().verify_voter_eligibility(&credential, &ManagedBuffer::new()));
+    }
+
+    #[test]
+    fn test_verify_voter_eligibility_invalid() {
+        // Less than 32 bytes
+        let credential = ManagedBuffer::<StaticApi>::from(b"short");
+
+        assert!(!verifier().verify_voter_eligibility(&credential, &ManagedBuffer::new()));
+    }
+
+    #[test]
+    fn test_verify_voter_eligibility_empty() {
+        let credential = ManagedBuffer::<StaticApi>::new();
+
+        assert!(!verifier().verify_voter_eligibility(&credential, &ManagedBuffer::new()));
+    }
+
+    #[test]
+    fn test_verify_encrypted_vote_valid() {
+        let encrypted = ManagedBuffer::<StaticApi>::from(b"encrypted_data");
+        let proof = ManagedBuffer::<StaticApi>::from(b"proof_data");
+
+        assert!(verifier().verify_encrypted_vote(&encrypted, &proof));
+    }
+
+    #[test]
+    fn test_verify_encrypted_vote_invalid() {
+        let encrypted = ManagedBuffer::<StaticApi>::new();
+        let proof = ManagedBuffer::<StaticApi>::from(b"proof_data");
+
+        assert!(!verifier().verify_encrypted_vote(&encrypted, &proof));
+    }
+}
@@ -3,6 +3,8 @@
 multiversx_sc::imports!();
 multiversx_sc::derive_imports!();
 
+use democratix_common::is_valid_ipfs_cid;
+
 // ==================== TYPES ====================
 
 /// Type de proposition de DAO politique
@@ -117,10 +119,18 @@ pub struct Primary<M: ManagedTypeApi> {
     pub created_at: u64,
 }
 
-/// Candidat à une primaire
+/// Données de l'événement `dao_created` (fondateur puis nom, comme à l'origine)
 #[type_abi]
 #[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone)]
-pub struct Candidate<M: ManagedTypeApi> {
+pub struct DaoCreatedData<M: ManagedTypeApi> {
+    pub founder: ManagedAddress<M>,
+    pub name: ManagedBuffer<M>,
+}
+
+/// Candidat à une primaire
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, ManagedVecItem, Clone)]
+pub struct PrimaryCandidate<M: ManagedTypeApi> {
     pub address: ManagedAddress<M>,
     pub primary_id: u64,
     pub name: ManagedBuffer<M>,
@@ -184,7 +194,7 @@ pub trait DaoPoliticalContract {
     fn primaries(&self) -> MapMapper<u64, Primary<Self::Api>>;
 
     #[storage_mapper("candidates")]
-    fn candidates(&self, primary_id: u64) -> VecMapper<Candidate<Self::Api>>;
+    fn candidates(&self, primary_id: u64) -> VecMapper<PrimaryCandidate<Self::Api>>;
 
     #[storage_mapper("primary_votes")]
     fn primary_votes(&self, primary_id: u64) -> MapMapper<ManagedAddress, PrimaryVote<Self::Api>>;
@@ -216,8 +226,8 @@ pub trait DaoPoliticalContract {
         membership_fee: BigUint,
     ) -> u64 {
         require!(name.len() > 0, "Name cannot be empty");
-        require!(charter_ipfs.len() == 46, "Invalid charter IPFS CID");
-        require!(program_ipfs.len() == 46, "Invalid program IPFS CID");
+        require!(is_valid_ipfs_cid(&charter_ipfs), "Invalid charter IPFS CID");
+        require!(is_valid_ipfs_cid(&program_ipfs), "Invalid program IPFS CID");
         require!(voting_threshold > 50 && voting_threshold <= 100, "Threshold must be 51-100%");
 
         let caller = self.blockchain().get_caller();
@@ -259,7 +269,7 @@ pub trait DaoPoliticalContract {
         self.next_dao_id().set(dao_id + 1);
 
         // Emit event
-        self.dao_created_event(dao_id, caller, name);
+        self.dao_created_event(dao_id, DaoCreatedData { founder: caller, name });
 
         dao_id
     }
@@ -311,7 +321,7 @@ pub trait DaoPoliticalContract {
     ) -> u64 {
         require!(self.daos().contains_key(&dao_id), "DAO not found");
         require!(title.len() > 0, "Title cannot be empty");
-        require!(description_ipfs.len() == 46, "Invalid IPFS CID");
+        require!(is_valid_ipfs_cid(&description_ipfs), "Invalid IPFS CID");
         require!(voting_duration_days >= 7 && voting_duration_days <= 60, "Duration must be 7-60 days");
 
         let caller = self.blockchain().get_caller();
@@ -384,11 +394,12 @@ pub trait DaoPoliticalContract {
         }
 
         self.proposal_votes(proposal_id, &caller).set(true);
+        let dao_id = proposal.dao_id;
         self.proposals().insert(proposal_id, proposal);
 
-        let mut member = self.members(proposal.dao_id).get(&caller).unwrap();
+        let mut member = self.members(dao_id).get(&caller).unwrap();
         member.votes_cast += 1;
-        self.members(proposal.dao_id).insert(caller.clone(), member);
+        self.members(dao_id).insert(caller.clone(), member);
 
         self.vote_cast_on_proposal_event(proposal_id, caller);
     }
@@ -482,8 +493,8 @@ pub trait DaoPoliticalContract {
         biography_ipfs: ManagedBuffer,
     ) {
         require!(self.primaries().contains_key(&primary_id), "Primary not found");
-        require!(program_ipfs.len() == 46, "Invalid program IPFS CID");
-        require!(biography_ipfs.len() == 46, "Invalid biography IPFS CID");
+        require!(is_valid_ipfs_cid(&program_ipfs), "Invalid program IPFS CID");
+        require!(is_valid_ipfs_cid(&biography_ipfs), "Invalid biography IPFS CID");
 
         let mut primary = self.primaries().get(&primary_id).unwrap();
         require!(primary.status == PrimaryStatus::Open, "Registration closed");
@@ -494,7 +505,7 @@ pub trait DaoPoliticalContract {
         let current_timestamp = self.blockchain().get_block_timestamp();
         require!(current_timestamp < primary.registration_deadline, "Registration period ended");
 
-        let candidate = Candidate {
+        let candidate = PrimaryCandidate {
             address: caller.clone(),
             primary_id,
             name: name.clone(),
@@ -588,8 +599,8 @@ pub trait DaoPoliticalContract {
     }
 
     #[view(getPrimaryCandidates)]
-    fn get_primary_candidates(&self, primary_id: u64) -> ManagedVec<Candidate<Self::Api>> {
-        self.candidates(primary_id).load_as_vec()
+    fn get_primary_candidates(&self, primary_id: u64) -> ManagedVec<PrimaryCandidate<Self::Api>> {
+        self.candidates(primary_id).iter().collect()
     }
 
     // ==================== EVENTS ====================
@@ -598,8 +609,7 @@ pub trait DaoPoliticalContract {
     fn dao_created_event(
         &self,
         #[indexed] dao_id: u64,
-        founder: ManagedAddress,
-        name: ManagedBuffer,
+        data: DaoCreatedData<Self::Api>,
     );
 
     #[event("member_joined")]
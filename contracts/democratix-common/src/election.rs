@@ -0,0 +1,74 @@
+use multiversx_sc::{api::ManagedTypeApi, derive_imports::*, types::ManagedBuffer};
+
+/// Structure représentant un candidat
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, Debug)]
+pub struct Candidate<M: ManagedTypeApi> {
+    pub id: u32,
+    pub name: ManagedBuffer<M>,
+    pub description_ipfs: ManagedBuffer<M>,
+}
+
+/// Structure pour les résultats finaux d'un candidat
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, ManagedVecItem, Clone, Debug)]
+pub struct CandidateResult {
+    pub candidate_id: u32,
+    pub vote_count: u64,
+}
+
+/// Résultat publié pour un candidat, avec son pourcentage
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, Debug)]
+pub struct PublishedCandidateResult {
+    pub candidate_id: u32,
+    pub vote_count: u64,
+    pub percentage: u64, // Pourcentage * 100 (ex: 4523 = 45.23%)
+}
+
+impl CandidateResult {
+    /// Convertit en résultat publié, pourcentage calculé sur `total_votes`
+    pub fn to_published(&self, total_votes: u64) -> PublishedCandidateResult {
+        PublishedCandidateResult {
+            candidate_id: self.candidate_id,
+            vote_count: self.vote_count,
+            percentage: percentage_bps(self.vote_count, total_votes),
+        }
+    }
+}
+
+impl From<&PublishedCandidateResult> for CandidateResult {
+    fn from(result: &PublishedCandidateResult) -> Self {
+        CandidateResult {
+            candidate_id: result.candidate_id,
+            vote_count: result.vote_count,
+        }
+    }
+}
+
+/// Pourcentage en points de base (`4523` = 45.23%), `0` si aucun vote
+pub fn percentage_bps(vote_count: u64, total_votes: u64) -> u64 {
+    if total_votes == 0 {
+        return 0;
+    }
+
+    vote_count * 10_000 / total_votes
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_to_published() {
+        let result = CandidateResult {
+            candidate_id: 1,
+            vote_count: 45,
+        };
+        let published = result.to_published(200);
+
+        assert_eq!(published.percentage, 2250);
+        assert_eq!(CandidateResult::from(&published).vote_count, 45);
+        assert_eq!(percentage_bps(3, 0), 0);
+    }
+}
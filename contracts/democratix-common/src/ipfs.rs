@@ -0,0 +1,25 @@
+use multiversx_sc::{api::ManagedTypeApi, types::ManagedBuffer};
+
+/// Longueur d'un CID IPFS v0 (base58, préfixe `Qm`)
+pub const IPFS_CID_LEN: usize = 46;
+
+/// Vérifie qu'un hash IPFS a la forme d'un CID v0
+pub fn is_valid_ipfs_cid<M: ManagedTypeApi>(cid: &ManagedBuffer<M>) -> bool {
+    cid.len() == IPFS_CID_LEN
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use multiversx_sc_scenario::api::StaticApi;
+
+    #[test]
+    fn test_is_valid_ipfs_cid() {
+        let cid = ManagedBuffer::<StaticApi>::from(b"QmYwAPJzv5CZsnA625s3Xf2nemtYgPpHdWEz79ojWnPbdG");
+        let short = ManagedBuffer::<StaticApi>::from(b"QmShort");
+
+        assert!(is_valid_ipfs_cid(&cid));
+        assert!(!is_valid_ipfs_cid(&short));
+        assert!(!is_valid_ipfs_cid(&ManagedBuffer::<StaticApi>::new()));
+    }
+}
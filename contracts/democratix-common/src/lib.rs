@@ -0,0 +1,16 @@
+#![no_std]
+
+//! Types et validateurs partagés par les contrats DEMOCRATIX
+//!
+//! Les encodages on-chain de ces types sont ceux des contrats d'origine : les migrer
+//! vers ce crate ne change pas le stockage existant.
+
+mod election;
+mod ipfs;
+mod ric;
+mod territory;
+
+pub use election::{percentage_bps, Candidate, CandidateResult, PublishedCandidateResult};
+pub use ipfs::{is_valid_ipfs_cid, IPFS_CID_LEN};
+pub use ric::RICType;
+pub use territory::{NumericTerritorialScope, TerritorialScope};
@@ -0,0 +1,14 @@
+use multiversx_sc::derive_imports::*;
+
+/// Type de référendum d'initiative citoyenne
+///
+/// Encodage historique du contrat RIC ; partagé avec les pétitions qui demandent
+/// leur conversion en RIC.
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, PartialEq, Clone, Debug)]
+pub enum RICType {
+    Legislatif,       // Nouvelle loi
+    Abrogatoire,      // Suppression loi existante
+    Revocatoire,      // Destitution élu
+    Constitutionnel,  // Modification constitution
+}
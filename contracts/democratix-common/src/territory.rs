@@ -0,0 +1,140 @@
+use multiversx_sc::{api::ManagedTypeApi, derive_imports::*, types::ManagedBuffer};
+
+/// Nombre de chiffres d'un code INSEE de commune
+const COMMUNE_CODE_DIGITS: usize = 5;
+
+/// Nombre minimal de chiffres d'un code de département ou de région
+const DEPARTMENT_CODE_DIGITS: usize = 2;
+const REGION_CODE_DIGITS: usize = 2;
+
+/// Niveau territorial (codes INSEE)
+///
+/// Type canonique : les codes sont des chaînes car certains ne sont pas numériques
+/// (ex: départements `2A` et `2B`).
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, PartialEq, Debug)]
+pub enum TerritorialScope<M: ManagedTypeApi> {
+    Commune { insee_code: ManagedBuffer<M> },
+    Department { code: ManagedBuffer<M> },
+    Region { code: ManagedBuffer<M> },
+    National,
+}
+
+/// Niveau territorial à identifiants numériques
+///
+/// Encodage historique du contrat RIC, conservé pour la compatibilité du stockage.
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, PartialEq, Clone, Debug)]
+pub enum NumericTerritorialScope {
+    National,
+    Regional { region_id: u32 },
+    Departmental { department_id: u32 },
+    Municipal { city_code: u32 },
+}
+
+impl<M: ManagedTypeApi> TerritorialScope<M> {
+    /// Convertit vers l'encodage numérique, `None` si un code n'est pas numérique
+    pub fn to_numeric(&self) -> Option<NumericTerritorialScope> {
+        match self {
+            TerritorialScope::National => Some(NumericTerritorialScope::National),
+            TerritorialScope::Region { code } => Some(NumericTerritorialScope::Regional {
+                region_id: parse_code(code)?,
+            }),
+            TerritorialScope::Department { code } => Some(NumericTerritorialScope::Departmental {
+                department_id: parse_code(code)?,
+            }),
+            TerritorialScope::Commune { insee_code } => Some(NumericTerritorialScope::Municipal {
+                city_code: parse_code(insee_code)?,
+            }),
+        }
+    }
+}
+
+impl NumericTerritorialScope {
+    /// Convertit vers le type canonique (codes complétés par des zéros : `1` -> `01`)
+    pub fn to_territorial_scope<M: ManagedTypeApi>(&self) -> TerritorialScope<M> {
+        match self {
+            NumericTerritorialScope::National => TerritorialScope::National,
+            NumericTerritorialScope::Regional { region_id } => TerritorialScope::Region {
+                code: format_code(*region_id, REGION_CODE_DIGITS),
+            },
+            NumericTerritorialScope::Departmental { department_id } => TerritorialScope::Department {
+                code: format_code(*department_id, DEPARTMENT_CODE_DIGITS),
+            },
+            NumericTerritorialScope::Municipal { city_code } => TerritorialScope::Commune {
+                insee_code: format_code(*city_code, COMMUNE_CODE_DIGITS),
+            },
+        }
+    }
+}
+
+fn parse_code<M: ManagedTypeApi>(code: &ManagedBuffer<M>) -> Option<u32> {
+    let mut buffer = [0u8; 10];
+    let len = code.len();
+    if len == 0 || len > buffer.len() {
+        return None;
+    }
+
+    let bytes = code.load_to_byte_array(&mut buffer);
+    let mut value = 0u32;
+    for byte in bytes {
+        if !byte.is_ascii_digit() {
+            return None;
+        }
+        value = value.checked_mul(10)?.checked_add((byte - b'0') as u32)?;
+    }
+
+    Some(value)
+}
+
+fn format_code<M: ManagedTypeApi>(value: u32, min_digits: usize) -> ManagedBuffer<M> {
+    let mut digits = [b'0'; 10];
+    let mut start = digits.len();
+    let mut remaining = value;
+    loop {
+        start -= 1;
+        digits[start] = b'0' + (remaining % 10) as u8;
+        remaining /= 10;
+        if remaining == 0 {
+            break;
+        }
+    }
+
+    let start = start.min(digits.len() - min_digits);
+    ManagedBuffer::new_from_bytes(&digits[start..])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use multiversx_sc_scenario::api::StaticApi;
+
+    #[test]
+    fn test_numeric_to_canonical() {
+        let scope: TerritorialScope<StaticApi> =
+            NumericTerritorialScope::Departmental { department_id: 1 }.to_territorial_scope();
+
+        assert_eq!(
+            scope,
+            TerritorialScope::Department {
+                code: ManagedBuffer::from(b"01")
+            }
+        );
+    }
+
+    #[test]
+    fn test_canonical_to_numeric() {
+        let commune = TerritorialScope::<StaticApi>::Commune {
+            insee_code: ManagedBuffer::from(b"75056"),
+        };
+        let corsica = TerritorialScope::<StaticApi>::Department {
+            code: ManagedBuffer::from(b"2A"),
+        };
+
+        assert_eq!(
+            commune.to_numeric(),
+            Some(NumericTerritorialScope::Municipal { city_code: 75056 })
+        );
+        assert_eq!(corsica.to_numeric(), None);
+    }
+}
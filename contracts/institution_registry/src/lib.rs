@@ -3,6 +3,8 @@
 multiversx_sc::imports!();
 multiversx_sc::derive_imports!();
 
+use democratix_common::TerritorialScope;
+
 /// Niveau institutionnel dans la hiérarchie territoriale
 #[type_abi]
 #[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, PartialEq, Debug)]
@@ -147,12 +149,14 @@ pub trait InstitutionRegistryContract {
         latitude: ManagedBuffer,
         longitude: ManagedBuffer,
     ) -> u64 {
-        require!(insee_code.len() > 0, "INSEE code required");
+        require!(!insee_code.is_empty(), "INSEE code required");
         require!(siren.len() == 9, "SIREN must be 9 digits");
-        require!(name.len() > 0, "Name required");
+        require!(!name.is_empty(), "Name required");
         require!(population > 0, "Population must be > 0");
+        // Les codes départements et régions se recoupent (ex: "84"),
+        // l'unicité est donc vérifiée par niveau
         require!(
-            !self.institutions_by_insee().contains_key(&insee_code),
+            self.institution_id_for(&level, &insee_code) == 0,
             "INSEE code already registered"
         );
 
@@ -198,7 +202,10 @@ pub trait InstitutionRegistryContract {
 
         // Sauvegarder
         self.institutions().insert(institution_id, institution);
-        self.institutions_by_insee().insert(insee_code.clone(), institution_id);
+        if !self.institutions_by_insee().contains_key(&insee_code) {
+            self.institutions_by_insee().insert(insee_code.clone(), institution_id);
+        }
+        self.institutions_by_territory(&level, &insee_code).set(institution_id);
         self.institutions_by_slug().insert(slug, institution_id);
         self.next_institution_id().set(institution_id + 1);
 
@@ -229,6 +236,25 @@ pub trait InstitutionRegistryContract {
         institution_id
     }
 
+    /// Retrouver l'institution d'un niveau par son code (0 si inconnue)
+    fn institution_id_for(&self, level: &InstitutionLevel, code: &ManagedBuffer) -> u64 {
+        let indexed_id = self.institutions_by_territory(level, code).get();
+        if indexed_id != 0 {
+            return indexed_id;
+        }
+
+        // Institutions enregistrées avant l'index par niveau
+        if let Some(id) = self.institutions_by_insee().get(code) {
+            if let Some(institution) = self.institutions().get(&id) {
+                if &institution.level == level {
+                    return id;
+                }
+            }
+        }
+
+        0
+    }
+
     /// Calculer catégorie de commune selon population
     fn calculate_commune_category(&self, population: u64) -> CommuneCategory {
         if population < 500 {
@@ -496,6 +522,66 @@ pub trait InstitutionRegistryContract {
         self.institutions().get(&id).unwrap()
     }
 
+    /// Obtenir l'ID de l'institution compétente pour un périmètre territorial
+    ///
+    /// Commune → code INSEE, département → code département, région → code région.
+    /// Retourne 0 si aucune institution n'est enregistrée ou pour le périmètre national.
+    #[view(getInstitutionIdByScope)]
+    fn get_institution_id_by_scope(&self, scope: TerritorialScope<Self::Api>) -> u64 {
+        match scope {
+            TerritorialScope::Commune { insee_code } => {
+                self.institution_id_for(&InstitutionLevel::Commune, &insee_code)
+            },
+            TerritorialScope::Department { code } => {
+                self.institution_id_for(&InstitutionLevel::Department, &code)
+            },
+            TerritorialScope::Region { code } => {
+                self.institution_id_for(&InstitutionLevel::Region, &code)
+            },
+            TerritorialScope::National => 0,
+        }
+    }
+
+    /// Obtenir les données de seuil de pétition d'une institution
+    ///
+    /// # Returns
+    /// (population, électeurs inscrits, % de seuil pétition)
+    #[view(getPetitionThresholdData)]
+    fn get_petition_threshold_data(&self, institution_id: u64) -> MultiValue3<u64, u64, u32> {
+        require!(
+            self.institutions().contains_key(&institution_id),
+            "Institution not found"
+        );
+        let institution = self.institutions().get(&institution_id).unwrap();
+
+        MultiValue3::from((
+            institution.population,
+            institution.electorate,
+            institution.petition_threshold_percentage,
+        ))
+    }
+
+    /// Obtenir les paramètres d'escalade d'une pétition restée sans réponse
+    ///
+    /// # Returns
+    /// (RIC activé, code du département parent)
+    #[view(getEscalationSettings)]
+    fn get_escalation_settings(&self, institution_id: u64) -> MultiValue2<bool, ManagedBuffer> {
+        require!(
+            self.institutions().contains_key(&institution_id),
+            "Institution not found"
+        );
+        let institution = self.institutions().get(&institution_id).unwrap();
+
+        MultiValue2::from((institution.ric_enabled, institution.parent_department_code))
+    }
+
+    /// Vérifier si une adresse administre une institution
+    #[view(isInstitutionAdmin)]
+    fn is_institution_admin(&self, institution_id: u64, address: ManagedAddress) -> bool {
+        self.institution_admins(institution_id).contains(&address)
+    }
+
     /// Obtenir institution par slug
     #[view(getInstitutionBySlug)]
     fn get_institution_by_slug(&self, slug: ManagedBuffer) -> Institution<Self::Api> {
@@ -587,6 +673,13 @@ pub trait InstitutionRegistryContract {
     #[storage_mapper("institutionsByInsee")]
     fn institutions_by_insee(&self) -> MapMapper<ManagedBuffer, u64>;
 
+    #[storage_mapper("institutionsByTerritory")]
+    fn institutions_by_territory(
+        &self,
+        level: &InstitutionLevel,
+        code: &ManagedBuffer,
+    ) -> SingleValueMapper<u64>;
+
     #[storage_mapper("institutionsBySlug")]
     fn institutions_by_slug(&self) -> MapMapper<ManagedBuffer, u64>;
 
@@ -3,16 +3,32 @@
 multiversx_sc::imports!();
 multiversx_sc::derive_imports!();
 
+pub use democratix_common::{RICType, TerritorialScope};
+use democratix_common::is_valid_ipfs_cid;
+use crypto_verifier::{DefaultVerifier, ProofVerifier};
+
+/// Délai de réponse de l'institution après l'atteinte du seuil (30 jours)
+const RESPONSE_DELAY: u64 = 30 * 86400;
+
+/// Paliers nationaux dans l'ordre où ils sont atteints
+const NATIONAL_TIERS: [NationalTier; 3] = [
+    NationalTier::Debate,
+    NationalTier::Inquiry,
+    NationalTier::Ric,
+];
+
 /// Types de pétitions selon le niveau territorial
 #[type_abi]
 #[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, PartialEq, Debug)]
 pub enum PetitionType {
-    Local,          // Commune (5% habitants)
-    Departmental,   // Département (2% habitants)
+    Local,          // Commune (seuil de l'institution, 5% par défaut)
+    Departmental,   // Département (seuil de l'institution, 5% par défaut)
     National,       // National (100k, 500k ou 1M signatures)
 }
 
-/// Statut d'une pétition
+/// Statut d'une pétition (cycle de vie uniquement)
+///
+/// Les paliers nationaux sont suivis séparément via `TierMilestone`.
 #[type_abi]
 #[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, PartialEq, Debug)]
 pub enum PetitionStatus {
@@ -21,23 +37,52 @@ pub enum PetitionStatus {
     ThresholdReached,    // Seuil atteint, en attente réponse
     ResponsePending,     // Institution doit répondre
     ResponseProvided,    // Réponse fournie
-    DebateTriggered,     // Débat déclenché (national)
-    InquiryTriggered,    // Commission d'enquête (500k national)
-    RICEligible,         // Éligible conversion RIC (1M national)
+    DebateTriggered,     // Obsolète : remplacé par les paliers, conservé pour l'encodage
+    InquiryTriggered,    // Obsolète : remplacé par les paliers, conservé pour l'encodage
+    RICEligible,         // Obsolète : remplacé par `ric_eligible`, conservé pour l'encodage
     RICConverted,        // Convertie en RIC
+    ResponseOverdue,     // Délai de réponse dépassé
+    Merged,              // Fusionnée dans une autre pétition (`merged_into`)
 }
 
-/// Niveau territorial
+/// Paliers des pétitions nationales
 #[type_abi]
-#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, PartialEq, Debug)]
-pub enum TerritorialScope<M: ManagedTypeApi> {
-    Commune { insee_code: ManagedBuffer<M> },
-    Department { code: ManagedBuffer<M> },
-    Region { code: ManagedBuffer<M> },
-    National,
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, Copy, PartialEq, Debug)]
+pub enum NationalTier {
+    Debate,     // 100k : débat à l'Assemblée
+    Inquiry,    // 500k : commission d'enquête
+    Ric,        // 1M : éligible conversion RIC
+}
+
+impl NationalTier {
+    /// Nombre de signatures requis pour atteindre le palier
+    pub fn threshold(&self) -> u64 {
+        match self {
+            NationalTier::Debate => 100_000,
+            NationalTier::Inquiry => 500_000,
+            NationalTier::Ric => 1_000_000,
+        }
+    }
+}
+
+/// Palier national atteint et suites données
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone)]
+pub struct TierMilestone<M: ManagedTypeApi> {
+    pub tier: NationalTier,
+    pub reached_at: u64,
+    pub signatures_at_reach: u64,
+    pub response_deadline: Option<u64>,              // Aucun pour le palier RIC (issue = conversion)
+    pub response_ipfs: Option<ManagedBuffer<M>>,
+    pub responder: Option<ManagedAddress<M>>,
+    pub debate_scheduled_at: Option<u64>,            // Palier débat uniquement
+    pub inquiry_outcome_ipfs: Option<ManagedBuffer<M>>, // Palier enquête uniquement
 }
 
 /// Structure principale d'une pétition
+///
+/// Les dix-neuf premiers champs reprennent la disposition de `LegacyPetition`,
+/// les suivants sont ajoutés à la fin.
 #[type_abi]
 #[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone)]
 pub struct Petition<M: ManagedTypeApi> {
@@ -51,8 +96,43 @@ pub struct Petition<M: ManagedTypeApi> {
     pub deadline: u64,
     pub signatures_count: u64,
     pub target_signatures: u64,
-    pub threshold_percentage: u32,        // Ex: 5% pour commune
-    pub population_base: u64,             // Population de référence
+    pub threshold_percentage: u32,        // Seuil de l'institution (défaut 5%)
+    pub population_base: u64,             // Électeurs inscrits (ou population) du territoire
+    pub status: PetitionStatus,
+    pub debate_triggered: bool,
+    pub inquiry_triggered: bool,
+    pub ric_eligible: bool,
+    pub response_ipfs: Option<ManagedBuffer<M>>,
+    pub response_deadline: Option<u64>,
+    pub responder: Option<ManagedAddress<M>>,
+    pub escalated_from: Option<TerritorialScope<M>>, // Territoire d'origine si escaladée
+    pub version: u32,                     // Version du texte (1 à la création)
+    pub stale_signatures_count: u64,      // Signatures sur une version antérieure, non reconfirmées
+    pub co_sponsors: ManagedVec<M, ManagedAddress<M>>,
+    pub hidden: bool,                     // Masquée par la modération
+    pub merged_into: Option<u64>,         // Pétition cible si fusionnée
+}
+
+/// Pétition enregistrée avant l'upgrade (ancienne disposition, clé `petitions`)
+///
+/// Ces pétitions n'ont ni escalade, ni amendement, ni co-signataires, ni fusion :
+/// elles sont lues en version 1, visibles, et migrées vers `petitionsV2` à leur
+/// prochaine écriture.
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone)]
+pub struct LegacyPetition<M: ManagedTypeApi> {
+    pub id: u64,
+    pub title: ManagedBuffer<M>,
+    pub description_ipfs: ManagedBuffer<M>,
+    pub scope: TerritorialScope<M>,
+    pub petition_type: PetitionType,
+    pub creator: ManagedAddress<M>,
+    pub created_at: u64,
+    pub deadline: u64,
+    pub signatures_count: u64,
+    pub target_signatures: u64,
+    pub threshold_percentage: u32,
+    pub population_base: u64,
     pub status: PetitionStatus,
     pub debate_triggered: bool,
     pub inquiry_triggered: bool,
@@ -62,14 +142,98 @@ pub struct Petition<M: ManagedTypeApi> {
     pub responder: Option<ManagedAddress<M>>,
 }
 
+impl<M: ManagedTypeApi> From<LegacyPetition<M>> for Petition<M> {
+    fn from(legacy: LegacyPetition<M>) -> Self {
+        Petition {
+            id: legacy.id,
+            title: legacy.title,
+            description_ipfs: legacy.description_ipfs,
+            scope: legacy.scope,
+            petition_type: legacy.petition_type,
+            creator: legacy.creator,
+            created_at: legacy.created_at,
+            deadline: legacy.deadline,
+            signatures_count: legacy.signatures_count,
+            target_signatures: legacy.target_signatures,
+            threshold_percentage: legacy.threshold_percentage,
+            population_base: legacy.population_base,
+            status: legacy.status,
+            debate_triggered: legacy.debate_triggered,
+            inquiry_triggered: legacy.inquiry_triggered,
+            ric_eligible: legacy.ric_eligible,
+            response_ipfs: legacy.response_ipfs,
+            response_deadline: legacy.response_deadline,
+            responder: legacy.responder,
+            escalated_from: None,
+            version: 1,
+            stale_signatures_count: 0,
+            co_sponsors: ManagedVec::new(),
+            hidden: false,
+            merged_into: None,
+        }
+    }
+}
+
+/// Statut d'une décision de modération
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, PartialEq, Debug)]
+pub enum ModerationStatus {
+    Hidden,     // Masquée, appel possible
+    Appealed,   // Appel du créateur en attente
+    Restored,   // Appel accepté, pétition rétablie
+    Upheld,     // Appel rejeté, masquage définitif
+}
+
+/// Décision de modération sur une pétition
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone)]
+pub struct ModerationRecord<M: ManagedTypeApi> {
+    pub moderator: ManagedAddress<M>,
+    pub reason_hash: ManagedBuffer<M>,
+    pub hidden_at: u64,
+    pub appeal_ipfs: Option<ManagedBuffer<M>>,
+    pub status: ModerationStatus,
+}
+
+/// Version du texte d'une pétition
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone)]
+pub struct PetitionVersion<M: ManagedTypeApi> {
+    pub version: u32,
+    pub description_ipfs: ManagedBuffer<M>,
+    pub created_at: u64,
+}
+
+/// Taille d'une racine de Merkle d'électorat
+const MERKLE_ROOT_LEN: usize = 32;
+
+/// Longueur maximale d'un titre de pétition
+const MAX_TITLE_LEN: usize = 200;
+
+/// Nombre maximal de co-signataires affichés sur une pétition
+const MAX_CO_SPONSORS: usize = 10;
+
+/// Taille maximale d'une page pour les views paginées
+const MAX_PAGE_SIZE: u64 = 100;
+
+/// Tags d'action ajoutés aux signaux publics (séparation de domaine des preuves)
+const WITHDRAW_ACTION: &[u8] = b"withdraw";
+const RECONFIRM_ACTION: &[u8] = b"reconfirm";
+const MERGE_ACTION: &[u8] = b"merge";
+
 /// Signature d'un citoyen (anonyme via nullifier)
+///
+/// Conservée pour audit : la preuve d'appartenance peut être revérifiée hors-chaîne
+/// contre `merkle_root` à partir de son hash.
 #[type_abi]
 #[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone)]
 pub struct PetitionSignature<M: ManagedTypeApi> {
     pub petition_id: u64,
     pub nullifier: ManagedBuffer<M>,
     pub timestamp: u64,
-    pub zk_proof: Option<ManagedBuffer<M>>,
+    pub merkle_root: ManagedBuffer<M>,   // Racine de l'électorat au moment de la signature
+    pub proof_hash: ManagedBuffer<M>,    // sha256 de la preuve Groth16 attestée
+    pub version: u32,                    // Version du texte signée (ou reconfirmée)
 }
 
 #[multiversx_sc::contract]
@@ -92,10 +256,16 @@ pub trait PetitionContract {
     /// * `scope` - Niveau territorial
     /// * `petition_type` - Type de pétition
     /// * `duration_days` - Durée en jours (ex: 90)
-    /// * `population_base` - Population de référence (pour calcul seuil)
+    ///
+    /// Pour les pétitions locales et départementales, la population de référence et
+    /// le pourcentage de seuil sont lus dans institution_registry pour le territoire.
+    ///
+    /// Si un dépôt de création est configuré, il doit être joint en EGLD ; il est
+    /// remboursé au créateur dès que la pétition atteint `depositRefundSignatures`.
     ///
     /// # Returns
     /// ID de la pétition créée
+    #[payable("EGLD")]
     #[endpoint(createPetition)]
     fn create_petition(
         &self,
@@ -104,22 +274,29 @@ pub trait PetitionContract {
         scope: TerritorialScope<Self::Api>,
         petition_type: PetitionType,
         duration_days: u64,
-        population_base: u64,
     ) -> u64 {
-        require!(title.len() > 0, "Title cannot be empty");
-        require!(description_ipfs.len() == 46, "Invalid IPFS CID");
-        require!(duration_days >= 30 && duration_days <= 180, "Duration must be 30-180 days");
-        require!(population_base > 0, "Population base must be > 0");
+        require!(!title.is_empty(), "Title cannot be empty");
+        require!(title.len() <= MAX_TITLE_LEN, "Title too long");
+        require!(is_valid_ipfs_cid(&description_ipfs), "Invalid IPFS CID");
+        require!((30..=180).contains(&duration_days), "Duration must be 30-180 days");
+
+        let deposit = self.call_value().egld().clone();
+        require!(
+            deposit == self.creation_deposit().get(),
+            "Incorrect creation deposit"
+        );
+        require!(
+            (petition_type == PetitionType::National) == (scope == TerritorialScope::National),
+            "Petition type does not match scope"
+        );
 
         let caller = self.blockchain().get_caller();
         let petition_id = self.next_petition_id().get();
         let current_timestamp = self.blockchain().get_block_timestamp();
 
-        // Calculer seuil en fonction du type
-        let (threshold_percentage, target_signatures) = self.calculate_threshold(
-            &petition_type,
-            population_base,
-        );
+        // Calculer seuil en fonction du type et des données de l'institution
+        let (population_base, threshold_percentage, target_signatures) =
+            self.calculate_threshold(&petition_type, &scope);
 
         let petition = Petition {
             id: petition_id,
@@ -141,9 +318,24 @@ pub trait PetitionContract {
             response_ipfs: None,
             response_deadline: None,
             responder: None,
+            escalated_from: None,
+            version: 1,
+            stale_signatures_count: 0,
+            co_sponsors: ManagedVec::new(),
+            hidden: false,
+            merged_into: None,
         };
 
-        self.petitions().insert(petition_id, petition);
+        if deposit > 0u64 {
+            self.petition_deposit(petition_id).set(&deposit);
+        }
+
+        self.store_petition(&petition);
+        self.petition_versions(petition_id).push(&PetitionVersion {
+            version: 1,
+            description_ipfs: description_ipfs.clone(),
+            created_at: current_timestamp,
+        });
         self.next_petition_id().set(petition_id + 1);
 
         // Emit event
@@ -158,31 +350,63 @@ pub trait PetitionContract {
     }
 
     /// Calculer le seuil de signatures requis
+    ///
+    /// # Returns
+    /// (population de référence, % de seuil, signatures requises)
     fn calculate_threshold(
         &self,
         petition_type: &PetitionType,
-        population: u64,
-    ) -> (u32, u64) {
-        match petition_type {
-            PetitionType::Local => {
-                // 5% de la population
-                let threshold_pct = 5u32;
-                let target = (population * 5) / 100;
-                (threshold_pct, target.max(100)) // Minimum 100 signatures
-            },
-            PetitionType::Departmental => {
-                // 2% de la population
-                let threshold_pct = 2u32;
-                let target = (population * 2) / 100;
-                (threshold_pct, target.max(1000)) // Minimum 1000 signatures
-            },
+        scope: &TerritorialScope<Self::Api>,
+    ) -> (u64, u32, u64) {
+        let minimum_signatures = match petition_type {
+            PetitionType::Local => 100u64,          // Minimum 100 signatures
+            PetitionType::Departmental => 1000u64,  // Minimum 1000 signatures
             PetitionType::National => {
-                // National : 100,000 minimum (débat Assemblée)
-                let threshold_pct = 0u32; // Seuil absolu
-                let target = 100_000u64;
-                (threshold_pct, target)
+                // National : 100,000 minimum (débat Assemblée), seuil absolu
+                return (0u64, 0u32, 100_000u64);
             },
-        }
+        };
+
+        let (population, electorate, threshold_pct) = self.institution_threshold_data(scope);
+
+        // Les signataires sont des électeurs : l'électorat prime sur la population
+        let population_base = if electorate > 0 { electorate } else { population };
+        require!(population_base > 0, "Institution has no population data");
+
+        let target = (population_base * threshold_pct as u64) / 100;
+        (population_base, threshold_pct, target.max(minimum_signatures))
+    }
+
+    /// Lire population, électorat et % de seuil de l'institution du territoire
+    fn institution_threshold_data(&self, scope: &TerritorialScope<Self::Api>) -> (u64, u64, u32) {
+        let (registry, institution_id) = self.scope_institution(scope);
+
+        self.institution_registry_proxy(registry)
+            .get_petition_threshold_data(institution_id)
+            .returns(ReturnsResult)
+            .sync_call()
+            .into_tuple()
+    }
+
+    /// Retrouver dans institution_registry l'institution compétente pour un territoire
+    ///
+    /// # Returns
+    /// (adresse du registre, ID de l'institution)
+    fn scope_institution(&self, scope: &TerritorialScope<Self::Api>) -> (ManagedAddress, u64) {
+        require!(
+            !self.institution_registry_address().is_empty(),
+            "Institution registry not configured"
+        );
+        let registry = self.institution_registry_address().get();
+
+        let institution_id: u64 = self
+            .institution_registry_proxy(registry.clone())
+            .get_institution_id_by_scope(scope.clone())
+            .returns(ReturnsResult)
+            .sync_call();
+        require!(institution_id != 0, "No institution registered for this territory");
+
+        (registry, institution_id)
     }
 
     /// Signer une pétition (avec nullifier anti-fraude)
@@ -190,21 +414,21 @@ pub trait PetitionContract {
     /// # Arguments
     /// * `petition_id` - ID de la pétition
     /// * `nullifier` - Hash cryptographique unique (Poseidon(citizen_id, petition_id, secret))
-    /// * `zk_proof` - Preuve zk-SNARK optionnelle d'éligibilité
+    /// * `zk_proof` - Preuve Groth16 d'appartenance à l'électorat du territoire
     #[endpoint(signPetition)]
     fn sign_petition(
         &self,
         petition_id: u64,
         nullifier: ManagedBuffer,
-        zk_proof: OptionalValue<ManagedBuffer>,
+        zk_proof: ManagedBuffer,
     ) {
-        require!(self.petitions().contains_key(&petition_id), "Petition not found");
         require!(nullifier.len() == 64, "Invalid nullifier length"); // 32 bytes hex
 
-        let mut petition = self.petitions().get(&petition_id).unwrap();
+        let mut petition = self.require_petition(petition_id);
 
         // Vérifications
-        require!(petition.status == PetitionStatus::Active, "Petition not active");
+        require!(self.is_collecting(&petition), "Petition not active");
+        require!(!petition.hidden, "Petition hidden by moderation");
         require!(
             self.blockchain().get_block_timestamp() <= petition.deadline,
             "Petition deadline expired"
@@ -214,16 +438,26 @@ pub trait PetitionContract {
             "Already signed (nullifier used)"
         );
 
-        // Si preuve zk-SNARK fournie, la vérifier
-        if let OptionalValue::Some(ref proof) = zk_proof {
-            require!(
-                self.verify_zk_proof(proof, &nullifier, petition_id),
-                "Invalid zk-SNARK proof"
-            );
-        }
+        // Preuve d'appartenance à l'électorat du territoire de la pétition
+        require!(
+            !self.electorate_root(&petition.scope).is_empty(),
+            "No electorate root for this territory"
+        );
+        let merkle_root = self.electorate_root(&petition.scope).get();
+        require!(
+            self.verify_zk_proof(&zk_proof, &merkle_root, &nullifier, petition_id, &[]),
+            "Invalid zk-SNARK proof"
+        );
 
-        // Note: Signature structure created for validation but not stored
-        // Nullifier is stored to prevent double-signing
+        let signature = PetitionSignature {
+            petition_id,
+            nullifier: nullifier.clone(),
+            timestamp: self.blockchain().get_block_timestamp(),
+            merkle_root,
+            proof_hash: self.crypto().sha256(&zk_proof).as_managed_buffer().clone(),
+            version: petition.version,
+        };
+        self.petition_signatures(petition_id).insert(nullifier.clone(), signature);
 
         self.used_nullifiers(petition_id).insert(nullifier.clone());
         petition.signatures_count += 1;
@@ -231,7 +465,7 @@ pub trait PetitionContract {
         // Vérifier si seuil atteint
         self.check_petition_thresholds(&mut petition);
 
-        self.petitions().insert(petition_id, petition.clone());
+        self.store_petition(&petition);
 
         // Emit event
         self.petition_signed_event(
@@ -241,10 +475,229 @@ pub trait PetitionContract {
         );
     }
 
+    /// Enregistrer une pétition et tenir à jour les index secondaires
+    ///
+    /// Toute écriture d'une pétition passe par ici : les index par territoire et par
+    /// statut suivent ainsi chaque changement de statut ou escalade de territoire.
+    fn store_petition(&self, petition: &Petition<Self::Api>) {
+        match self.load_petition(petition.id) {
+            Some(previous) => {
+                if previous.status != petition.status {
+                    self.petitions_by_status(&previous.status).swap_remove(&petition.id);
+                    self.petitions_by_status(&petition.status).insert(petition.id);
+                }
+                if previous.scope != petition.scope {
+                    self.petitions_by_scope(&previous.scope).swap_remove(&petition.id);
+                    self.petitions_by_scope(&petition.scope).insert(petition.id);
+                }
+            },
+            None => self.index_petition(petition),
+        }
+
+        self.petitions().insert(petition.id, petition.clone());
+        self.legacy_petitions().remove(&petition.id);
+    }
+
+    /// Charger une pétition, y compris une pétition créée avant l'upgrade
+    ///
+    /// Les pétitions réécrites depuis l'upgrade sont sous `petitionsV2` ; les autres
+    /// sont encore sous `petitions` dans l'ancienne disposition (`LegacyPetition`).
+    fn load_petition(&self, petition_id: u64) -> Option<Petition<Self::Api>> {
+        self.petitions()
+            .get(&petition_id)
+            .or_else(|| self.legacy_petitions().get(&petition_id).map(Petition::from))
+    }
+
+    /// Charger une pétition existante
+    fn require_petition(&self, petition_id: u64) -> Petition<Self::Api> {
+        let petition = self.load_petition(petition_id);
+        require!(petition.is_some(), "Petition not found");
+        petition.unwrap()
+    }
+
+    /// Ajouter une pétition aux index secondaires
+    fn index_petition(&self, petition: &Petition<Self::Api>) {
+        self.petitions_by_status(&petition.status).insert(petition.id);
+        self.petitions_by_scope(&petition.scope).insert(petition.id);
+        self.petitions_by_creator(&petition.creator).insert(petition.id);
+    }
+
+    /// Charger une page de pétitions à partir d'un index secondaire
+    ///
+    /// Accès direct par position dans l'index : le coût ne dépend que de `limit`.
+    /// Les pétitions masquées sont omises si `include_hidden` est faux, la page peut
+    /// alors contenir moins de `limit` éléments.
+    fn petitions_page(
+        &self,
+        index: &UnorderedSetMapper<u64>,
+        offset: u64,
+        limit: u64,
+        include_hidden: bool,
+    ) -> MultiValueEncoded<Petition<Self::Api>> {
+        require!(limit <= MAX_PAGE_SIZE, "Page size too large");
+
+        let total = index.len() as u64;
+        let start = offset.min(total);
+        let end = (start + limit).min(total);
+
+        let mut result = MultiValueEncoded::new();
+        for position in start..end {
+            let id = index.get_by_index(position as usize + 1);
+            if let Some(petition) = self.load_petition(id) {
+                if include_hidden || !petition.hidden {
+                    result.push(petition);
+                }
+            }
+        }
+
+        result
+    }
+
+    /// Reconstruire les index secondaires des pétitions existantes (admin, après upgrade)
+    ///
+    /// # Arguments
+    /// * `from_id` - Premier ID à indexer
+    /// * `to_id` - Dernier ID à indexer (inclus)
+    #[only_owner]
+    #[endpoint(rebuildIndexes)]
+    fn rebuild_indexes(&self, from_id: u64, to_id: u64) {
+        for id in from_id..=to_id {
+            if let Some(petition) = self.load_petition(id) {
+                self.index_petition(&petition);
+            }
+        }
+    }
+
+    /// Retirer sa signature, avant l'atteinte du seuil et de la date limite
+    ///
+    /// La preuve porte sur les mêmes signaux publics que la signature (racine enregistrée
+    /// lors de la signature), suivis du tag `withdraw`. Le nullifier redevient utilisable.
+    ///
+    /// # Arguments
+    /// * `petition_id` - ID de la pétition
+    /// * `nullifier` - Nullifier de la signature
+    /// * `proof` - Preuve Groth16 de détention du nullifier
+    #[endpoint(withdrawSignature)]
+    fn withdraw_signature(&self, petition_id: u64, nullifier: ManagedBuffer, proof: ManagedBuffer) {
+        let (mut petition, signature) = self.signature_for_update(petition_id, &nullifier);
+        require!(
+            self.verify_zk_proof(&proof, &signature.merkle_root, &nullifier, petition_id, WITHDRAW_ACTION),
+            "Invalid zk-SNARK proof"
+        );
+
+        if signature.version == petition.version {
+            petition.signatures_count -= 1;
+        } else {
+            petition.stale_signatures_count -= 1;
+        }
+
+        self.petition_signatures(petition_id).remove(&nullifier);
+        self.used_nullifiers(petition_id).swap_remove(&nullifier);
+        self.store_petition(&petition);
+
+        self.signature_withdrawn_event(petition_id, &nullifier, petition.signatures_count);
+    }
+
+    /// Reconfirmer une signature collectée sur une version antérieure du texte
+    ///
+    /// # Arguments
+    /// * `petition_id` - ID de la pétition
+    /// * `nullifier` - Nullifier de la signature
+    /// * `proof` - Preuve Groth16 de détention du nullifier (tag `reconfirm`)
+    #[endpoint(reconfirmSignature)]
+    fn reconfirm_signature(&self, petition_id: u64, nullifier: ManagedBuffer, proof: ManagedBuffer) {
+        let (mut petition, mut signature) = self.signature_for_update(petition_id, &nullifier);
+        require!(signature.version < petition.version, "Signature already on current version");
+        require!(
+            self.verify_zk_proof(&proof, &signature.merkle_root, &nullifier, petition_id, RECONFIRM_ACTION),
+            "Invalid zk-SNARK proof"
+        );
+
+        signature.version = petition.version;
+        signature.timestamp = self.blockchain().get_block_timestamp();
+        self.petition_signatures(petition_id).insert(nullifier.clone(), signature);
+
+        petition.stale_signatures_count -= 1;
+        petition.signatures_count += 1;
+        self.check_petition_thresholds(&mut petition);
+        self.store_petition(&petition);
+
+        self.signature_reconfirmed_event(petition_id, &nullifier, petition.version);
+    }
+
+    /// Amender la description d'une pétition (créateur uniquement)
+    ///
+    /// Crée une nouvelle version du texte. Les signatures existantes passent dans
+    /// `stale_signatures_count` jusqu'à leur reconfirmation.
+    ///
+    /// # Arguments
+    /// * `petition_id` - ID de la pétition
+    /// * `description_ipfs` - Hash IPFS du texte amendé
+    ///
+    /// # Returns
+    /// Numéro de la nouvelle version
+    #[endpoint(amendPetition)]
+    fn amend_petition(&self, petition_id: u64, description_ipfs: ManagedBuffer) -> u32 {
+        require!(is_valid_ipfs_cid(&description_ipfs), "Invalid IPFS CID");
+
+        let mut petition = self.require_petition(petition_id);
+        let current_timestamp = self.blockchain().get_block_timestamp();
+
+        require!(
+            self.blockchain().get_caller() == petition.creator,
+            "Only creator can amend"
+        );
+        require!(petition.status == PetitionStatus::Active, "Petition not active");
+        require!(current_timestamp <= petition.deadline, "Petition deadline expired");
+        require!(
+            description_ipfs != petition.description_ipfs,
+            "Description unchanged"
+        );
+
+        petition.version += 1;
+        petition.description_ipfs = description_ipfs.clone();
+        petition.stale_signatures_count += petition.signatures_count;
+        petition.signatures_count = 0;
+
+        self.petition_versions(petition_id).push(&PetitionVersion {
+            version: petition.version,
+            description_ipfs: description_ipfs.clone(),
+            created_at: current_timestamp,
+        });
+        self.store_petition(&petition);
+
+        self.petition_amended_event(petition_id, petition.version, &description_ipfs);
+
+        petition.version
+    }
+
+    /// Charger une pétition encore en collecte et une signature existante
+    fn signature_for_update(
+        &self,
+        petition_id: u64,
+        nullifier: &ManagedBuffer,
+    ) -> (Petition<Self::Api>, PetitionSignature<Self::Api>) {
+        let petition = self.require_petition(petition_id);
+        require!(petition.status == PetitionStatus::Active, "Petition not active");
+        require!(
+            self.blockchain().get_block_timestamp() <= petition.deadline,
+            "Petition deadline expired"
+        );
+
+        let signature = self.petition_signatures(petition_id).get(nullifier);
+        require!(signature.is_some(), "Signature not found");
+
+        (petition, signature.unwrap())
+    }
+
     /// Vérifier et déclencher actions si seuils atteints
     fn check_petition_thresholds(&self, petition: &mut Petition<Self::Api>) {
         let signatures = petition.signatures_count;
 
+        if signatures >= self.deposit_refund_signatures().get() {
+            self.refund_deposit(petition);
+        }
+
         match &petition.petition_type {
             PetitionType::Local | PetitionType::Departmental => {
                 // Seuil % atteint
@@ -255,7 +708,7 @@ pub trait PetitionContract {
 
                     // Institution doit répondre sous 30 jours
                     petition.response_deadline = Some(
-                        self.blockchain().get_block_timestamp() + 30 * 86400
+                        self.blockchain().get_block_timestamp() + RESPONSE_DELAY
                     );
 
                     self.threshold_reached_event(
@@ -266,41 +719,182 @@ pub trait PetitionContract {
                 }
             },
             PetitionType::National => {
-                // Paliers nationaux
-                if signatures >= 100_000 && !petition.debate_triggered {
-                    petition.debate_triggered = true;
-                    petition.status = PetitionStatus::DebateTriggered;
-
-                    self.debate_triggered_event(petition.id, signatures);
+                // Paliers nationaux : enregistrés comme jalons, le statut reste un cycle de vie
+                for tier in NATIONAL_TIERS {
+                    if signatures < tier.threshold()
+                        || !self.tier_milestone(petition.id, tier).is_empty()
+                    {
+                        continue;
+                    }
+
+                    self.reach_national_tier(petition, tier);
                 }
+            },
+        }
+    }
+
+    /// Rembourser le dépôt de création au créateur
+    fn refund_deposit(&self, petition: &Petition<Self::Api>) {
+        if self.petition_deposit(petition.id).is_empty() {
+            return;
+        }
 
-                if signatures >= 500_000 && !petition.inquiry_triggered {
-                    petition.inquiry_triggered = true;
-                    petition.status = PetitionStatus::InquiryTriggered;
+        let deposit = self.petition_deposit(petition.id).take();
+        self.send().direct_egld(&petition.creator, &deposit);
 
-                    self.inquiry_triggered_event(petition.id, signatures);
-                }
+        self.deposit_refunded_event(petition.id, &petition.creator, &deposit);
+    }
+
+    /// Conserver le dépôt de création (pétition spam ou sans soutien)
+    fn forfeit_deposit(&self, petition_id: u64) {
+        if self.petition_deposit(petition_id).is_empty() {
+            return;
+        }
 
-                if signatures >= 1_000_000 && !petition.ric_eligible {
-                    petition.ric_eligible = true;
-                    petition.status = PetitionStatus::RICEligible;
+        let deposit = self.petition_deposit(petition_id).take();
+        self.forfeited_deposits().update(|total| *total += &deposit);
+    }
 
-                    self.ric_eligible_event(petition.id, signatures);
-                }
+    /// Enregistrer un palier national atteint
+    fn reach_national_tier(&self, petition: &mut Petition<Self::Api>, tier: NationalTier) {
+        let current_timestamp = self.blockchain().get_block_timestamp();
+        let response_deadline = match tier {
+            NationalTier::Ric => None,
+            _ => Some(current_timestamp + RESPONSE_DELAY),
+        };
+
+        self.tier_milestone(petition.id, tier).set(TierMilestone {
+            tier,
+            reached_at: current_timestamp,
+            signatures_at_reach: petition.signatures_count,
+            response_deadline,
+            response_ipfs: None,
+            responder: None,
+            debate_scheduled_at: None,
+            inquiry_outcome_ipfs: None,
+        });
+
+        match tier {
+            NationalTier::Debate => {
+                petition.debate_triggered = true;
+                self.debate_triggered_event(petition.id, petition.signatures_count);
+            },
+            NationalTier::Inquiry => {
+                petition.inquiry_triggered = true;
+                self.inquiry_triggered_event(petition.id, petition.signatures_count);
+            },
+            NationalTier::Ric => {
+                petition.ric_eligible = true;
+                self.ric_eligible_event(petition.id, petition.signatures_count);
+            },
+        }
+
+        self.refresh_national_status(petition);
+    }
+
+    /// Recalculer statut et délai de réponse d'une pétition nationale depuis ses paliers
+    ///
+    /// Le délai retenu est celui du plus ancien palier resté sans réponse.
+    fn refresh_national_status(&self, petition: &mut Petition<Self::Api>) {
+        if petition.status == PetitionStatus::RICConverted {
+            return;
+        }
+
+        let mut pending_deadline: Option<u64> = None;
+        let mut any_tier_reached = false;
+        for tier in NATIONAL_TIERS {
+            if self.tier_milestone(petition.id, tier).is_empty() {
+                continue;
+            }
+            any_tier_reached = true;
+
+            let milestone = self.tier_milestone(petition.id, tier).get();
+            if milestone.response_ipfs.is_none() && pending_deadline.is_none() {
+                pending_deadline = milestone.response_deadline;
+            }
+        }
+
+        if !any_tier_reached {
+            return;
+        }
+
+        let still_overdue = petition.status == PetitionStatus::ResponseOverdue
+            && pending_deadline
+                .is_some_and(|deadline| self.blockchain().get_block_timestamp() > deadline);
+
+        petition.response_deadline = pending_deadline;
+        petition.status = if pending_deadline.is_none() {
+            PetitionStatus::ResponseProvided
+        } else if still_overdue {
+            PetitionStatus::ResponseOverdue
+        } else {
+            PetitionStatus::ThresholdReached
+        };
+    }
+
+    /// Vérifier si une pétition accepte encore des signatures
+    ///
+    /// Une pétition nationale continue de collecter après ses premiers paliers.
+    fn is_collecting(&self, petition: &Petition<Self::Api>) -> bool {
+        match petition.petition_type {
+            PetitionType::National => {
+                petition.status != PetitionStatus::Closed
+                    && petition.status != PetitionStatus::RICConverted
+                    && petition.status != PetitionStatus::Merged
             },
+            _ => petition.status == PetitionStatus::Active,
         }
     }
 
-    /// Vérifier preuve zk-SNARK (simplifié, vérification réelle via backend)
+    /// Vérifier la preuve Groth16 d'appartenance à l'électorat
+    ///
+    /// Signaux publics du circuit : `merkle_root (32) || nullifier || petition_id (8, big-endian)`,
+    /// suivis du tag d'action pour un retrait ou une reconfirmation (vide pour une signature).
+    /// La preuve est vérifiée hors-chaîne et attestée par le vérificateur de confiance.
     fn verify_zk_proof(
         &self,
-        _proof: &ManagedBuffer,
-        _nullifier: &ManagedBuffer,
-        _petition_id: u64,
+        proof: &ManagedBuffer,
+        merkle_root: &ManagedBuffer,
+        nullifier: &ManagedBuffer,
+        petition_id: u64,
+        action: &[u8],
+    ) -> bool {
+        let mut public_inputs = merkle_root.clone();
+        public_inputs.append(nullifier);
+        public_inputs.append_bytes(&petition_id.to_be_bytes());
+        public_inputs.append_bytes(action);
+
+        self.proof_verifier().verify_zk_snark_proof(proof, &public_inputs)
+    }
+
+    /// Vérifier la preuve de transfert d'une signature vers la pétition cible d'une fusion
+    ///
+    /// Signaux publics : ceux de la signature d'origine avec le tag `merge`, suivis de
+    /// `target_id (8, big-endian) || merge_nullifier`. Le circuit prouve que les deux
+    /// nullifiers sont dérivés du même citoyen et du même secret : `merge_nullifier` est
+    /// le nullifier du signataire sur la cible.
+    fn verify_merge_proof(
+        &self,
+        proof: &ManagedBuffer,
+        signature: &PetitionSignature<Self::Api>,
+        target_id: u64,
+        merge_nullifier: &ManagedBuffer,
     ) -> bool {
-        // Dans un contrat production, on vérifierait la preuve Groth16 on-chain
-        // Pour l'instant, on assume que le backend a déjà vérifié
-        true
+        let mut public_inputs = signature.merkle_root.clone();
+        public_inputs.append(&signature.nullifier);
+        public_inputs.append_bytes(&signature.petition_id.to_be_bytes());
+        public_inputs.append_bytes(MERGE_ACTION);
+        public_inputs.append_bytes(&target_id.to_be_bytes());
+        public_inputs.append(merge_nullifier);
+
+        self.proof_verifier().verify_zk_snark_proof(proof, &public_inputs)
+    }
+
+    /// Vérificateur de preuves, attesté par le backend vérificateur
+    fn proof_verifier(&self) -> DefaultVerifier<Self::Api> {
+        require!(!self.proof_verifier_address().is_empty(), "Proof verifier not configured");
+
+        DefaultVerifier::new(self.proof_verifier_address().get())
     }
 
     /// Institution répond à la pétition
@@ -314,22 +908,27 @@ pub trait PetitionContract {
         petition_id: u64,
         response_ipfs: ManagedBuffer,
     ) {
-        require!(self.petitions().contains_key(&petition_id), "Petition not found");
-        require!(response_ipfs.len() == 46, "Invalid IPFS CID");
+        require!(is_valid_ipfs_cid(&response_ipfs), "Invalid IPFS CID");
 
         let caller = self.blockchain().get_caller();
-        let mut petition = self.petitions().get(&petition_id).unwrap();
+        let mut petition = self.require_petition(petition_id);
+
+        require!(
+            petition.petition_type != PetitionType::National,
+            "National petitions are answered per tier"
+        );
 
+        // Une réponse tardive reste acceptée
         require!(
             petition.status == PetitionStatus::ThresholdReached
-                || petition.status == PetitionStatus::ResponsePending,
+                || petition.status == PetitionStatus::ResponsePending
+                || petition.status == PetitionStatus::ResponseOverdue,
             "Petition not awaiting response"
         );
 
-        // Vérifier autorisation (normalement via registre institutions)
-        // Pour l'instant, on accepte toute adresse autorisée
+        // Seule l'institution du territoire concerné peut répondre
         require!(
-            self.authorized_responders().contains(&caller),
+            self.is_scope_responder(&petition.scope, &caller),
             "Not authorized to respond"
         );
 
@@ -337,7 +936,7 @@ pub trait PetitionContract {
         petition.responder = Some(caller.clone());
         petition.status = PetitionStatus::ResponseProvided;
 
-        self.petitions().insert(petition_id, petition);
+        self.store_petition(&petition);
 
         self.petition_response_event(
             petition_id,
@@ -346,46 +945,228 @@ pub trait PetitionContract {
         );
     }
 
-    /// Convertir pétition éligible en RIC
+    /// Réponse officielle à un palier national atteint
     ///
     /// # Arguments
-    /// * `petition_id` - ID de la pétition (doit avoir 1M+ signatures)
-    /// * `ric_contract_address` - Adresse du smart contract RIC
-    ///
-    /// # Returns
-    /// ID du RIC créé
-    #[endpoint(convertToRIC)]
-    fn convert_to_ric(
+    /// * `petition_id` - ID de la pétition nationale
+    /// * `tier` - Palier concerné
+    /// * `response_ipfs` - Hash IPFS de la réponse officielle
+    #[endpoint(respondToNationalTier)]
+    fn respond_to_national_tier(
         &self,
         petition_id: u64,
-        ric_contract_address: ManagedAddress,
-    ) -> u64 {
-        require!(self.petitions().contains_key(&petition_id), "Petition not found");
+        tier: NationalTier,
+        response_ipfs: ManagedBuffer,
+    ) {
+        require!(is_valid_ipfs_cid(&response_ipfs), "Invalid IPFS CID");
+
+        let caller = self.blockchain().get_caller();
+        let (mut petition, mut milestone) = self.national_milestone_for(petition_id, tier, &caller);
+        require!(milestone.response_ipfs.is_none(), "Tier already answered");
+
+        milestone.response_ipfs = Some(response_ipfs.clone());
+        milestone.responder = Some(caller.clone());
+        self.tier_milestone(petition_id, tier).set(milestone);
+
+        petition.response_ipfs = Some(response_ipfs.clone());
+        petition.responder = Some(caller.clone());
+        self.refresh_national_status(&mut petition);
+        self.store_petition(&petition);
+
+        self.tier_response_event(petition_id, tier, &caller, &response_ipfs);
+    }
+
+    /// Programmer le débat parlementaire du palier 100k
+    ///
+    /// # Arguments
+    /// * `petition_id` - ID de la pétition nationale
+    /// * `debate_at` - Date du débat (timestamp)
+    #[endpoint(scheduleDebate)]
+    fn schedule_debate(&self, petition_id: u64, debate_at: u64) {
+        require!(
+            debate_at > self.blockchain().get_block_timestamp(),
+            "Debate date must be in the future"
+        );
+
+        let caller = self.blockchain().get_caller();
+        let (_, mut milestone) =
+            self.national_milestone_for(petition_id, NationalTier::Debate, &caller);
+
+        milestone.debate_scheduled_at = Some(debate_at);
+        self.tier_milestone(petition_id, NationalTier::Debate).set(milestone);
+
+        self.debate_scheduled_event(petition_id, debate_at);
+    }
+
+    /// Enregistrer les conclusions de la commission d'enquête du palier 500k
+    ///
+    /// # Arguments
+    /// * `petition_id` - ID de la pétition nationale
+    /// * `outcome_ipfs` - Hash IPFS du rapport de la commission
+    #[endpoint(recordInquiryOutcome)]
+    fn record_inquiry_outcome(&self, petition_id: u64, outcome_ipfs: ManagedBuffer) {
+        require!(is_valid_ipfs_cid(&outcome_ipfs), "Invalid IPFS CID");
 
-        let mut petition = self.petitions().get(&petition_id).unwrap();
+        let caller = self.blockchain().get_caller();
+        let (_, mut milestone) =
+            self.national_milestone_for(petition_id, NationalTier::Inquiry, &caller);
+        require!(milestone.inquiry_outcome_ipfs.is_none(), "Inquiry outcome already recorded");
+
+        milestone.inquiry_outcome_ipfs = Some(outcome_ipfs.clone());
+        self.tier_milestone(petition_id, NationalTier::Inquiry).set(milestone);
+
+        self.inquiry_outcome_event(petition_id, &outcome_ipfs);
+    }
+
+    /// Charger une pétition nationale et un palier atteint, pour un répondant national
+    fn national_milestone_for(
+        &self,
+        petition_id: u64,
+        tier: NationalTier,
+        caller: &ManagedAddress,
+    ) -> (Petition<Self::Api>, TierMilestone<Self::Api>) {
+        let petition = self.require_petition(petition_id);
+        require!(
+            petition.petition_type == PetitionType::National,
+            "Not a national petition"
+        );
+        require!(
+            self.is_scope_responder(&petition.scope, caller),
+            "Not authorized to respond"
+        );
+        require!(
+            !self.tier_milestone(petition_id, tier).is_empty(),
+            "Tier not reached"
+        );
+
+        let milestone = self.tier_milestone(petition_id, tier).get();
+        (petition, milestone)
+    }
+
+    /// Constater le dépassement du délai de réponse (appel public)
+    ///
+    /// La pétition passe en `ResponseOverdue`. Une pétition locale est ensuite escaladée
+    /// selon les paramètres de la commune : éligible RIC si le RIC local est activé,
+    /// sinon transmise au département, qui dispose d'un nouveau délai de réponse. Une
+    /// commune sans département de rattachement renseigné n'est pas escaladée.
+    ///
+    /// # Arguments
+    /// * `petition_id` - ID de la pétition
+    #[endpoint(checkResponseDeadline)]
+    fn check_response_deadline(&self, petition_id: u64) {
+        let mut petition = self.require_petition(petition_id);
+        let current_timestamp = self.blockchain().get_block_timestamp();
+
+        require!(
+            petition.status == PetitionStatus::ThresholdReached
+                || petition.status == PetitionStatus::ResponsePending,
+            "Petition not awaiting response"
+        );
+        let deadline = petition.response_deadline.unwrap_or(u64::MAX);
+        require!(current_timestamp > deadline, "Response deadline not reached");
+
+        petition.status = PetitionStatus::ResponseOverdue;
+        self.response_overdue_event(petition_id, deadline);
+
+        if petition.petition_type == PetitionType::Local {
+            let (registry, institution_id) = self.scope_institution(&petition.scope);
+            let (ric_enabled, department_code) = self
+                .institution_registry_proxy(registry)
+                .get_escalation_settings(institution_id)
+                .returns(ReturnsResult)
+                .sync_call()
+                .into_tuple();
+
+            if ric_enabled {
+                petition.ric_eligible = true;
+                self.ric_eligible_event(petition_id, petition.signatures_count);
+            } else if !department_code.is_empty() {
+                // Sans département de rattachement, la pétition reste en retard sans escalade
+                let department = TerritorialScope::Department { code: department_code };
+                self.petition_escalated_event(petition_id, &petition.scope, &department);
+
+                petition.escalated_from = Some(petition.scope.clone());
+                petition.scope = department;
+                petition.petition_type = PetitionType::Departmental;
+                petition.status = PetitionStatus::ResponsePending;
+                petition.response_deadline = Some(current_timestamp + RESPONSE_DELAY);
+            }
+        }
+
+        self.store_petition(&petition);
+    }
+
+    /// Vérifier qu'une adresse peut répondre au nom du territoire de la pétition
+    ///
+    /// Commune, département et région : l'institution est retrouvée dans
+    /// institution_registry par son code, puis l'adresse doit figurer parmi ses admins.
+    /// National : l'adresse doit figurer parmi les répondants nationaux autorisés.
+    fn is_scope_responder(
+        &self,
+        scope: &TerritorialScope<Self::Api>,
+        address: &ManagedAddress,
+    ) -> bool {
+        if *scope == TerritorialScope::National {
+            return self.authorized_responders().contains(address);
+        }
+
+        let (registry, institution_id) = self.scope_institution(scope);
+
+        self.institution_registry_proxy(registry)
+            .is_institution_admin(institution_id, address.clone())
+            .returns(ReturnsResult)
+            .sync_call()
+    }
+
+    /// Convertir pétition éligible en RIC
+    ///
+    /// Le RIC est créé sur le contrat configuré via `setRICContract`, directement en
+    /// attente de validation, avec un lien vers la pétition. Seul le créateur choisit
+    /// le type de RIC demandé.
+    ///
+    /// # Arguments
+    /// * `petition_id` - ID de la pétition (1M+ signatures, ou escalade RIC locale)
+    /// * `ric_type` - Type de RIC demandé (législatif, abrogatoire, ...)
+    ///
+    /// # Returns
+    /// ID du RIC créé
+    #[endpoint(convertToRIC)]
+    fn convert_to_ric(&self, petition_id: u64, ric_type: RICType) -> u64 {
+        require!(!self.ric_contract_address().is_empty(), "RIC contract not configured");
+
+        let mut petition = self.require_petition(petition_id);
+
+        require!(
+            self.blockchain().get_caller() == petition.creator,
+            "Only creator can convert to RIC"
+        );
 
         require!(
             petition.ric_eligible,
             "Petition not eligible for RIC (need 1M signatures)"
         );
         require!(
-            petition.status == PetitionStatus::RICEligible,
-            "Petition already converted or not eligible"
+            petition.status != PetitionStatus::RICConverted,
+            "Petition already converted"
         );
 
         // Appel cross-contract au RIC contract pour créer le RIC
         let ric_id: u64 = self
-            .ric_proxy(ric_contract_address)
+            .ric_proxy(self.ric_contract_address().get())
             .create_ric_from_petition(
+                petition_id,
+                ric_type,
                 petition.title.clone(),
                 petition.description_ipfs.clone(),
                 petition.scope.clone(),
                 petition.signatures_count,
             )
-            .execute_on_dest_context();
+            .returns(ReturnsResult)
+            .sync_call();
 
         petition.status = PetitionStatus::RICConverted;
-        self.petitions().insert(petition_id, petition);
+        self.store_petition(&petition);
+        self.petition_ric_id(petition_id).set(ric_id);
 
         self.ric_conversion_event(petition_id, ric_id);
 
@@ -395,9 +1176,7 @@ pub trait PetitionContract {
     /// Fermer pétition après deadline
     #[endpoint(closePetition)]
     fn close_petition(&self, petition_id: u64) {
-        require!(self.petitions().contains_key(&petition_id), "Petition not found");
-
-        let mut petition = self.petitions().get(&petition_id).unwrap();
+        let mut petition = self.require_petition(petition_id);
 
         require!(
             self.blockchain().get_block_timestamp() > petition.deadline,
@@ -406,19 +1185,375 @@ pub trait PetitionContract {
         require!(petition.status == PetitionStatus::Active, "Petition not active");
 
         petition.status = PetitionStatus::Closed;
-        self.petitions().insert(petition_id, petition.clone());
+        self.store_petition(&petition);
+
+        // Le minimum de signatures n'a jamais été atteint
+        self.forfeit_deposit(petition_id);
 
         self.petition_closed_event(petition_id, petition.signatures_count);
     }
 
-    /// Ajouter une adresse autorisée à répondre (admin)
+    // ========== FUSION ==========
+
+    /// Approuver la fusion de la pétition `source_id` dans `target_id`
+    ///
+    /// La fusion est actée dès qu'un modérateur l'approuve, ou quand les créateurs des
+    /// deux pétitions l'ont approuvée. La source passe alors en `Merged` et ne collecte
+    /// plus ; chaque signataire transfère ensuite sa signature via `importMergedSignature`.
+    ///
+    /// # Arguments
+    /// * `source_id` - Pétition absorbée (B)
+    /// * `target_id` - Pétition conservée (A)
+    #[endpoint(approveMerge)]
+    fn approve_merge(&self, source_id: u64, target_id: u64) {
+        require!(source_id != target_id, "Cannot merge a petition into itself");
+
+        let mut source = self.require_petition(source_id);
+        let target = self.require_petition(target_id);
+
+        require!(
+            source.status == PetitionStatus::Active && self.is_collecting(&target),
+            "Both petitions must be collecting signatures"
+        );
+        require!(
+            source.scope == target.scope && source.petition_type == target.petition_type,
+            "Petitions must share scope and type"
+        );
+
+        let caller = self.blockchain().get_caller();
+        let is_moderator = self.moderators().contains(&caller);
+        require!(
+            is_moderator || caller == source.creator || caller == target.creator,
+            "Only creators or a moderator can merge"
+        );
+
+        self.merge_approvals(source_id, target_id).insert(caller.clone());
+        self.merge_approved_event(source_id, target_id, &caller);
+
+        let approvals = self.merge_approvals(source_id, target_id);
+        let agreed = is_moderator
+            || (approvals.contains(&source.creator) && approvals.contains(&target.creator));
+        if !agreed {
+            return;
+        }
+
+        source.status = PetitionStatus::Merged;
+        source.merged_into = Some(target_id);
+        self.store_petition(&source);
+        self.merge_approvals(source_id, target_id).clear();
+
+        // Le créateur de B apparaît sur A ; son dépôt suit la règle habituelle :
+        // rendu si B a atteint le seuil de remboursement, conservé sinon
+        self.add_merged_co_sponsor(target_id, &source.creator);
+        if source.signatures_count >= self.deposit_refund_signatures().get() {
+            self.refund_deposit(&source);
+        } else {
+            self.forfeit_deposit(source_id);
+        }
+
+        self.petitions_merged_event(source_id, target_id);
+    }
+
+    /// Transférer sa signature d'une pétition fusionnée vers la pétition cible
+    ///
+    /// Les nullifiers sont propres à chaque pétition : les signatures de deux pétitions
+    /// ne peuvent pas être reliées entre elles. Le signataire fournit donc son nullifier
+    /// sur la cible (`merge_nullifier`) avec une preuve le liant à sa signature sur la
+    /// source. S'il a déjà signé la cible, la signature est écartée et n'est comptée
+    /// qu'une fois. Une signature non reconfirmée sur la source le reste sur la cible.
+    /// Le transfert n'est possible que tant que la cible collecte des signatures.
+    ///
+    /// # Arguments
+    /// * `source_id` - Pétition fusionnée
+    /// * `nullifier` - Nullifier de la signature sur la source
+    /// * `merge_nullifier` - Nullifier du signataire sur la cible
+    /// * `proof` - Preuve Groth16 liant les deux nullifiers (tag `merge`)
+    ///
+    /// # Returns
+    /// Nombre de signatures restant à transférer
+    #[endpoint(importMergedSignature)]
+    fn import_merged_signature(
+        &self,
+        source_id: u64,
+        nullifier: ManagedBuffer,
+        merge_nullifier: ManagedBuffer,
+        proof: ManagedBuffer,
+    ) -> u64 {
+        require!(merge_nullifier.len() == 64, "Invalid nullifier length");
+
+        let mut source = self.require_petition(source_id);
+        require!(source.status == PetitionStatus::Merged, "Petition not merged");
+
+        let target_id = source.merged_into.unwrap();
+        let mut target = self.require_petition(target_id);
+        require!(self.is_collecting(&target), "Target petition not active");
+        require!(
+            self.blockchain().get_block_timestamp() <= target.deadline,
+            "Target petition deadline expired"
+        );
+
+        let signature = self.petition_signatures(source_id).get(&nullifier);
+        require!(signature.is_some(), "Signature not found");
+        let mut signature = signature.unwrap();
+        require!(
+            self.verify_merge_proof(&proof, &signature, target_id, &merge_nullifier),
+            "Invalid zk-SNARK proof"
+        );
+
+        self.petition_signatures(source_id).remove(&nullifier);
+        if signature.version == source.version {
+            source.signatures_count -= 1;
+        } else {
+            source.stale_signatures_count -= 1;
+        }
+        self.store_petition(&source);
+
+        let duplicate = self.used_nullifiers(target_id).contains(&merge_nullifier);
+        if !duplicate {
+            if signature.version == source.version {
+                signature.version = target.version;
+                target.signatures_count += 1;
+            } else {
+                signature.version = 0;
+                target.stale_signatures_count += 1;
+            }
+            signature.petition_id = target_id;
+            signature.nullifier = merge_nullifier.clone();
+
+            self.used_nullifiers(target_id).insert(merge_nullifier.clone());
+            self.petition_signatures(target_id).insert(merge_nullifier.clone(), signature);
+
+            self.check_petition_thresholds(&mut target);
+            self.store_petition(&target);
+        }
+
+        self.merged_signature_imported_event(source_id, target_id, &merge_nullifier, duplicate);
+
+        self.petition_signatures(source_id).len() as u64
+    }
+
+    /// Ajouter le créateur d'une pétition fusionnée aux co-signataires de la cible
+    fn add_merged_co_sponsor(&self, target_id: u64, co_sponsor: &ManagedAddress) {
+        let mut target = self.require_petition(target_id);
+        if *co_sponsor == target.creator
+            || target.co_sponsors.contains(co_sponsor)
+            || target.co_sponsors.len() >= MAX_CO_SPONSORS
+        {
+            return;
+        }
+
+        target.co_sponsors.push(co_sponsor.clone());
+        self.store_petition(&target);
+
+        self.co_sponsor_added_event(target_id, co_sponsor);
+    }
+
+    /// Ajouter un co-signataire affiché sur la pétition (créateur uniquement)
+    #[endpoint(addCoSponsor)]
+    fn add_co_sponsor(&self, petition_id: u64, co_sponsor: ManagedAddress) {
+        let mut petition = self.require_petition(petition_id);
+        require!(
+            self.blockchain().get_caller() == petition.creator,
+            "Only creator can add co-sponsors"
+        );
+        require!(co_sponsor != petition.creator, "Creator cannot co-sponsor");
+        require!(!petition.co_sponsors.contains(&co_sponsor), "Already a co-sponsor");
+        require!(petition.co_sponsors.len() < MAX_CO_SPONSORS, "Too many co-sponsors");
+
+        petition.co_sponsors.push(co_sponsor.clone());
+        self.store_petition(&petition);
+
+        self.co_sponsor_added_event(petition_id, &co_sponsor);
+    }
+
+    /// Retirer un co-signataire (créateur, ou le co-signataire lui-même)
+    #[endpoint(removeCoSponsor)]
+    fn remove_co_sponsor(&self, petition_id: u64, co_sponsor: ManagedAddress) {
+        let caller = self.blockchain().get_caller();
+        let mut petition = self.require_petition(petition_id);
+        require!(
+            caller == petition.creator || caller == co_sponsor,
+            "Not authorized"
+        );
+
+        let index = petition.co_sponsors.find(&co_sponsor);
+        require!(index.is_some(), "Not a co-sponsor");
+        petition.co_sponsors.remove(index.unwrap());
+        self.store_petition(&petition);
+
+        self.co_sponsor_removed_event(petition_id, &co_sponsor);
+    }
+
+    // ========== MODÉRATION ==========
+
+    /// Masquer une pétition (modérateur)
+    ///
+    /// # Arguments
+    /// * `petition_id` - ID de la pétition
+    /// * `reason_hash` - Hash du motif de modération (publié hors-chaîne)
+    #[endpoint(hidePetition)]
+    fn hide_petition(&self, petition_id: u64, reason_hash: ManagedBuffer) {
+        require!(!reason_hash.is_empty(), "Reason hash required");
+
+        let caller = self.blockchain().get_caller();
+        require!(self.moderators().contains(&caller), "Only moderators can hide petitions");
+
+        let mut petition = self.require_petition(petition_id);
+        require!(!petition.hidden, "Petition already hidden");
+
+        petition.hidden = true;
+        self.store_petition(&petition);
+
+        self.moderation(petition_id).set(ModerationRecord {
+            moderator: caller.clone(),
+            reason_hash: reason_hash.clone(),
+            hidden_at: self.blockchain().get_block_timestamp(),
+            appeal_ipfs: None,
+            status: ModerationStatus::Hidden,
+        });
+
+        self.petition_hidden_event(petition_id, &caller, &reason_hash);
+    }
+
+    /// Faire appel d'un masquage (créateur)
+    ///
+    /// # Arguments
+    /// * `petition_id` - ID de la pétition
+    /// * `appeal_ipfs` - Hash IPFS de l'argumentaire d'appel
+    #[endpoint(appealModeration)]
+    fn appeal_moderation(&self, petition_id: u64, appeal_ipfs: ManagedBuffer) {
+        require!(is_valid_ipfs_cid(&appeal_ipfs), "Invalid IPFS CID");
+
+        let petition = self.require_petition(petition_id);
+        require!(
+            self.blockchain().get_caller() == petition.creator,
+            "Only creator can appeal"
+        );
+        require!(!self.moderation(petition_id).is_empty(), "Petition not moderated");
+
+        let mut record = self.moderation(petition_id).get();
+        require!(record.status == ModerationStatus::Hidden, "Appeal not possible");
+
+        record.appeal_ipfs = Some(appeal_ipfs.clone());
+        record.status = ModerationStatus::Appealed;
+        self.moderation(petition_id).set(record);
+
+        self.moderation_appealed_event(petition_id, &appeal_ipfs);
+    }
+
+    /// Trancher un appel de modération (admin)
+    ///
+    /// Si l'appel est rejeté, le masquage devient définitif et le dépôt est conservé.
+    #[only_owner]
+    #[endpoint(resolveAppeal)]
+    fn resolve_appeal(&self, petition_id: u64, restore: bool) {
+        require!(!self.moderation(petition_id).is_empty(), "Petition not moderated");
+
+        let mut record = self.moderation(petition_id).get();
+        require!(record.status == ModerationStatus::Appealed, "No pending appeal");
+
+        if restore {
+            let mut petition = self.require_petition(petition_id);
+            petition.hidden = false;
+            self.store_petition(&petition);
+            record.status = ModerationStatus::Restored;
+        } else {
+            self.forfeit_deposit(petition_id);
+            record.status = ModerationStatus::Upheld;
+        }
+        self.moderation(petition_id).set(record);
+
+        self.appeal_resolved_event(petition_id, restore);
+    }
+
+    /// Ajouter un modérateur (admin)
+    #[only_owner]
+    #[endpoint(addModerator)]
+    fn add_moderator(&self, address: ManagedAddress) {
+        self.moderators().insert(address);
+    }
+
+    /// Retirer un modérateur (admin)
+    #[only_owner]
+    #[endpoint(removeModerator)]
+    fn remove_moderator(&self, address: ManagedAddress) {
+        self.moderators().swap_remove(&address);
+    }
+
+    /// Configurer le dépôt de création anti-spam (admin)
+    ///
+    /// # Arguments
+    /// * `amount` - Dépôt en EGLD (0 pour désactiver)
+    /// * `refund_signatures` - Signatures requises pour le remboursement
+    #[only_owner]
+    #[endpoint(setCreationDeposit)]
+    fn set_creation_deposit(&self, amount: BigUint, refund_signatures: u64) {
+        self.creation_deposit().set(&amount);
+        self.deposit_refund_signatures().set(refund_signatures);
+    }
+
+    /// Retirer les dépôts conservés (admin)
+    #[only_owner]
+    #[endpoint(withdrawForfeitedDeposits)]
+    fn withdraw_forfeited_deposits(&self) {
+        let amount = self.forfeited_deposits().take();
+        require!(amount > 0u64, "Nothing to withdraw");
+
+        self.send().direct_egld(&self.blockchain().get_caller(), &amount);
+    }
+
+    /// Configurer l'adresse du contrat RIC (admin)
+    #[only_owner]
+    #[endpoint(setRICContract)]
+    fn set_ric_contract(&self, address: ManagedAddress) {
+        require!(
+            self.blockchain().is_smart_contract(&address),
+            "RIC contract must be a smart contract"
+        );
+        self.ric_contract_address().set(address);
+    }
+
+    /// Configurer l'adresse du contrat institution_registry (admin)
+    #[only_owner]
+    #[endpoint(setInstitutionRegistry)]
+    fn set_institution_registry(&self, address: ManagedAddress) {
+        require!(
+            self.blockchain().is_smart_contract(&address),
+            "Institution registry must be a smart contract"
+        );
+        self.institution_registry_address().set(address);
+    }
+
+    /// Publier la racine de Merkle de l'électorat d'un territoire (admin)
+    ///
+    /// # Arguments
+    /// * `scope` - Territoire concerné
+    /// * `merkle_root` - Racine de l'arbre des engagements d'électeurs (32 bytes)
+    #[only_owner]
+    #[endpoint(setElectorateRoot)]
+    fn set_electorate_root(&self, scope: TerritorialScope<Self::Api>, merkle_root: ManagedBuffer) {
+        require!(merkle_root.len() == MERKLE_ROOT_LEN, "Invalid Merkle root length");
+
+        self.electorate_root(&scope).set(&merkle_root);
+
+        self.electorate_root_updated_event(&scope, &merkle_root);
+    }
+
+    /// Configurer l'adresse du vérificateur de preuves (admin)
+    #[only_owner]
+    #[endpoint(setProofVerifier)]
+    fn set_proof_verifier(&self, address: ManagedAddress) {
+        self.proof_verifier_address().set(address);
+    }
+
+    /// Ajouter un répondant autorisé pour les pétitions nationales (admin)
     #[only_owner]
     #[endpoint(addAuthorizedResponder)]
     fn add_authorized_responder(&self, address: ManagedAddress) {
         self.authorized_responders().insert(address);
     }
 
-    /// Retirer une adresse autorisée (admin)
+    /// Retirer un répondant national autorisé (admin)
     #[only_owner]
     #[endpoint(removeAuthorizedResponder)]
     fn remove_authorized_responder(&self, address: ManagedAddress) {
@@ -430,8 +1565,7 @@ pub trait PetitionContract {
     /// Obtenir détails d'une pétition
     #[view(getPetition)]
     fn get_petition(&self, petition_id: u64) -> Petition<Self::Api> {
-        require!(self.petitions().contains_key(&petition_id), "Petition not found");
-        self.petitions().get(&petition_id).unwrap()
+        self.require_petition(petition_id)
     }
 
     /// Obtenir nombre total de pétitions
@@ -446,54 +1580,230 @@ pub trait PetitionContract {
         self.used_nullifiers(petition_id).contains(&nullifier)
     }
 
-    /// Obtenir toutes les pétitions actives
+    /// Obtenir la signature enregistrée pour un nullifier
+    #[view(getSignature)]
+    fn get_signature(&self, petition_id: u64, nullifier: ManagedBuffer) -> PetitionSignature<Self::Api> {
+        require!(
+            self.petition_signatures(petition_id).contains_key(&nullifier),
+            "Signature not found"
+        );
+        self.petition_signatures(petition_id).get(&nullifier).unwrap()
+    }
+
+    /// Obtenir les signatures d'une pétition (paginé, pour audit)
+    ///
+    /// Accès direct par position dans l'ensemble des nullifiers utilisés : le coût ne
+    /// dépend que de `limit`. Les nullifiers sans signature enregistrée (signatures
+    /// antérieures à l'upgrade, ou transférées lors d'une fusion) sont omis, la page
+    /// peut alors contenir moins de `limit` éléments.
+    #[view(getSignatures)]
+    fn get_signatures(
+        &self,
+        petition_id: u64,
+        offset: u64,
+        limit: u64,
+    ) -> MultiValueEncoded<PetitionSignature<Self::Api>> {
+        require!(limit <= MAX_PAGE_SIZE, "Page size too large");
+
+        let nullifiers = self.used_nullifiers(petition_id);
+        let total = nullifiers.len() as u64;
+        let start = offset.min(total);
+        let end = (start + limit).min(total);
+
+        let mut result = MultiValueEncoded::new();
+        for position in start..end {
+            let nullifier = nullifiers.get_by_index(position as usize + 1);
+            if let Some(signature) = self.petition_signatures(petition_id).get(&nullifier) {
+                result.push(signature);
+            }
+        }
+
+        result
+    }
+
+    /// Obtenir l'historique des versions du texte d'une pétition
+    #[view(getPetitionVersions)]
+    fn get_petition_versions(&self, petition_id: u64) -> MultiValueEncoded<PetitionVersion<Self::Api>> {
+        self.petition_versions(petition_id).iter().collect()
+    }
+
+    /// Obtenir les pétitions visibles qui collectent encore des signatures (paginé)
+    ///
+    /// Parcourt l'index `Active`, puis les index `ThresholdReached`, `ResponseProvided`
+    /// et `ResponseOverdue` où restent les pétitions nationales ayant atteint un palier.
+    /// Une page examine `limit` pétitions et ne retourne que celles visibles et encore
+    /// ouvertes à la signature : elle peut contenir moins de `limit` éléments.
     #[view(getActivePetitions)]
-    fn get_active_petitions(&self) -> MultiValueEncoded<Petition<Self::Api>> {
+    fn get_active_petitions(&self, offset: u64, limit: u64) -> MultiValueEncoded<Petition<Self::Api>> {
+        let current_timestamp = self.blockchain().get_block_timestamp();
+
+        self.statuses_page(
+            &[
+                PetitionStatus::Active,
+                PetitionStatus::ThresholdReached,
+                PetitionStatus::ResponseProvided,
+                PetitionStatus::ResponseOverdue,
+            ],
+            offset,
+            limit,
+            |_, petition| {
+                !petition.hidden
+                    && self.is_collecting(petition)
+                    && current_timestamp <= petition.deadline
+            },
+        )
+    }
+
+    /// Obtenir les pétitions d'un territoire (paginé)
+    ///
+    /// Le territoire est désigné par son code INSEE, département ou région.
+    #[view(getPetitionsByScope)]
+    fn get_petitions_by_scope(
+        &self,
+        scope: TerritorialScope<Self::Api>,
+        offset: u64,
+        limit: u64,
+    ) -> MultiValueEncoded<Petition<Self::Api>> {
+        self.petitions_page(&self.petitions_by_scope(&scope), offset, limit, true)
+    }
+
+    /// Obtenir les pétitions dans un statut donné (paginé)
+    #[view(getPetitionsByStatus)]
+    fn get_petitions_by_status(
+        &self,
+        status: PetitionStatus,
+        offset: u64,
+        limit: u64,
+    ) -> MultiValueEncoded<Petition<Self::Api>> {
+        self.petitions_page(&self.petitions_by_status(&status), offset, limit, true)
+    }
+
+    /// Nombre de pétitions d'un territoire
+    #[view(getPetitionCountByScope)]
+    fn get_petition_count_by_scope(&self, scope: TerritorialScope<Self::Api>) -> u64 {
+        self.petitions_by_scope(&scope).len() as u64
+    }
+
+    /// Nombre de pétitions dans un statut donné
+    #[view(getPetitionCountByStatus)]
+    fn get_petition_count_by_status(&self, status: PetitionStatus) -> u64 {
+        self.petitions_by_status(&status).len() as u64
+    }
+
+    /// Obtenir l'ID du RIC issu d'une pétition (0 si non convertie)
+    #[view(getPetitionRICId)]
+    fn get_petition_ric_id(&self, petition_id: u64) -> u64 {
+        self.petition_ric_id(petition_id).get()
+    }
+
+    /// Obtenir les paliers nationaux atteints et leurs suites
+    #[view(getTierMilestones)]
+    fn get_tier_milestones(&self, petition_id: u64) -> MultiValueEncoded<TierMilestone<Self::Api>> {
         let mut result = MultiValueEncoded::new();
-        let total = self.next_petition_id().get();
 
-        for id in 1..total {
-            if let Some(petition) = self.petitions().get(&id) {
-                if petition.status == PetitionStatus::Active {
-                    result.push(petition);
-                }
+        for tier in NATIONAL_TIERS {
+            if !self.tier_milestone(petition_id, tier).is_empty() {
+                result.push(self.tier_milestone(petition_id, tier).get());
             }
         }
 
         result
     }
 
-    /// Obtenir pétitions par créateur
-    #[view(getPetitionsByCreator)]
-    fn get_petitions_by_creator(
+    /// Obtenir les pétitions dont le délai de réponse est dépassé (paginé)
+    ///
+    /// Inclut les pétitions déjà constatées (`ResponseOverdue`) et celles dont
+    /// le délai est passé sans que `checkResponseDeadline` ait encore été appelé.
+    /// La pagination parcourt les index `ResponseOverdue`, `ThresholdReached` puis
+    /// `ResponsePending` : une page examine `limit` pétitions et ne retourne que celles
+    /// en retard, elle peut donc contenir moins de `limit` éléments.
+    #[view(getOverdueResponses)]
+    fn get_overdue_responses(&self, offset: u64, limit: u64) -> MultiValueEncoded<Petition<Self::Api>> {
+        let current_timestamp = self.blockchain().get_block_timestamp();
+
+        self.statuses_page(
+            &[
+                PetitionStatus::ResponseOverdue,
+                PetitionStatus::ThresholdReached,
+                PetitionStatus::ResponsePending,
+            ],
+            offset,
+            limit,
+            |status, petition| {
+                *status == PetitionStatus::ResponseOverdue
+                    || petition
+                        .response_deadline
+                        .is_some_and(|deadline| current_timestamp > deadline)
+            },
+        )
+    }
+
+    /// Charger une page de pétitions à travers plusieurs index de statut, dans l'ordre donné
+    ///
+    /// Une page examine `limit` pétitions et ne retourne que celles retenues par `keep`.
+    fn statuses_page<F>(
         &self,
-        creator: ManagedAddress,
-    ) -> MultiValueEncoded<Petition<Self::Api>> {
+        statuses: &[PetitionStatus],
+        offset: u64,
+        limit: u64,
+        keep: F,
+    ) -> MultiValueEncoded<Petition<Self::Api>>
+    where
+        F: Fn(&PetitionStatus, &Petition<Self::Api>) -> bool,
+    {
+        require!(limit <= MAX_PAGE_SIZE, "Page size too large");
+
         let mut result = MultiValueEncoded::new();
-        let total = self.next_petition_id().get();
+        let mut skip = offset;
+        let mut remaining = limit;
+
+        for status in statuses {
+            let index = self.petitions_by_status(status);
+            let total = index.len() as u64;
+            if skip >= total {
+                skip -= total;
+                continue;
+            }
 
-        for id in 1..total {
-            if let Some(petition) = self.petitions().get(&id) {
-                if petition.creator == creator {
-                    result.push(petition);
+            let end = (skip + remaining).min(total);
+            for position in skip..end {
+                let id = index.get_by_index(position as usize + 1);
+                if let Some(petition) = self.load_petition(id) {
+                    if keep(status, &petition) {
+                        result.push(petition);
+                    }
                 }
             }
+
+            remaining -= end - skip;
+            skip = 0;
+            if remaining == 0 {
+                break;
+            }
         }
 
         result
     }
 
+    /// Obtenir pétitions par créateur (paginé)
+    #[view(getPetitionsByCreator)]
+    fn get_petitions_by_creator(
+        &self,
+        creator: ManagedAddress,
+        offset: u64,
+        limit: u64,
+    ) -> MultiValueEncoded<Petition<Self::Api>> {
+        self.petitions_page(&self.petitions_by_creator(&creator), offset, limit, true)
+    }
+
     /// Obtenir statistiques d'une pétition
     #[view(getPetitionStats)]
     fn get_petition_stats(&self, petition_id: u64) -> MultiValue4<u64, u64, u32, bool> {
-        require!(self.petitions().contains_key(&petition_id), "Petition not found");
-        let petition = self.petitions().get(&petition_id).unwrap();
+        let petition = self.require_petition(petition_id);
 
-        let progress_percentage = if petition.target_signatures > 0 {
-            ((petition.signatures_count * 100) / petition.target_signatures) as u32
-        } else {
-            0u32
-        };
+        let progress_percentage = (petition.signatures_count * 100)
+            .checked_div(petition.target_signatures)
+            .unwrap_or(0) as u32;
 
         MultiValue4::from((
             petition.signatures_count,
@@ -505,18 +1815,99 @@ pub trait PetitionContract {
 
     // ========== STORAGE ==========
 
-    #[storage_mapper("petitions")]
+    /// Pétitions, dans la disposition courante
+    #[storage_mapper("petitionsV2")]
     fn petitions(&self) -> MapMapper<u64, Petition<Self::Api>>;
 
+    /// Pétitions créées avant l'upgrade et pas encore réécrites (ancienne disposition)
+    #[storage_mapper("petitions")]
+    fn legacy_petitions(&self) -> MapMapper<u64, LegacyPetition<Self::Api>>;
+
     #[storage_mapper("nextPetitionId")]
     fn next_petition_id(&self) -> SingleValueMapper<u64>;
 
     #[storage_mapper("usedNullifiers")]
     fn used_nullifiers(&self, petition_id: u64) -> UnorderedSetMapper<ManagedBuffer>;
 
+    /// Palier national atteint par une pétition (vide tant que le seuil n'est pas atteint)
+    #[storage_mapper("tierMilestone")]
+    fn tier_milestone(
+        &self,
+        petition_id: u64,
+        tier: NationalTier,
+    ) -> SingleValueMapper<TierMilestone<Self::Api>>;
+
+    #[storage_mapper("petitionSignatures")]
+    fn petition_signatures(
+        &self,
+        petition_id: u64,
+    ) -> MapMapper<ManagedBuffer, PetitionSignature<Self::Api>>;
+
+    #[storage_mapper("petitionVersions")]
+    fn petition_versions(&self, petition_id: u64) -> VecMapper<PetitionVersion<Self::Api>>;
+
+    // Index secondaires, tenus à jour par `store_petition`
+
+    #[storage_mapper("petitionsByScope")]
+    fn petitions_by_scope(&self, scope: &TerritorialScope<Self::Api>) -> UnorderedSetMapper<u64>;
+
+    #[storage_mapper("petitionsByStatus")]
+    fn petitions_by_status(&self, status: &PetitionStatus) -> UnorderedSetMapper<u64>;
+
+    #[storage_mapper("petitionsByCreator")]
+    fn petitions_by_creator(&self, creator: &ManagedAddress) -> UnorderedSetMapper<u64>;
+
+    #[view(getMergeApprovals)]
+    #[storage_mapper("mergeApprovals")]
+    fn merge_approvals(&self, source_id: u64, target_id: u64) -> UnorderedSetMapper<ManagedAddress>;
+
+    #[view(getModeration)]
+    #[storage_mapper("moderation")]
+    fn moderation(&self, petition_id: u64) -> SingleValueMapper<ModerationRecord<Self::Api>>;
+
+    #[storage_mapper("moderators")]
+    fn moderators(&self) -> UnorderedSetMapper<ManagedAddress>;
+
+    #[view(getCreationDeposit)]
+    #[storage_mapper("creationDeposit")]
+    fn creation_deposit(&self) -> SingleValueMapper<BigUint>;
+
+    #[view(getDepositRefundSignatures)]
+    #[storage_mapper("depositRefundSignatures")]
+    fn deposit_refund_signatures(&self) -> SingleValueMapper<u64>;
+
+    /// Dépôt de création en attente de remboursement
+    #[view(getPetitionDeposit)]
+    #[storage_mapper("petitionDeposit")]
+    fn petition_deposit(&self, petition_id: u64) -> SingleValueMapper<BigUint>;
+
+    #[storage_mapper("forfeitedDeposits")]
+    fn forfeited_deposits(&self) -> SingleValueMapper<BigUint>;
+
+    #[view(getElectorateRoot)]
+    #[storage_mapper("electorateRoot")]
+    fn electorate_root(&self, scope: &TerritorialScope<Self::Api>) -> SingleValueMapper<ManagedBuffer>;
+
+    #[view(getProofVerifier)]
+    #[storage_mapper("proofVerifier")]
+    fn proof_verifier_address(&self) -> SingleValueMapper<ManagedAddress>;
+
+    /// Répondants autorisés pour les pétitions nationales
     #[storage_mapper("authorizedResponders")]
     fn authorized_responders(&self) -> UnorderedSetMapper<ManagedAddress>;
 
+    #[view(getRICContract)]
+    #[storage_mapper("ricContractAddress")]
+    fn ric_contract_address(&self) -> SingleValueMapper<ManagedAddress>;
+
+    /// ID du RIC créé à partir d'une pétition
+    #[storage_mapper("petitionRicId")]
+    fn petition_ric_id(&self, petition_id: u64) -> SingleValueMapper<u64>;
+
+    #[view(getInstitutionRegistry)]
+    #[storage_mapper("institutionRegistryAddress")]
+    fn institution_registry_address(&self) -> SingleValueMapper<ManagedAddress>;
+
     // ========== EVENTS ==========
 
     #[event("petitionCreated")]
@@ -536,6 +1927,98 @@ pub trait PetitionContract {
         total_signatures: u64,
     );
 
+    #[event("signatureWithdrawn")]
+    fn signature_withdrawn_event(
+        &self,
+        #[indexed] petition_id: u64,
+        #[indexed] nullifier: &ManagedBuffer,
+        total_signatures: u64,
+    );
+
+    #[event("signatureReconfirmed")]
+    fn signature_reconfirmed_event(
+        &self,
+        #[indexed] petition_id: u64,
+        #[indexed] nullifier: &ManagedBuffer,
+        version: u32,
+    );
+
+    #[event("petitionAmended")]
+    fn petition_amended_event(
+        &self,
+        #[indexed] petition_id: u64,
+        #[indexed] version: u32,
+        description_ipfs: &ManagedBuffer,
+    );
+
+    #[event("mergeApproved")]
+    fn merge_approved_event(
+        &self,
+        #[indexed] source_id: u64,
+        #[indexed] target_id: u64,
+        #[indexed] approver: &ManagedAddress,
+    );
+
+    #[event("petitionsMerged")]
+    fn petitions_merged_event(
+        &self,
+        #[indexed] source_id: u64,
+        #[indexed] target_id: u64,
+    );
+
+    #[event("mergedSignatureImported")]
+    fn merged_signature_imported_event(
+        &self,
+        #[indexed] source_id: u64,
+        #[indexed] target_id: u64,
+        #[indexed] merge_nullifier: &ManagedBuffer,
+        duplicate: bool,
+    );
+
+    #[event("depositRefunded")]
+    fn deposit_refunded_event(
+        &self,
+        #[indexed] petition_id: u64,
+        #[indexed] creator: &ManagedAddress,
+        amount: &BigUint,
+    );
+
+    #[event("coSponsorAdded")]
+    fn co_sponsor_added_event(
+        &self,
+        #[indexed] petition_id: u64,
+        #[indexed] co_sponsor: &ManagedAddress,
+    );
+
+    #[event("coSponsorRemoved")]
+    fn co_sponsor_removed_event(
+        &self,
+        #[indexed] petition_id: u64,
+        #[indexed] co_sponsor: &ManagedAddress,
+    );
+
+    #[event("petitionHidden")]
+    fn petition_hidden_event(
+        &self,
+        #[indexed] petition_id: u64,
+        #[indexed] moderator: &ManagedAddress,
+        reason_hash: &ManagedBuffer,
+    );
+
+    #[event("moderationAppealed")]
+    fn moderation_appealed_event(
+        &self,
+        #[indexed] petition_id: u64,
+        appeal_ipfs: &ManagedBuffer,
+    );
+
+    #[event("appealResolved")]
+    fn appeal_resolved_event(
+        &self,
+        #[indexed] petition_id: u64,
+        restored: bool,
+    );
+
     #[event("thresholdReached")]
     fn threshold_reached_event(
         &self,
@@ -573,6 +2056,51 @@ pub trait PetitionContract {
         response_ipfs: &ManagedBuffer,
     );
 
+    #[event("tierResponse")]
+    fn tier_response_event(
+        &self,
+        #[indexed] petition_id: u64,
+        #[indexed] tier: NationalTier,
+        #[indexed] responder: &ManagedAddress,
+        response_ipfs: &ManagedBuffer,
+    );
+
+    #[event("debateScheduled")]
+    fn debate_scheduled_event(
+        &self,
+        #[indexed] petition_id: u64,
+        debate_at: u64,
+    );
+
+    #[event("inquiryOutcome")]
+    fn inquiry_outcome_event(
+        &self,
+        #[indexed] petition_id: u64,
+        outcome_ipfs: &ManagedBuffer,
+    );
+
+    #[event("electorateRootUpdated")]
+    fn electorate_root_updated_event(
+        &self,
+        #[indexed] scope: &TerritorialScope<Self::Api>,
+        merkle_root: &ManagedBuffer,
+    );
+
+    #[event("responseOverdue")]
+    fn response_overdue_event(
+        &self,
+        #[indexed] petition_id: u64,
+        response_deadline: u64,
+    );
+
+    #[event("petitionEscalated")]
+    fn petition_escalated_event(
+        &self,
+        #[indexed] petition_id: u64,
+        #[indexed] from_scope: &TerritorialScope<Self::Api>,
+        to_scope: &TerritorialScope<Self::Api>,
+    );
+
     #[event("ricConversion")]
     fn ric_conversion_event(
         &self,
@@ -591,6 +2119,12 @@ pub trait PetitionContract {
 
     #[proxy]
     fn ric_proxy(&self, sc_address: ManagedAddress) -> ric_proxy::Proxy<Self::Api>;
+
+    #[proxy]
+    fn institution_registry_proxy(
+        &self,
+        sc_address: ManagedAddress,
+    ) -> institution_registry_proxy::Proxy<Self::Api>;
 }
 
 /// Proxy pour appels cross-contract au RIC contract
@@ -602,10 +2136,32 @@ mod ric_proxy {
         #[endpoint(createRICFromPetition)]
         fn create_ric_from_petition(
             &self,
+            petition_id: u64,
+            ric_type: super::RICType,
             title: ManagedBuffer,
             description_ipfs: ManagedBuffer,
             scope: super::TerritorialScope<Self::Api>,
-            initial_signatures: u64,
+            signatures_count: u64,
         ) -> u64;
     }
 }
+
+/// Proxy pour appels cross-contract au registre des institutions
+mod institution_registry_proxy {
+    multiversx_sc::imports!();
+
+    #[multiversx_sc::proxy]
+    pub trait InstitutionRegistryProxy {
+        #[view(getInstitutionIdByScope)]
+        fn get_institution_id_by_scope(&self, scope: super::TerritorialScope<Self::Api>) -> u64;
+
+        #[view(getPetitionThresholdData)]
+        fn get_petition_threshold_data(&self, institution_id: u64) -> MultiValue3<u64, u64, u32>;
+
+        #[view(getEscalationSettings)]
+        fn get_escalation_settings(&self, institution_id: u64) -> MultiValue2<bool, ManagedBuffer>;
+
+        #[view(isInstitutionAdmin)]
+        fn is_institution_admin(&self, institution_id: u64, address: ManagedAddress) -> bool;
+    }
+}
@@ -0,0 +1 @@
+mod petition_test;
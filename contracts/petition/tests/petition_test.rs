@@ -0,0 +1,1274 @@
+use institution_registry::InstitutionRegistryContract;
+use multiversx_sc_scenario::imports::*;
+use petition::PetitionContract;
+
+const PETITION_CODE: MxscPath = MxscPath::new("output/petition.mxsc.json");
+const INSTITUTION_REGISTRY_CODE: MxscPath =
+    MxscPath::new("../institution_registry/output/institution_registry.mxsc.json");
+
+const PETITION_ADDRESS: TestSCAddress = TestSCAddress::new("petition");
+const INSTITUTION_REGISTRY_ADDRESS: TestSCAddress = TestSCAddress::new("institution-registry");
+
+const OWNER_ADDRESS: TestAddress = TestAddress::new("owner");
+const CREATOR_ADDRESS: TestAddress = TestAddress::new("creator");
+const CITIZEN_ADDRESS: TestAddress = TestAddress::new("citizen");
+const MODERATOR_ADDRESS: TestAddress = TestAddress::new("moderator");
+const VERIFIER_ADDRESS: TestAddress = TestAddress::new("verifier");
+const LYON_ADMIN_ADDRESS: TestAddress = TestAddress::new("lyon-admin");
+const RHONE_ADMIN_ADDRESS: TestAddress = TestAddress::new("rhone-admin");
+const NATIONAL_RESPONDER_ADDRESS: TestAddress = TestAddress::new("national-responder");
+
+const LYON_INSEE_CODE: &[u8] = b"69123";
+const RHONE_CODE: &[u8] = b"69";
+const RESPONSE_IPFS: &[u8] = b"QmT78zSuBmuS4z925WZfrqQ1qHaJ56DQaTfyMUF7F8ff5o";
+const MERKLE_ROOT: [u8; 32] = [7u8; 32];
+const RESPONSE_DELAY: u64 = 30 * 86400;
+const DESCRIPTION_IPFS: &[u8] = b"QmYwAPJzv5CZsnA625s3Xf2nemtYgPpHdWEz79ojWnPbdG";
+
+fn world() -> ScenarioWorld {
+    let mut blockchain = ScenarioWorld::new();
+    blockchain.register_contract(PETITION_CODE, petition::ContractBuilder);
+    blockchain.register_contract(
+        INSTITUTION_REGISTRY_CODE,
+        institution_registry::ContractBuilder,
+    );
+    blockchain
+}
+
+fn lyon_scope() -> petition::TerritorialScope<DebugApi> {
+    petition::TerritorialScope::Commune {
+        insee_code: managed_buffer!(LYON_INSEE_CODE),
+    }
+}
+
+fn rhone_scope() -> petition::TerritorialScope<DebugApi> {
+    petition::TerritorialScope::Department {
+        code: managed_buffer!(RHONE_CODE),
+    }
+}
+
+fn deploy(world: &mut ScenarioWorld) {
+    for account in [
+        OWNER_ADDRESS,
+        CREATOR_ADDRESS,
+        CITIZEN_ADDRESS,
+        MODERATOR_ADDRESS,
+        LYON_ADMIN_ADDRESS,
+        RHONE_ADMIN_ADDRESS,
+        NATIONAL_RESPONDER_ADDRESS,
+    ] {
+        world.account(account).nonce(1).balance(1_000u64);
+    }
+    world.current_block().block_timestamp(1000);
+
+    world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .raw_deploy()
+        .code(PETITION_CODE)
+        .new_address(PETITION_ADDRESS)
+        .whitebox(petition::contract_obj, |sc| {
+            sc.init();
+        });
+}
+
+/// Configure le vérificateur de preuves, la racine de l'électorat national et un modérateur
+fn setup_national(world: &mut ScenarioWorld) {
+    deploy(world);
+
+    world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(PETITION_ADDRESS)
+        .whitebox(petition::contract_obj, |sc| {
+            sc.set_proof_verifier(VERIFIER_ADDRESS.to_managed_address());
+            sc.set_electorate_root(
+                petition::TerritorialScope::National,
+                managed_buffer!(&MERKLE_ROOT[..]),
+            );
+            sc.add_moderator(MODERATOR_ADDRESS.to_managed_address());
+            sc.add_authorized_responder(NATIONAL_RESPONDER_ADDRESS.to_managed_address());
+        });
+}
+
+/// Ajoute le registre des institutions avec Lyon (300 000 électeurs, rattachée au
+/// Rhône) et le département du Rhône, chacun avec son administrateur
+fn setup_local(world: &mut ScenarioWorld) {
+    setup_national(world);
+
+    world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .raw_deploy()
+        .code(INSTITUTION_REGISTRY_CODE)
+        .new_address(INSTITUTION_REGISTRY_ADDRESS)
+        .whitebox(institution_registry::contract_obj, |sc| {
+            sc.init();
+        });
+
+    world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(INSTITUTION_REGISTRY_ADDRESS)
+        .whitebox(institution_registry::contract_obj, |sc| {
+            let lyon_id = sc.register_institution(
+                managed_buffer!(LYON_INSEE_CODE),
+                managed_buffer!(b"216901231"),
+                managed_buffer!(b"Lyon"),
+                managed_buffer!(b"lyon"),
+                institution_registry::InstitutionLevel::Commune,
+                522_000u64,
+                300_000u64,
+                managed_buffer!(b"84"),
+                managed_buffer!(RHONE_CODE),
+                managed_buffer!(b"45.7578"),
+                managed_buffer!(b"4.8320"),
+            );
+            sc.add_institution_admin(lyon_id, LYON_ADMIN_ADDRESS.to_managed_address());
+
+            let rhone_id = sc.register_institution(
+                managed_buffer!(RHONE_CODE),
+                managed_buffer!(b"226900017"),
+                managed_buffer!(b"Rhone"),
+                managed_buffer!(b"rhone"),
+                institution_registry::InstitutionLevel::Department,
+                1_900_000u64,
+                1_200_000u64,
+                managed_buffer!(b"84"),
+                managed_buffer!(b""),
+                managed_buffer!(b"45.7500"),
+                managed_buffer!(b"4.8500"),
+            );
+            sc.add_institution_admin(rhone_id, RHONE_ADMIN_ADDRESS.to_managed_address());
+        });
+
+    world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(PETITION_ADDRESS)
+        .whitebox(petition::contract_obj, |sc| {
+            sc.set_institution_registry(INSTITUTION_REGISTRY_ADDRESS.to_managed_address());
+            sc.set_electorate_root(lyon_scope(), managed_buffer!(&MERKLE_ROOT[..]));
+        });
+}
+
+/// Crée une pétition locale à Lyon et retourne son ID
+fn create_lyon_petition(world: &mut ScenarioWorld) -> u64 {
+    let mut petition_id = 0u64;
+    world
+        .tx()
+        .from(CREATOR_ADDRESS)
+        .to(PETITION_ADDRESS)
+        .whitebox(petition::contract_obj, |sc| {
+            petition_id = sc.create_petition(
+                managed_buffer!(b"Pistes cyclables"),
+                managed_buffer!(DESCRIPTION_IPFS),
+                lyon_scope(),
+                petition::PetitionType::Local,
+                30u64,
+            );
+        });
+    petition_id
+}
+
+/// Simule des signatures déjà collectées, pour atteindre un seuil sans les signer une à une
+fn seed_signatures_count(world: &mut ScenarioWorld, petition_id: u64, count: u64) {
+    world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(PETITION_ADDRESS)
+        .whitebox(petition::contract_obj, |sc| {
+            let mut petition = sc.get_petition(petition_id);
+            petition.signatures_count = count;
+            sc.store_petition(&petition);
+        });
+}
+
+/// Porte une pétition à son seuil : signatures simulées puis une dernière signature
+/// Amène le compteur juste sous `target` puis signe réellement, pour déclencher le passage de seuil
+fn reach_threshold(world: &mut ScenarioWorld, petition_id: u64, target: u64) {
+    seed_signatures_count(world, petition_id, target - 1);
+    sign(world, petition_id, target as u32, None);
+}
+
+fn respond(
+    world: &mut ScenarioWorld,
+    from: TestAddress,
+    petition_id: u64,
+    expected_error: Option<&str>,
+) {
+    let tx = world.tx().from(from).to(PETITION_ADDRESS);
+    let call = |sc: petition::ContractObj<DebugApi>| {
+        sc.respond_to_petition(petition_id, managed_buffer!(RESPONSE_IPFS));
+    };
+    match expected_error {
+        Some(message) => tx
+            .returns(ExpectError(4, message))
+            .whitebox(petition::contract_obj, call),
+        None => tx.whitebox(petition::contract_obj, call),
+    }
+}
+
+fn respond_to_tier(
+    world: &mut ScenarioWorld,
+    from: TestAddress,
+    petition_id: u64,
+    tier: petition::NationalTier,
+    expected_error: Option<&str>,
+) {
+    let tx = world.tx().from(from).to(PETITION_ADDRESS);
+    let call = |sc: petition::ContractObj<DebugApi>| {
+        sc.respond_to_national_tier(petition_id, tier, managed_buffer!(RESPONSE_IPFS));
+    };
+    match expected_error {
+        Some(message) => tx
+            .returns(ExpectError(4, message))
+            .whitebox(petition::contract_obj, call),
+        None => tx.whitebox(petition::contract_obj, call),
+    }
+}
+
+fn check_response_deadline(
+    world: &mut ScenarioWorld,
+    petition_id: u64,
+    expected_error: Option<&str>,
+) {
+    let tx = world.tx().from(CITIZEN_ADDRESS).to(PETITION_ADDRESS);
+    let call = |sc: petition::ContractObj<DebugApi>| {
+        sc.check_response_deadline(petition_id);
+    };
+    match expected_error {
+        Some(message) => tx
+            .returns(ExpectError(4, message))
+            .whitebox(petition::contract_obj, call),
+        None => tx.whitebox(petition::contract_obj, call),
+    }
+}
+
+/// Action de signature sur une signature existante (retrait ou reconfirmation)
+fn update_signature(
+    world: &mut ScenarioWorld,
+    petition_id: u64,
+    seed: u32,
+    withdraw: bool,
+    expected_error: Option<&str>,
+) {
+    let tx = world.tx().from(CITIZEN_ADDRESS).to(PETITION_ADDRESS);
+    let call = |sc: petition::ContractObj<DebugApi>| {
+        let nullifier = managed_buffer!(nullifier(seed).as_bytes());
+        let proof = managed_buffer!(b"groth16_membership_proof");
+        if withdraw {
+            sc.withdraw_signature(petition_id, nullifier, proof);
+        } else {
+            sc.reconfirm_signature(petition_id, nullifier, proof);
+        }
+    };
+    match expected_error {
+        Some(message) => tx
+            .returns(ExpectError(4, message))
+            .whitebox(petition::contract_obj, call),
+        None => tx.whitebox(petition::contract_obj, call),
+    }
+}
+
+fn get_petition<F>(world: &mut ScenarioWorld, petition_id: u64, check: F)
+where
+    F: FnOnce(petition::Petition<DebugApi>),
+{
+    world
+        .query()
+        .to(PETITION_ADDRESS)
+        .whitebox(petition::contract_obj, |sc| {
+            check(sc.get_petition(petition_id));
+        });
+}
+
+/// Crée une pétition nationale de 30 jours et retourne son ID
+fn create_national_petition(world: &mut ScenarioWorld) -> u64 {
+    let mut petition_id = 0u64;
+    world
+        .tx()
+        .from(CREATOR_ADDRESS)
+        .to(PETITION_ADDRESS)
+        .whitebox(petition::contract_obj, |sc| {
+            petition_id = sc.create_petition(
+                managed_buffer!(b"Referendum sur les retraites"),
+                managed_buffer!(DESCRIPTION_IPFS),
+                petition::TerritorialScope::National,
+                petition::PetitionType::National,
+                30u64,
+            );
+        });
+    petition_id
+}
+
+/// Nullifier de test (64 caractères)
+fn nullifier(seed: u32) -> String {
+    format!("{:064}", seed)
+}
+
+fn sign(world: &mut ScenarioWorld, petition_id: u64, seed: u32, expected_error: Option<&str>) {
+    let tx = world.tx().from(CITIZEN_ADDRESS).to(PETITION_ADDRESS);
+    let call = |sc: petition::ContractObj<DebugApi>| {
+        sc.sign_petition(
+            petition_id,
+            managed_buffer!(nullifier(seed).as_bytes()),
+            managed_buffer!(b"groth16_membership_proof"),
+        );
+    };
+    match expected_error {
+        Some(message) => tx
+            .returns(ExpectError(4, message))
+            .whitebox(petition::contract_obj, call),
+        None => tx.whitebox(petition::contract_obj, call),
+    }
+}
+
+/// Transfère la signature `source_seed` de la pétition fusionnée vers la cible
+fn import_merged_signature(
+    world: &mut ScenarioWorld,
+    source_id: u64,
+    source_seed: u32,
+    target_seed: u32,
+    proof: &[u8],
+    expected_error: Option<&str>,
+) -> u64 {
+    let mut remaining = 0u64;
+    let tx = world.tx().from(CITIZEN_ADDRESS).to(PETITION_ADDRESS);
+    let call = |sc: petition::ContractObj<DebugApi>| {
+        remaining = sc.import_merged_signature(
+            source_id,
+            managed_buffer!(nullifier(source_seed).as_bytes()),
+            managed_buffer!(nullifier(target_seed).as_bytes()),
+            managed_buffer!(proof),
+        );
+    };
+    match expected_error {
+        Some(message) => tx
+            .returns(ExpectError(4, message))
+            .whitebox(petition::contract_obj, call),
+        None => tx.whitebox(petition::contract_obj, call),
+    }
+    remaining
+}
+
+fn signatures_count(world: &mut ScenarioWorld, petition_id: u64) -> u64 {
+    let mut count = 0u64;
+    world
+        .query()
+        .to(PETITION_ADDRESS)
+        .whitebox(petition::contract_obj, |sc| {
+            count = sc.get_petition(petition_id).signatures_count;
+        });
+    count
+}
+
+/// Pétition dans la disposition d'avant l'upgrade (clé `petitions`)
+fn legacy_petition(
+    id: u64,
+    scope: petition::TerritorialScope<DebugApi>,
+    petition_type: petition::PetitionType,
+    status: petition::PetitionStatus,
+) -> petition::LegacyPetition<DebugApi> {
+    petition::LegacyPetition {
+        id,
+        title: managed_buffer!(b"Pistes cyclables"),
+        description_ipfs: managed_buffer!(DESCRIPTION_IPFS),
+        scope,
+        petition_type,
+        creator: CREATOR_ADDRESS.to_managed_address(),
+        created_at: 1000,
+        deadline: 1000 + 30 * 86400,
+        signatures_count: 42,
+        target_signatures: 100,
+        threshold_percentage: 5,
+        population_base: 2_000,
+        status,
+        debate_triggered: false,
+        inquiry_triggered: false,
+        ric_eligible: false,
+        response_ipfs: None,
+        response_deadline: None,
+        responder: None,
+    }
+}
+
+/// IDs d'une page de pétitions
+fn petition_ids(page: MultiValueEncoded<DebugApi, petition::Petition<DebugApi>>) -> Vec<u64> {
+    page.into_iter().map(|petition| petition.id).collect()
+}
+
+#[test]
+fn test_rebuild_indexes_with_legacy_petitions() {
+    let mut world = world();
+    deploy(&mut world);
+
+    // Pétitions enregistrées par la version précédente du contrat, sans index
+    world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(PETITION_ADDRESS)
+        .whitebox(petition::contract_obj, |sc| {
+            sc.legacy_petitions().insert(
+                1u64,
+                legacy_petition(
+                    1,
+                    lyon_scope(),
+                    petition::PetitionType::Local,
+                    petition::PetitionStatus::Active,
+                ),
+            );
+            sc.legacy_petitions().insert(
+                2u64,
+                legacy_petition(
+                    2,
+                    petition::TerritorialScope::National,
+                    petition::PetitionType::National,
+                    petition::PetitionStatus::ResponseProvided,
+                ),
+            );
+            sc.next_petition_id().set(3u64);
+        });
+
+    world
+        .query()
+        .to(PETITION_ADDRESS)
+        .whitebox(petition::contract_obj, |sc| {
+            let petition = sc.get_petition(1u64);
+            assert_eq!(petition.signatures_count, 42u64);
+            assert_eq!(petition.version, 1u32);
+            assert!(!petition.hidden);
+            assert!(petition.merged_into.is_none());
+
+            assert_eq!(
+                sc.get_petition_count_by_status(petition::PetitionStatus::Active),
+                0u64
+            );
+        });
+
+    world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(PETITION_ADDRESS)
+        .whitebox(petition::contract_obj, |sc| {
+            sc.rebuild_indexes(1u64, 2u64);
+        });
+
+    world
+        .query()
+        .to(PETITION_ADDRESS)
+        .whitebox(petition::contract_obj, |sc| {
+            assert_eq!(
+                sc.get_petition_count_by_status(petition::PetitionStatus::Active),
+                1u64
+            );
+            assert_eq!(
+                sc.get_petition_count_by_status(petition::PetitionStatus::ResponseProvided),
+                1u64
+            );
+            assert_eq!(sc.get_petition_count_by_scope(lyon_scope()), 1u64);
+
+            // La pétition nationale a répondu à un palier mais collecte encore
+            assert_eq!(
+                petition_ids(sc.get_active_petitions(0u64, 10u64)),
+                vec![1u64, 2u64]
+            );
+            assert_eq!(
+                petition_ids(sc.get_petitions_by_creator(
+                    CREATOR_ADDRESS.to_managed_address(),
+                    0u64,
+                    10u64
+                )),
+                vec![1u64, 2u64]
+            );
+        });
+
+    // La prochaine écriture migre la pétition vers la nouvelle disposition
+    world.current_block().block_timestamp(1000 + 30 * 86400 + 1);
+
+    world
+        .tx()
+        .from(CREATOR_ADDRESS)
+        .to(PETITION_ADDRESS)
+        .whitebox(petition::contract_obj, |sc| {
+            sc.close_petition(1u64);
+        });
+
+    world
+        .query()
+        .to(PETITION_ADDRESS)
+        .whitebox(petition::contract_obj, |sc| {
+            assert!(sc.legacy_petitions().get(&1u64).is_none());
+            assert_eq!(
+                sc.get_petition(1u64).status,
+                petition::PetitionStatus::Closed
+            );
+            assert_eq!(
+                sc.get_petition_count_by_status(petition::PetitionStatus::Active),
+                0u64
+            );
+            assert_eq!(
+                sc.get_petition_count_by_status(petition::PetitionStatus::Closed),
+                1u64
+            );
+        });
+}
+
+#[test]
+fn test_merge_counts_duplicate_signers_once() {
+    let mut world = world();
+    setup_national(&mut world);
+
+    let target_id = create_national_petition(&mut world);
+    let source_id = create_national_petition(&mut world);
+
+    // Le citoyen 1 signe les deux pétitions, avec un nullifier propre à chacune ;
+    // le citoyen 2 ne signe que la source
+    sign(&mut world, target_id, 101, None);
+    sign(&mut world, source_id, 201, None);
+    sign(&mut world, source_id, 202, None);
+
+    world
+        .tx()
+        .from(MODERATOR_ADDRESS)
+        .to(PETITION_ADDRESS)
+        .whitebox(petition::contract_obj, |sc| {
+            sc.approve_merge(source_id, target_id);
+        });
+
+    // Sans preuve liant les deux nullifiers, le transfert est refusé
+    import_merged_signature(
+        &mut world,
+        source_id,
+        201,
+        101,
+        b"",
+        Some("Invalid zk-SNARK proof"),
+    );
+
+    // Le citoyen 1 a déjà signé la cible : sa signature est écartée
+    let remaining = import_merged_signature(&mut world, source_id, 201, 101, b"merge_proof", None);
+    assert_eq!(remaining, 1u64);
+    assert_eq!(signatures_count(&mut world, target_id), 1u64);
+
+    let remaining = import_merged_signature(&mut world, source_id, 202, 102, b"merge_proof", None);
+    assert_eq!(remaining, 0u64);
+    assert_eq!(signatures_count(&mut world, target_id), 2u64);
+    assert_eq!(signatures_count(&mut world, source_id), 0u64);
+
+    // Une signature ne se transfère qu'une fois, et son nullifier est consommé sur la cible
+    import_merged_signature(
+        &mut world,
+        source_id,
+        202,
+        102,
+        b"merge_proof",
+        Some("Signature not found"),
+    );
+    sign(
+        &mut world,
+        target_id,
+        102,
+        Some("Already signed (nullifier used)"),
+    );
+}
+
+#[test]
+fn test_import_merged_signature_requires_collecting_target() {
+    let mut world = world();
+    setup_national(&mut world);
+
+    let target_id = create_national_petition(&mut world);
+    let source_id = create_national_petition(&mut world);
+    sign(&mut world, source_id, 201, None);
+
+    world
+        .tx()
+        .from(MODERATOR_ADDRESS)
+        .to(PETITION_ADDRESS)
+        .whitebox(petition::contract_obj, |sc| {
+            sc.approve_merge(source_id, target_id);
+        });
+
+    // La cible n'accepte plus de signatures après sa date limite
+    world.current_block().block_timestamp(1000 + 30 * 86400 + 1);
+    import_merged_signature(
+        &mut world,
+        source_id,
+        201,
+        101,
+        b"merge_proof",
+        Some("Target petition deadline expired"),
+    );
+
+    world
+        .tx()
+        .from(CREATOR_ADDRESS)
+        .to(PETITION_ADDRESS)
+        .whitebox(petition::contract_obj, |sc| {
+            sc.close_petition(target_id);
+        });
+    import_merged_signature(
+        &mut world,
+        source_id,
+        201,
+        101,
+        b"merge_proof",
+        Some("Target petition not active"),
+    );
+    assert_eq!(signatures_count(&mut world, source_id), 1u64);
+}
+
+#[test]
+fn test_get_signatures_pages_by_position() {
+    let mut world = world();
+    setup_national(&mut world);
+
+    let petition_id = create_national_petition(&mut world);
+    for seed in 1..=3u32 {
+        sign(&mut world, petition_id, seed, None);
+    }
+
+    world
+        .query()
+        .to(PETITION_ADDRESS)
+        .whitebox(petition::contract_obj, |sc| {
+            assert_eq!(
+                sc.get_signatures(petition_id, 0u64, 2u64)
+                    .into_iter()
+                    .count(),
+                2
+            );
+            assert_eq!(
+                sc.get_signatures(petition_id, 2u64, 2u64)
+                    .into_iter()
+                    .count(),
+                1
+            );
+            assert_eq!(
+                sc.get_signatures(petition_id, 5u64, 2u64)
+                    .into_iter()
+                    .count(),
+                0
+            );
+        });
+
+    world
+        .query()
+        .to(PETITION_ADDRESS)
+        .returns(ExpectError(4, "Page size too large"))
+        .whitebox(petition::contract_obj, |sc| {
+            sc.get_signatures(petition_id, 0u64, 101u64);
+        });
+}
+
+#[test]
+fn test_active_petitions_include_national_petitions_past_a_tier() {
+    let mut world = world();
+    setup_national(&mut world);
+
+    let first_id = create_national_petition(&mut world);
+    let second_id = create_national_petition(&mut world);
+
+    // Palier débat atteint : la pétition quitte l'index `Active` mais collecte encore
+    world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(PETITION_ADDRESS)
+        .whitebox(petition::contract_obj, |sc| {
+            let mut petition = sc.get_petition(first_id);
+            petition.status = petition::PetitionStatus::ThresholdReached;
+            petition.debate_triggered = true;
+            sc.store_petition(&petition);
+        });
+
+    world
+        .query()
+        .to(PETITION_ADDRESS)
+        .whitebox(petition::contract_obj, |sc| {
+            assert_eq!(
+                petition_ids(sc.get_active_petitions(0u64, 10u64)),
+                vec![second_id, first_id]
+            );
+            assert_eq!(
+                petition_ids(sc.get_active_petitions(1u64, 10u64)),
+                vec![first_id]
+            );
+        });
+
+    // Après la date limite, plus aucune ne collecte
+    world.current_block().block_timestamp(1000 + 30 * 86400 + 1);
+
+    world
+        .query()
+        .to(PETITION_ADDRESS)
+        .whitebox(petition::contract_obj, |sc| {
+            assert!(petition_ids(sc.get_active_petitions(0u64, 10u64)).is_empty());
+        });
+}
+
+#[test]
+fn test_only_territory_institution_admin_can_respond() {
+    let mut world = world();
+    setup_local(&mut world);
+
+    let petition_id = create_lyon_petition(&mut world);
+
+    // Pas de réponse avant l'atteinte du seuil
+    respond(
+        &mut world,
+        LYON_ADMIN_ADDRESS,
+        petition_id,
+        Some("Petition not awaiting response"),
+    );
+
+    reach_threshold(&mut world, petition_id, 15_000);
+
+    // Ni un citoyen, ni l'administrateur d'une autre institution, ni un répondant national
+    respond(
+        &mut world,
+        CITIZEN_ADDRESS,
+        petition_id,
+        Some("Not authorized to respond"),
+    );
+    respond(
+        &mut world,
+        RHONE_ADMIN_ADDRESS,
+        petition_id,
+        Some("Not authorized to respond"),
+    );
+    respond(
+        &mut world,
+        NATIONAL_RESPONDER_ADDRESS,
+        petition_id,
+        Some("Not authorized to respond"),
+    );
+
+    respond(&mut world, LYON_ADMIN_ADDRESS, petition_id, None);
+    get_petition(&mut world, petition_id, |petition| {
+        assert_eq!(petition.status, petition::PetitionStatus::ResponseProvided);
+        assert_eq!(
+            petition.responder,
+            Some(LYON_ADMIN_ADDRESS.to_managed_address())
+        );
+    });
+}
+
+#[test]
+fn test_threshold_derived_from_institution_registry() {
+    let mut world = world();
+    setup_local(&mut world);
+
+    // 5% par défaut des 300 000 électeurs de Lyon
+    let petition_id = create_lyon_petition(&mut world);
+    get_petition(&mut world, petition_id, |petition| {
+        assert_eq!(petition.population_base, 300_000u64);
+        assert_eq!(petition.threshold_percentage, 5u32);
+        assert_eq!(petition.target_signatures, 15_000u64);
+    });
+
+    // Seuil personnalisé par la commune
+    world
+        .tx()
+        .from(LYON_ADMIN_ADDRESS)
+        .to(INSTITUTION_REGISTRY_ADDRESS)
+        .whitebox(institution_registry::contract_obj, |sc| {
+            sc.enable_democratix(1u64, false, 2u32, 5u32);
+        });
+
+    let petition_id = create_lyon_petition(&mut world);
+    get_petition(&mut world, petition_id, |petition| {
+        assert_eq!(petition.threshold_percentage, 2u32);
+        assert_eq!(petition.target_signatures, 6_000u64);
+    });
+
+    // Une commune absente du registre n'a pas de seuil
+    world
+        .tx()
+        .from(CREATOR_ADDRESS)
+        .to(PETITION_ADDRESS)
+        .returns(ExpectError(
+            4,
+            "No institution registered for this territory",
+        ))
+        .whitebox(petition::contract_obj, |sc| {
+            sc.create_petition(
+                managed_buffer!(b"Pistes cyclables"),
+                managed_buffer!(DESCRIPTION_IPFS),
+                petition::TerritorialScope::Commune {
+                    insee_code: managed_buffer!(b"75056"),
+                },
+                petition::PetitionType::Local,
+                30u64,
+            );
+        });
+}
+
+#[test]
+fn test_overdue_local_petition_escalates_to_department() {
+    let mut world = world();
+    setup_local(&mut world);
+
+    let petition_id = create_lyon_petition(&mut world);
+    reach_threshold(&mut world, petition_id, 15_000);
+
+    check_response_deadline(
+        &mut world,
+        petition_id,
+        Some("Response deadline not reached"),
+    );
+
+    world
+        .current_block()
+        .block_timestamp(1000 + RESPONSE_DELAY + 1);
+    world
+        .query()
+        .to(PETITION_ADDRESS)
+        .whitebox(petition::contract_obj, |sc| {
+            assert_eq!(
+                petition_ids(sc.get_overdue_responses(0u64, 10u64)),
+                vec![petition_id]
+            );
+        });
+
+    // Sans RIC local, la pétition est transmise au département avec un nouveau délai
+    check_response_deadline(&mut world, petition_id, None);
+    get_petition(&mut world, petition_id, |petition| {
+        assert_eq!(petition.status, petition::PetitionStatus::ResponsePending);
+        assert_eq!(petition.petition_type, petition::PetitionType::Departmental);
+        assert!(petition.scope == rhone_scope());
+        assert!(petition.escalated_from == Some(lyon_scope()));
+        assert_eq!(
+            petition.response_deadline,
+            Some(1000 + 2 * RESPONSE_DELAY + 1)
+        );
+    });
+
+    world
+        .query()
+        .to(PETITION_ADDRESS)
+        .whitebox(petition::contract_obj, |sc| {
+            assert_eq!(sc.get_petition_count_by_scope(lyon_scope()), 0u64);
+            assert_eq!(sc.get_petition_count_by_scope(rhone_scope()), 1u64);
+        });
+
+    // La commune n'est plus compétente, le département l'est
+    respond(
+        &mut world,
+        LYON_ADMIN_ADDRESS,
+        petition_id,
+        Some("Not authorized to respond"),
+    );
+    respond(&mut world, RHONE_ADMIN_ADDRESS, petition_id, None);
+}
+
+#[test]
+fn test_overdue_local_petition_becomes_ric_eligible() {
+    let mut world = world();
+    setup_local(&mut world);
+
+    world
+        .tx()
+        .from(LYON_ADMIN_ADDRESS)
+        .to(INSTITUTION_REGISTRY_ADDRESS)
+        .whitebox(institution_registry::contract_obj, |sc| {
+            sc.enable_democratix(1u64, true, 5u32, 5u32);
+        });
+
+    let petition_id = create_lyon_petition(&mut world);
+    reach_threshold(&mut world, petition_id, 15_000);
+
+    world
+        .current_block()
+        .block_timestamp(1000 + RESPONSE_DELAY + 1);
+    check_response_deadline(&mut world, petition_id, None);
+    get_petition(&mut world, petition_id, |petition| {
+        assert_eq!(petition.status, petition::PetitionStatus::ResponseOverdue);
+        assert!(petition.ric_eligible);
+        assert!(petition.escalated_from.is_none());
+    });
+
+    check_response_deadline(
+        &mut world,
+        petition_id,
+        Some("Petition not awaiting response"),
+    );
+}
+
+#[test]
+fn test_national_tiers_are_answered_per_tier() {
+    let mut world = world();
+    setup_national(&mut world);
+
+    let petition_id = create_national_petition(&mut world);
+    reach_threshold(&mut world, petition_id, 100_000);
+
+    get_petition(&mut world, petition_id, |petition| {
+        assert_eq!(petition.status, petition::PetitionStatus::ThresholdReached);
+        assert!(petition.debate_triggered);
+        assert!(!petition.inquiry_triggered);
+    });
+
+    // Réponse par palier uniquement, et par un répondant national
+    respond(
+        &mut world,
+        NATIONAL_RESPONDER_ADDRESS,
+        petition_id,
+        Some("National petitions are answered per tier"),
+    );
+    respond_to_tier(
+        &mut world,
+        CITIZEN_ADDRESS,
+        petition_id,
+        petition::NationalTier::Debate,
+        Some("Not authorized to respond"),
+    );
+    respond_to_tier(
+        &mut world,
+        NATIONAL_RESPONDER_ADDRESS,
+        petition_id,
+        petition::NationalTier::Inquiry,
+        Some("Tier not reached"),
+    );
+
+    respond_to_tier(
+        &mut world,
+        NATIONAL_RESPONDER_ADDRESS,
+        petition_id,
+        petition::NationalTier::Debate,
+        None,
+    );
+    respond_to_tier(
+        &mut world,
+        NATIONAL_RESPONDER_ADDRESS,
+        petition_id,
+        petition::NationalTier::Debate,
+        Some("Tier already answered"),
+    );
+
+    // Le palier suivant rouvre l'attente de réponse, la collecte continue
+    reach_threshold(&mut world, petition_id, 500_000);
+    get_petition(&mut world, petition_id, |petition| {
+        assert_eq!(petition.status, petition::PetitionStatus::ThresholdReached);
+        assert!(petition.inquiry_triggered);
+        assert_eq!(petition.signatures_count, 500_000u64);
+    });
+
+    world
+        .query()
+        .to(PETITION_ADDRESS)
+        .whitebox(petition::contract_obj, |sc| {
+            let milestones: Vec<petition::NationalTier> = sc
+                .get_tier_milestones(petition_id)
+                .into_iter()
+                .map(|milestone| milestone.tier)
+                .collect();
+            assert_eq!(
+                milestones,
+                vec![
+                    petition::NationalTier::Debate,
+                    petition::NationalTier::Inquiry
+                ]
+            );
+        });
+}
+
+#[test]
+fn test_sign_petition_requires_verified_membership_proof() {
+    let mut world = world();
+    deploy(&mut world);
+
+    let petition_id = create_national_petition(&mut world);
+
+    sign(
+        &mut world,
+        petition_id,
+        1,
+        Some("No electorate root for this territory"),
+    );
+
+    world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(PETITION_ADDRESS)
+        .whitebox(petition::contract_obj, |sc| {
+            sc.set_electorate_root(
+                petition::TerritorialScope::National,
+                managed_buffer!(&MERKLE_ROOT[..]),
+            );
+        });
+    sign(
+        &mut world,
+        petition_id,
+        1,
+        Some("Proof verifier not configured"),
+    );
+
+    world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(PETITION_ADDRESS)
+        .whitebox(petition::contract_obj, |sc| {
+            sc.set_proof_verifier(VERIFIER_ADDRESS.to_managed_address());
+        });
+
+    world
+        .tx()
+        .from(CITIZEN_ADDRESS)
+        .to(PETITION_ADDRESS)
+        .returns(ExpectError(4, "Invalid zk-SNARK proof"))
+        .whitebox(petition::contract_obj, |sc| {
+            sc.sign_petition(
+                petition_id,
+                managed_buffer!(nullifier(1).as_bytes()),
+                ManagedBuffer::new(),
+            );
+        });
+
+    sign(&mut world, petition_id, 1, None);
+    sign(
+        &mut world,
+        petition_id,
+        1,
+        Some("Already signed (nullifier used)"),
+    );
+
+    // La signature conserve la racine et le hash de la preuve pour audit
+    world
+        .query()
+        .to(PETITION_ADDRESS)
+        .whitebox(petition::contract_obj, |sc| {
+            let signature = sc.get_signature(petition_id, managed_buffer!(nullifier(1).as_bytes()));
+            assert_eq!(signature.merkle_root, managed_buffer!(&MERKLE_ROOT[..]));
+            assert_eq!(signature.proof_hash.len(), 32);
+            assert_eq!(signature.version, 1u32);
+            assert!(sc.is_nullifier_used(petition_id, managed_buffer!(nullifier(1).as_bytes())));
+        });
+}
+
+#[test]
+fn test_withdraw_reconfirm_and_amend() {
+    let mut world = world();
+    setup_national(&mut world);
+
+    let petition_id = create_national_petition(&mut world);
+    sign(&mut world, petition_id, 1, None);
+    sign(&mut world, petition_id, 2, None);
+    sign(&mut world, petition_id, 3, None);
+
+    update_signature(&mut world, petition_id, 3, true, None);
+    update_signature(
+        &mut world,
+        petition_id,
+        3,
+        true,
+        Some("Signature not found"),
+    );
+    assert_eq!(signatures_count(&mut world, petition_id), 2u64);
+
+    // Seul le créateur amende, et le texte doit changer
+    world
+        .tx()
+        .from(CITIZEN_ADDRESS)
+        .to(PETITION_ADDRESS)
+        .returns(ExpectError(4, "Only creator can amend"))
+        .whitebox(petition::contract_obj, |sc| {
+            sc.amend_petition(petition_id, managed_buffer!(RESPONSE_IPFS));
+        });
+
+    world
+        .tx()
+        .from(CREATOR_ADDRESS)
+        .to(PETITION_ADDRESS)
+        .whitebox(petition::contract_obj, |sc| {
+            assert_eq!(
+                sc.amend_petition(petition_id, managed_buffer!(RESPONSE_IPFS)),
+                2u32
+            );
+        });
+
+    get_petition(&mut world, petition_id, |petition| {
+        assert_eq!(petition.version, 2u32);
+        assert_eq!(petition.signatures_count, 0u64);
+        assert_eq!(petition.stale_signatures_count, 2u64);
+    });
+
+    // Les anciennes signatures comptent de nouveau une fois reconfirmées
+    update_signature(&mut world, petition_id, 1, false, None);
+    update_signature(
+        &mut world,
+        petition_id,
+        1,
+        false,
+        Some("Signature already on current version"),
+    );
+    update_signature(&mut world, petition_id, 2, true, None);
+
+    get_petition(&mut world, petition_id, |petition| {
+        assert_eq!(petition.signatures_count, 1u64);
+        assert_eq!(petition.stale_signatures_count, 0u64);
+    });
+
+    world
+        .query()
+        .to(PETITION_ADDRESS)
+        .whitebox(petition::contract_obj, |sc| {
+            assert_eq!(sc.get_petition_versions(petition_id).into_iter().count(), 2);
+        });
+}
+
+#[test]
+fn test_creation_deposit_refund_and_moderation() {
+    let mut world = world();
+    setup_national(&mut world);
+
+    world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(PETITION_ADDRESS)
+        .whitebox(petition::contract_obj, |sc| {
+            sc.set_creation_deposit(BigUint::from(100u64), 2u64);
+        });
+
+    world
+        .tx()
+        .from(CREATOR_ADDRESS)
+        .to(PETITION_ADDRESS)
+        .returns(ExpectError(4, "Incorrect creation deposit"))
+        .whitebox(petition::contract_obj, |sc| {
+            sc.create_petition(
+                managed_buffer!(b"Referendum sur les retraites"),
+                managed_buffer!(DESCRIPTION_IPFS),
+                petition::TerritorialScope::National,
+                petition::PetitionType::National,
+                30u64,
+            );
+        });
+
+    let mut petition_id = 0u64;
+    world
+        .tx()
+        .from(CREATOR_ADDRESS)
+        .to(PETITION_ADDRESS)
+        .egld(100u64)
+        .whitebox(petition::contract_obj, |sc| {
+            petition_id = sc.create_petition(
+                managed_buffer!(b"Referendum sur les retraites"),
+                managed_buffer!(DESCRIPTION_IPFS),
+                petition::TerritorialScope::National,
+                petition::PetitionType::National,
+                30u64,
+            );
+        });
+    world.check_account(CREATOR_ADDRESS).balance(900u64);
+
+    // Dépôt rendu au créateur dès le minimum de signatures atteint
+    sign(&mut world, petition_id, 1, None);
+    sign(&mut world, petition_id, 2, None);
+    world.check_account(CREATOR_ADDRESS).balance(1_000u64);
+
+    // Co-signataires gérés par le créateur
+    world
+        .tx()
+        .from(CITIZEN_ADDRESS)
+        .to(PETITION_ADDRESS)
+        .returns(ExpectError(4, "Only creator can add co-sponsors"))
+        .whitebox(petition::contract_obj, |sc| {
+            sc.add_co_sponsor(petition_id, CITIZEN_ADDRESS.to_managed_address());
+        });
+    world
+        .tx()
+        .from(CREATOR_ADDRESS)
+        .to(PETITION_ADDRESS)
+        .whitebox(petition::contract_obj, |sc| {
+            sc.add_co_sponsor(petition_id, CITIZEN_ADDRESS.to_managed_address());
+        });
+
+    // Masquage réservé aux modérateurs, puis appel du créateur
+    world
+        .tx()
+        .from(CITIZEN_ADDRESS)
+        .to(PETITION_ADDRESS)
+        .returns(ExpectError(4, "Only moderators can hide petitions"))
+        .whitebox(petition::contract_obj, |sc| {
+            sc.hide_petition(petition_id, managed_buffer!(b"spam"));
+        });
+    world
+        .tx()
+        .from(MODERATOR_ADDRESS)
+        .to(PETITION_ADDRESS)
+        .whitebox(petition::contract_obj, |sc| {
+            sc.hide_petition(petition_id, managed_buffer!(b"spam"));
+        });
+
+    sign(
+        &mut world,
+        petition_id,
+        3,
+        Some("Petition hidden by moderation"),
+    );
+    world
+        .query()
+        .to(PETITION_ADDRESS)
+        .whitebox(petition::contract_obj, |sc| {
+            assert!(petition_ids(sc.get_active_petitions(0u64, 10u64)).is_empty());
+        });
+
+    world
+        .tx()
+        .from(CITIZEN_ADDRESS)
+        .to(PETITION_ADDRESS)
+        .returns(ExpectError(4, "Only creator can appeal"))
+        .whitebox(petition::contract_obj, |sc| {
+            sc.appeal_moderation(petition_id, managed_buffer!(RESPONSE_IPFS));
+        });
+    world
+        .tx()
+        .from(CREATOR_ADDRESS)
+        .to(PETITION_ADDRESS)
+        .whitebox(petition::contract_obj, |sc| {
+            sc.appeal_moderation(petition_id, managed_buffer!(RESPONSE_IPFS));
+        });
+    world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(PETITION_ADDRESS)
+        .whitebox(petition::contract_obj, |sc| {
+            sc.resolve_appeal(petition_id, true);
+        });
+
+    get_petition(&mut world, petition_id, |petition| {
+        assert!(!petition.hidden);
+        assert_eq!(petition.co_sponsors.len(), 1);
+    });
+    sign(&mut world, petition_id, 3, None);
+}
+
+#[test]
+fn test_index_views_reject_oversized_pages() {
+    let mut world = world();
+    setup_national(&mut world);
+    create_national_petition(&mut world);
+
+    world
+        .query()
+        .to(PETITION_ADDRESS)
+        .returns(ExpectError(4, "Page size too large"))
+        .whitebox(petition::contract_obj, |sc| {
+            sc.get_active_petitions(0u64, 101u64);
+        });
+
+    world
+        .query()
+        .to(PETITION_ADDRESS)
+        .whitebox(petition::contract_obj, |sc| {
+            assert_eq!(
+                petition_ids(sc.get_petitions_by_status(
+                    petition::PetitionStatus::Active,
+                    0u64,
+                    100u64
+                )),
+                vec![1u64]
+            );
+        });
+}
@@ -3,6 +3,8 @@
 multiversx_sc::imports!();
 multiversx_sc::derive_imports!();
 
+use democratix_common::is_valid_ipfs_cid;
+
 /// Type de sondage
 #[type_abi]
 #[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, PartialEq, Debug)]
@@ -146,7 +148,7 @@ pub trait PollContract {
         is_anonymous: bool,
     ) -> u64 {
         require!(title.len() > 0, "Title required");
-        require!(description_ipfs.len() == 46, "Invalid IPFS CID");
+        require!(is_valid_ipfs_cid(&description_ipfs), "Invalid IPFS CID");
         require!(duration_days >= 1 && duration_days <= 90, "Duration 1-90 days");
 
         let caller = self.blockchain().get_caller();
@@ -396,7 +398,7 @@ pub trait PollContract {
     #[endpoint(closePoll)]
     fn close_poll(&self, poll_id: u64, results_ipfs: ManagedBuffer) {
         require!(self.polls().contains_key(&poll_id), "Poll not found");
-        require!(results_ipfs.len() == 46, "Invalid IPFS CID");
+        require!(is_valid_ipfs_cid(&results_ipfs), "Invalid IPFS CID");
 
         let caller = self.blockchain().get_caller();
         let mut poll = self.polls().get(&poll_id).unwrap();
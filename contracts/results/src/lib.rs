@@ -2,13 +2,27 @@
 
 use multiversx_sc::{derive_imports::*, imports::*};
 
-/// Résultat pour un candidat
+use democratix_common::CandidateResult;
+use democratix_common::is_valid_ipfs_cid;
+pub use democratix_common::PublishedCandidateResult;
+
+/// Quorum d'observateurs par défaut pour certifier des résultats
+const DEFAULT_CERTIFICATION_QUORUM: u32 = 2;
+
+/// Délai par défaut pour contester des résultats après publication (7 jours)
+const DEFAULT_CONTEST_PERIOD: u64 = 604_800;
+
+/// Durée par défaut d'une fenêtre de contestation (14 jours)
+const DEFAULT_DISPUTE_WINDOW: u64 = 1_209_600;
+
+/// État des résultats publiés
 #[type_abi]
-#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, Debug)]
-pub struct CandidateResult {
-    pub candidate_id: u32,
-    pub vote_count: u64,
-    pub percentage: u64, // Pourcentage * 100 (ex: 4523 = 45.23%)
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum ResultsStatus {
+    Published,   // Importés depuis le contrat de vote
+    Certified,   // Quorum d'observateurs atteint
+    Contested,   // Contestation en cours
+    Invalidated, // Contestation retenue
 }
 
 /// Structure représentant les résultats d'une élection
@@ -20,57 +34,127 @@ pub struct ElectionResults {
     pub num_candidates: u32,
     pub is_finalized: bool,
     pub finalized_at: u64,
+    pub status: ResultsStatus,
+    pub certifications_count: u32,
+    pub certified_at: u64, // 0 tant que non certifiés
+}
+
+/// Type d'observateur accrédité
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, PartialEq, Debug)]
+pub enum ObserverType {
+    Ngo,         // Association / ONG
+    Bailiff,     // Commissaire de justice (huissier)
+    Institution, // Autorité publique
+}
+
+/// Observateur accrédité
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, Debug)]
+pub struct Observer<M: ManagedTypeApi> {
+    pub address: ManagedAddress<M>,
+    pub name: ManagedBuffer<M>,
+    pub observer_type: ObserverType,
+    pub accredited_at: u64,
+    pub is_active: bool,
+}
+
+/// Certification des résultats par un observateur
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, Debug)]
+pub struct Certification<M: ManagedTypeApi> {
+    pub observer: ManagedAddress<M>,
+    pub certified_at: u64,
+    pub report_ipfs: Option<ManagedBuffer<M>>,
+}
+
+/// Contestation des résultats
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, Debug)]
+pub struct Contestation<M: ManagedTypeApi> {
+    pub contested_by: ManagedAddress<M>,
+    pub reason_ipfs: ManagedBuffer<M>,
+    pub opened_at: u64,
+    pub dispute_ends_at: u64,
+    pub resolved: bool,
+    pub upheld: bool,
 }
 
 /// Smart Contract de Dépouillement des Résultats
 ///
-/// Ce contrat gère le dépouillement et la publication des résultats d'élection.
-/// Il utilise le déchiffrement homomorphique pour compter les votes sans révéler
-/// les choix individuels.
-///
-/// NOTE: Pour le POC, ce contrat est une structure de base.
-/// L'implémentation complète du déchiffrement homomorphique sera ajoutée
-/// dans les prochaines phases du projet.
+/// Ce contrat publie les résultats d'élection. Les résultats ne sont jamais saisis à
+/// la main : ils sont importés depuis le contrat de vote une fois l'élection
+/// finalisée (dépouillement homomorphique ou en clair réalisé par le contrat de vote).
 #[multiversx_sc::contract]
 pub trait ResultsContract {
     #[init]
-    fn init(&self) {}
+    fn init(&self) {
+        self.set_default_settings();
+    }
 
     /// Fonction appelée lors de l'upgrade du contrat
     #[upgrade]
-    fn upgrade(&self) {}
+    fn upgrade(&self) {
+        self.set_default_settings();
+    }
 
-    /// Publier les résultats d'une élection (VERSION POC)
+    /// Importe les résultats finaux d'une élection depuis le contrat de vote
     ///
-    /// Dans la version production, cette fonction devrait:
-    /// 1. Récupérer tous les votes chiffrés du contrat voting
-    /// 2. Utiliser le déchiffrement homomorphique pour compter sans révéler
-    /// 3. Publier les résultats agrégés
+    /// Les résultats sont lus via `getFinalResults`, qui échoue tant que l'élection
+    /// n'est pas `Finalized`. Les pourcentages sont calculés sur les suffrages exprimés.
+    /// Une fois importés, les résultats ne peuvent plus être modifiés.
     ///
     /// # Arguments
     /// * `election_id` - ID de l'élection
-    /// * `total_votes` - Nombre total de votes
-    #[endpoint(publishResults)]
-    fn publish_results(
-        &self,
-        election_id: u64,
-        total_votes: u64,
-    ) {
-        // TODO: Vérifier que l'appelant est autorisé (organisateur ou autorité)
-        // TODO: Vérifier que l'élection est fermée
-        // TODO: Implémenter le déchiffrement homomorphique
-
+    #[endpoint(importResults)]
+    fn import_results(&self, election_id: u64) {
+        let caller = self.blockchain().get_caller();
+        require!(
+            caller == self.blockchain().get_owner_address() || self.publishers().contains(&caller),
+            "Non autorisé à publier des résultats"
+        );
+        require!(
+            !self.voting_contract_address().is_empty(),
+            "Contrat de vote non configuré"
+        );
         require!(
             self.results(election_id).is_empty(),
             "Résultats déjà publiés"
         );
 
+        let voting_contract = self.voting_contract_address().get();
+        let final_results: MultiValueEncoded<MultiValue2<u32, u64>> = self
+            .voting_proxy(voting_contract)
+            .get_final_results(election_id)
+            .returns(ReturnsResult)
+            .sync_call();
+
+        let mut imported = ManagedVec::<Self::Api, CandidateResult>::new();
+        let mut total_votes = 0u64;
+        for result in final_results.into_iter() {
+            let (candidate_id, vote_count) = result.into_tuple();
+            total_votes += vote_count;
+            imported.push(CandidateResult {
+                candidate_id,
+                vote_count,
+            });
+        }
+        require!(!imported.is_empty(), "Aucun résultat à importer");
+
+        let mut candidate_results = self.candidate_results(election_id);
+        for result in imported.iter() {
+            candidate_results.push(&result.to_published(total_votes));
+        }
+
         let election_results = ElectionResults {
             election_id,
             total_votes,
-            num_candidates: 0, // TODO: Calculer depuis les votes
+            num_candidates: imported.len() as u32,
             is_finalized: true,
             finalized_at: self.blockchain().get_block_timestamp(),
+            status: ResultsStatus::Published,
+            certifications_count: 0,
+            certified_at: 0,
         };
 
         self.results(election_id).set(&election_results);
@@ -78,27 +162,250 @@ pub trait ResultsContract {
         self.results_published_event(election_id, total_votes);
     }
 
-    /// Ajoute un résultat pour un candidat
-    #[endpoint(addCandidateResult)]
-    fn add_candidate_result(
-        &self,
-        election_id: u64,
-        candidate_id: u32,
-        vote_count: u64,
-        percentage: u64,
-    ) {
-        // TODO: Vérifier autorisation
-        let result = CandidateResult {
-            candidate_id,
-            vote_count,
-            percentage,
+    /// Configure le contrat de vote dont les résultats sont importés (admin)
+    #[only_owner]
+    #[endpoint(setVotingContract)]
+    fn set_voting_contract(&self, address: ManagedAddress) {
+        require!(
+            self.blockchain().is_smart_contract(&address),
+            "L'adresse doit être un smart contract"
+        );
+        self.voting_contract_address().set(address);
+    }
+
+    /// Autorise une adresse à importer des résultats (admin)
+    #[only_owner]
+    #[endpoint(addPublisher)]
+    fn add_publisher(&self, address: ManagedAddress) {
+        self.publishers().insert(address);
+    }
+
+    /// Retire une adresse autorisée à importer des résultats (admin)
+    #[only_owner]
+    #[endpoint(removePublisher)]
+    fn remove_publisher(&self, address: ManagedAddress) {
+        self.publishers().swap_remove(&address);
+    }
+
+    // === OBSERVATEURS ET CERTIFICATION ===
+
+    /// Accrédite un observateur indépendant (admin)
+    ///
+    /// # Arguments
+    /// * `address` - Adresse de l'observateur
+    /// * `name` - Nom de l'organisation ou de l'officier
+    /// * `observer_type` - Type d'observateur
+    #[only_owner]
+    #[endpoint(accreditObserver)]
+    fn accredit_observer(&self, address: ManagedAddress, name: ManagedBuffer, observer_type: ObserverType) {
+        require!(!name.is_empty(), "Nom de l'observateur requis");
+
+        let observer = Observer {
+            address: address.clone(),
+            name,
+            observer_type,
+            accredited_at: self.blockchain().get_block_timestamp(),
+            is_active: true,
+        };
+
+        self.observers(&address).set(&observer);
+        self.observer_list().insert(address.clone());
+
+        self.observer_accredited_event(&address);
+    }
+
+    /// Retire l'accréditation d'un observateur (admin)
+    ///
+    /// Les certifications déjà déposées restent acquises.
+    #[only_owner]
+    #[endpoint(revokeObserver)]
+    fn revoke_observer(&self, address: ManagedAddress) {
+        require!(!self.observers(&address).is_empty(), "Observateur inconnu");
+
+        self.observers(&address).update(|observer| observer.is_active = false);
+
+        self.observer_revoked_event(&address);
+    }
+
+    /// Certifie les résultats publiés d'une élection
+    ///
+    /// Les résultats passent à l'état `Certified` dès que le quorum d'observateurs est
+    /// atteint, sauf contestation en cours.
+    ///
+    /// # Arguments
+    /// * `election_id` - ID de l'élection
+    /// * `report_ipfs` - Rapport d'observation (optionnel)
+    #[endpoint(certifyResults)]
+    fn certify_results(&self, election_id: u64, report_ipfs: OptionalValue<ManagedBuffer>) {
+        let caller = self.blockchain().get_caller();
+        self.require_active_observer(&caller);
+        require!(
+            !self.results(election_id).is_empty(),
+            "Résultats non disponibles"
+        );
+        require!(
+            !self.certified_by(election_id).contains(&caller),
+            "Résultats déjà certifiés par cet observateur"
+        );
+
+        let mut results = self.results(election_id).get();
+        require!(
+            results.status != ResultsStatus::Invalidated,
+            "Résultats invalidés"
+        );
+
+        let report_ipfs = report_ipfs.into_option();
+        if let Some(report) = &report_ipfs {
+            require!(is_valid_ipfs_cid(report), "Invalid IPFS CID");
+        }
+
+        let current_time = self.blockchain().get_block_timestamp();
+        let certification = Certification {
+            observer: caller.clone(),
+            certified_at: current_time,
+            report_ipfs,
         };
-        self.candidate_results(election_id).push(&result);
+        self.certifications(election_id).push(&certification);
+        self.certified_by(election_id).insert(caller.clone());
+        results.certifications_count += 1;
+
+        self.results_certification_event(election_id, &caller, results.certifications_count);
+
+        if results.status == ResultsStatus::Published
+            && results.certifications_count >= self.certification_quorum().get()
+        {
+            results.status = ResultsStatus::Certified;
+            results.certified_at = current_time;
+            self.results_certified_event(election_id, results.certifications_count);
+        }
+
+        self.results(election_id).set(&results);
+    }
+
+    /// Conteste des résultats publiés et ouvre une fenêtre de contestation
+    ///
+    /// Réservé aux observateurs accrédités, pendant le délai de contestation qui suit
+    /// la publication. La certification est suspendue jusqu'à la décision.
+    ///
+    /// # Arguments
+    /// * `election_id` - ID de l'élection
+    /// * `reason_ipfs` - Exposé des motifs
+    #[endpoint(contestResults)]
+    fn contest_results(&self, election_id: u64, reason_ipfs: ManagedBuffer) {
+        let caller = self.blockchain().get_caller();
+        self.require_active_observer(&caller);
+        require!(
+            !self.results(election_id).is_empty(),
+            "Résultats non disponibles"
+        );
+        require!(is_valid_ipfs_cid(&reason_ipfs), "Invalid IPFS CID");
+
+        let mut results = self.results(election_id).get();
+        require!(
+            results.status == ResultsStatus::Published || results.status == ResultsStatus::Certified,
+            "Résultats déjà contestés"
+        );
+
+        let current_time = self.blockchain().get_block_timestamp();
+        require!(
+            current_time <= results.finalized_at + self.contest_period().get(),
+            "Délai de contestation expiré"
+        );
+
+        let contestation = Contestation {
+            contested_by: caller.clone(),
+            reason_ipfs,
+            opened_at: current_time,
+            dispute_ends_at: current_time + self.dispute_window().get(),
+            resolved: false,
+            upheld: false,
+        };
+        self.contestation(election_id).set(&contestation);
+
+        results.status = ResultsStatus::Contested;
+        self.results(election_id).set(&results);
+
+        self.results_contested_event(election_id, &caller, contestation.dispute_ends_at);
+    }
+
+    /// Tranche une contestation à la fin de la fenêtre de contestation (admin)
+    ///
+    /// # Arguments
+    /// * `election_id` - ID de l'élection
+    /// * `upheld` - `true` si la contestation est retenue (résultats invalidés)
+    #[only_owner]
+    #[endpoint(resolveContestation)]
+    fn resolve_contestation(&self, election_id: u64, upheld: bool) {
+        require!(
+            !self.contestation(election_id).is_empty(),
+            "Aucune contestation"
+        );
+
+        let mut contestation = self.contestation(election_id).get();
+        require!(!contestation.resolved, "Contestation déjà tranchée");
+        require!(
+            self.blockchain().get_block_timestamp() >= contestation.dispute_ends_at,
+            "Fenêtre de contestation encore ouverte"
+        );
+
+        contestation.resolved = true;
+        contestation.upheld = upheld;
+        self.contestation(election_id).set(&contestation);
+
+        let mut results = self.results(election_id).get();
+        if upheld {
+            results.status = ResultsStatus::Invalidated;
+        } else if results.certifications_count >= self.certification_quorum().get() {
+            results.status = ResultsStatus::Certified;
+            if results.certified_at == 0 {
+                results.certified_at = self.blockchain().get_block_timestamp();
+            }
+        } else {
+            results.status = ResultsStatus::Published;
+        }
+        self.results(election_id).set(&results);
+
+        self.contestation_resolved_event(election_id, upheld);
+    }
+
+    /// Configure le nombre d'observateurs requis pour certifier (admin)
+    #[only_owner]
+    #[endpoint(setCertificationQuorum)]
+    fn set_certification_quorum(&self, quorum: u32) {
+        require!(quorum > 0, "Le quorum doit être positif");
+        self.certification_quorum().set(quorum);
+    }
+
+    /// Configure le délai de contestation et la durée de la fenêtre de contestation (admin)
+    ///
+    /// # Arguments
+    /// * `contest_period` - Délai après publication pendant lequel contester (secondes)
+    /// * `dispute_window` - Durée d'instruction d'une contestation (secondes)
+    #[only_owner]
+    #[endpoint(setContestSettings)]
+    fn set_contest_settings(&self, contest_period: u64, dispute_window: u64) {
+        self.contest_period().set(contest_period);
+        self.dispute_window().set(dispute_window);
+    }
+
+    /// Helper: valeurs par défaut des paramètres de certification
+    fn set_default_settings(&self) {
+        self.certification_quorum().set_if_empty(DEFAULT_CERTIFICATION_QUORUM);
+        self.contest_period().set_if_empty(DEFAULT_CONTEST_PERIOD);
+        self.dispute_window().set_if_empty(DEFAULT_DISPUTE_WINDOW);
+    }
+
+    /// Helper: vérifie que l'adresse est un observateur accrédité actif
+    fn require_active_observer(&self, address: &ManagedAddress) {
+        require!(
+            !self.observers(address).is_empty() && self.observers(address).get().is_active,
+            "Observateur non accrédité"
+        );
     }
 
     /// Récupère le résultat d'un candidat spécifique
     #[view(getCandidateResult)]
-    fn get_candidate_result(&self, election_id: u64, index: usize) -> CandidateResult {
+    fn get_candidate_result(&self, election_id: u64, index: usize) -> PublishedCandidateResult {
         self.candidate_results(election_id).get(index)
     }
 
@@ -113,6 +420,50 @@ pub trait ResultsContract {
         self.results(election_id).get()
     }
 
+    /// Récupère les résultats de tous les candidats
+    #[view(getCandidateResults)]
+    fn get_candidate_results(&self, election_id: u64) -> MultiValueEncoded<PublishedCandidateResult> {
+        self.candidate_results(election_id).iter().collect()
+    }
+
+    /// Vérifie si les résultats sont certifiés
+    #[view(isCertified)]
+    fn is_certified(&self, election_id: u64) -> bool {
+        !self.results(election_id).is_empty()
+            && self.results(election_id).get().status == ResultsStatus::Certified
+    }
+
+    /// Récupère les certifications déposées pour une élection
+    #[view(getCertifications)]
+    fn get_certifications(&self, election_id: u64) -> MultiValueEncoded<Certification<Self::Api>> {
+        self.certifications(election_id).iter().collect()
+    }
+
+    /// Récupère la contestation d'une élection, s'il y en a une
+    #[view(getContestation)]
+    fn get_contestation(&self, election_id: u64) -> OptionalValue<Contestation<Self::Api>> {
+        if self.contestation(election_id).is_empty() {
+            return OptionalValue::None;
+        }
+
+        OptionalValue::Some(self.contestation(election_id).get())
+    }
+
+    #[view(getObserver)]
+    fn get_observer(&self, address: ManagedAddress) -> Observer<Self::Api> {
+        require!(!self.observers(&address).is_empty(), "Observateur inconnu");
+        self.observers(&address).get()
+    }
+
+    #[view(getObservers)]
+    fn get_observers(&self) -> MultiValueEncoded<Observer<Self::Api>> {
+        let mut observers = MultiValueEncoded::new();
+        for address in self.observer_list().iter() {
+            observers.push(self.observers(&address).get());
+        }
+        observers
+    }
+
     /// Vérifier si les résultats sont disponibles
     #[view(areResultsAvailable)]
     fn are_results_available(&self, election_id: u64) -> bool {
@@ -125,7 +476,42 @@ pub trait ResultsContract {
     fn results(&self, election_id: u64) -> SingleValueMapper<ElectionResults>;
 
     #[storage_mapper("candidateResults")]
-    fn candidate_results(&self, election_id: u64) -> VecMapper<CandidateResult>;
+    fn candidate_results(&self, election_id: u64) -> VecMapper<PublishedCandidateResult>;
+
+    #[view(getVotingContract)]
+    #[storage_mapper("votingContractAddress")]
+    fn voting_contract_address(&self) -> SingleValueMapper<ManagedAddress>;
+
+    /// Adresses autorisées à appeler `importResults` (en plus du propriétaire)
+    #[storage_mapper("publishers")]
+    fn publishers(&self) -> UnorderedSetMapper<ManagedAddress>;
+
+    #[storage_mapper("observers")]
+    fn observers(&self, address: &ManagedAddress) -> SingleValueMapper<Observer<Self::Api>>;
+
+    #[storage_mapper("observerList")]
+    fn observer_list(&self) -> UnorderedSetMapper<ManagedAddress>;
+
+    #[storage_mapper("certifications")]
+    fn certifications(&self, election_id: u64) -> VecMapper<Certification<Self::Api>>;
+
+    #[storage_mapper("certifiedBy")]
+    fn certified_by(&self, election_id: u64) -> UnorderedSetMapper<ManagedAddress>;
+
+    #[storage_mapper("contestation")]
+    fn contestation(&self, election_id: u64) -> SingleValueMapper<Contestation<Self::Api>>;
+
+    #[view(getCertificationQuorum)]
+    #[storage_mapper("certificationQuorum")]
+    fn certification_quorum(&self) -> SingleValueMapper<u32>;
+
+    #[view(getContestPeriod)]
+    #[storage_mapper("contestPeriod")]
+    fn contest_period(&self) -> SingleValueMapper<u64>;
+
+    #[view(getDisputeWindow)]
+    #[storage_mapper("disputeWindow")]
+    fn dispute_window(&self) -> SingleValueMapper<u64>;
 
     // === EVENTS ===
 
@@ -135,4 +521,48 @@ pub trait ResultsContract {
         #[indexed] election_id: u64,
         total_votes: u64,
     );
+
+    #[event("observerAccredited")]
+    fn observer_accredited_event(&self, #[indexed] observer: &ManagedAddress);
+
+    #[event("observerRevoked")]
+    fn observer_revoked_event(&self, #[indexed] observer: &ManagedAddress);
+
+    #[event("resultsCertification")]
+    fn results_certification_event(
+        &self,
+        #[indexed] election_id: u64,
+        #[indexed] observer: &ManagedAddress,
+        certifications_count: u32,
+    );
+
+    #[event("resultsCertified")]
+    fn results_certified_event(&self, #[indexed] election_id: u64, certifications_count: u32);
+
+    #[event("resultsContested")]
+    fn results_contested_event(
+        &self,
+        #[indexed] election_id: u64,
+        #[indexed] contested_by: &ManagedAddress,
+        dispute_ends_at: u64,
+    );
+
+    #[event("contestationResolved")]
+    fn contestation_resolved_event(&self, #[indexed] election_id: u64, upheld: bool);
+
+    // === PROXY ===
+
+    #[proxy]
+    fn voting_proxy(&self, sc_address: ManagedAddress) -> voting_proxy::Proxy<Self::Api>;
+}
+
+/// Proxy pour appels cross-contract au contrat voting
+mod voting_proxy {
+    multiversx_sc::imports!();
+
+    #[multiversx_sc::proxy]
+    pub trait VotingProxy {
+        #[view(getFinalResults)]
+        fn get_final_results(&self, election_id: u64) -> MultiValueEncoded<MultiValue2<u32, u64>>;
+    }
 }
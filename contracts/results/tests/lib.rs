@@ -0,0 +1 @@
+mod results_test;
@@ -0,0 +1,551 @@
+use multiversx_sc_scenario::imports::*;
+use multiversx_sc_scenario::multiversx_chain_vm::crypto_functions::sha256;
+use results::ResultsContract;
+use voter_registry::VoterRegistry;
+use voting::VotingContract;
+
+const RESULTS_CODE: MxscPath = MxscPath::new("output/results.mxsc.json");
+const VOTING_CODE: MxscPath = MxscPath::new("../voting/output/voting.mxsc.json");
+const VOTER_REGISTRY_CODE: MxscPath = MxscPath::new("../voter-registry/output/voter-registry.mxsc.json");
+
+const RESULTS_ADDRESS: TestSCAddress = TestSCAddress::new("results");
+const VOTING_ADDRESS: TestSCAddress = TestSCAddress::new("voting");
+const VOTER_REGISTRY_ADDRESS: TestSCAddress = TestSCAddress::new("voter-registry");
+
+const OWNER_ADDRESS: TestAddress = TestAddress::new("owner");
+const PUBLISHER_ADDRESS: TestAddress = TestAddress::new("publisher");
+const OBSERVER1_ADDRESS: TestAddress = TestAddress::new("observer1");
+const OBSERVER2_ADDRESS: TestAddress = TestAddress::new("observer2");
+const OBSERVER3_ADDRESS: TestAddress = TestAddress::new("observer3");
+const VERIFIER_ADDRESS: TestAddress = TestAddress::new("verifier");
+const VOTERS: [TestAddress; 3] = [
+    TestAddress::new("voter1"),
+    TestAddress::new("voter2"),
+    TestAddress::new("voter3"),
+];
+
+const REPORT_IPFS: &[u8] = b"QmYwAPJzv5CZsnA625s3Xf2nemtYgPpHdWEz79ojWnPbdG";
+
+const START_TIME: u64 = 1100;
+const END_TIME: u64 = 4700;
+/// Fin du délai de contestation du contrat de vote (finalisation à END_TIME + 1, 7 jours)
+const VOTING_DISPUTE_DEADLINE: u64 = END_TIME + 1 + 604_800;
+
+// Toy RSA key of the blind signature authority (n = 61 * 53)
+const RSA_MODULUS: u64 = 3233;
+const RSA_PUBLIC_EXPONENT: u64 = 17;
+const RSA_PRIVATE_EXPONENT: u64 = 2753;
+// Blinding factor r and its inverse modulo n
+const BLINDING_FACTOR: u64 = 7;
+const BLINDING_FACTOR_INVERSE: u64 = 462;
+
+fn world() -> ScenarioWorld {
+    let mut blockchain = ScenarioWorld::new();
+    blockchain.register_contract(RESULTS_CODE, results::ContractBuilder);
+    blockchain.register_contract(VOTING_CODE, voting::ContractBuilder);
+    blockchain.register_contract(VOTER_REGISTRY_CODE, voter_registry::ContractBuilder);
+    blockchain
+}
+
+fn mod_pow(mut base: u64, mut exponent: u64, modulus: u64) -> u64 {
+    let mut result = 1u64;
+    base %= modulus;
+    while exponent > 0 {
+        if exponent & 1 == 1 {
+            result = result * base % modulus;
+        }
+        base = base * base % modulus;
+        exponent >>= 1;
+    }
+    result
+}
+
+/// `H(election_id || token) mod n`, as computed by the voter registry
+fn blind_token_message(election_id: u64, token: &[u8]) -> u64 {
+    let mut data = election_id.to_be_bytes().to_vec();
+    data.extend_from_slice(token);
+    sha256(&data)
+        .iter()
+        .fold(0u64, |acc, byte| (acc * 256 + *byte as u64) % RSA_MODULUS)
+}
+
+/// Inscrit un électeur auprès du registre et retourne son token signé
+fn issue_voting_token(world: &mut ScenarioWorld, voter: TestAddress, credential_proof: &[u8]) -> (Vec<u8>, Vec<u8>) {
+    let mut token = b"token:".to_vec();
+    token.extend_from_slice(credential_proof);
+
+    let message = blind_token_message(1, &token);
+    let blinded_message = message * mod_pow(BLINDING_FACTOR, RSA_PUBLIC_EXPONENT, RSA_MODULUS) % RSA_MODULUS;
+
+    world
+        .tx()
+        .from(voter)
+        .to(VOTER_REGISTRY_ADDRESS)
+        .whitebox(voter_registry::contract_obj, |sc| {
+            sc.register_voter(
+                1u64,
+                managed_buffer!(credential_proof),
+                managed_buffer!(&blinded_message.to_be_bytes()[..]),
+            );
+        });
+
+    let blind_signature = mod_pow(blinded_message, RSA_PRIVATE_EXPONENT, RSA_MODULUS);
+    world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(VOTER_REGISTRY_ADDRESS)
+        .whitebox(voter_registry::contract_obj, |sc| {
+            sc.sign_blinded_token(
+                1u64,
+                managed_buffer!(&sha256(credential_proof)[..]),
+                managed_buffer!(&blind_signature.to_be_bytes()[..]),
+            );
+        });
+
+    let signature = blind_signature * BLINDING_FACTOR_INVERSE % RSA_MODULUS;
+
+    (token, signature.to_be_bytes().to_vec())
+}
+
+/// Déploie les trois contrats et dépouille on-chain une élection en clair
+/// (candidat 1: 2 voix, candidat 2: 1 voix), finalisée à END_TIME + 1
+fn setup(world: &mut ScenarioWorld) {
+    for account in [
+        OWNER_ADDRESS,
+        PUBLISHER_ADDRESS,
+        OBSERVER1_ADDRESS,
+        OBSERVER2_ADDRESS,
+        OBSERVER3_ADDRESS,
+    ]
+    .into_iter()
+    .chain(VOTERS)
+    {
+        world.account(account).nonce(1);
+    }
+    world.current_block().block_timestamp(1000);
+
+    world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .raw_deploy()
+        .code(RESULTS_CODE)
+        .new_address(RESULTS_ADDRESS)
+        .whitebox(results::contract_obj, |sc| {
+            sc.init();
+        });
+
+    world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .raw_deploy()
+        .code(VOTING_CODE)
+        .new_address(VOTING_ADDRESS)
+        .whitebox(voting::contract_obj, |sc| {
+            sc.init();
+        });
+
+    world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .raw_deploy()
+        .code(VOTER_REGISTRY_CODE)
+        .new_address(VOTER_REGISTRY_ADDRESS)
+        .whitebox(voter_registry::contract_obj, |sc| {
+            sc.init();
+        });
+
+    world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(VOTER_REGISTRY_ADDRESS)
+        .whitebox(voter_registry::contract_obj, |sc| {
+            sc.add_voting_contract(VOTING_ADDRESS.to_managed_address());
+            sc.set_eligibility_verifier(VERIFIER_ADDRESS.to_managed_address());
+
+            // The owner acts as the blind signature authority
+            sc.set_blind_signature_authority(
+                OWNER_ADDRESS.to_managed_address(),
+                BigUint::from(RSA_MODULUS),
+                RSA_PUBLIC_EXPONENT,
+            );
+        });
+
+    world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(VOTING_ADDRESS)
+        .whitebox(voting::contract_obj, |sc| {
+            sc.set_backend_verifier(VERIFIER_ADDRESS.to_managed_address());
+            sc.set_voter_registry(VOTER_REGISTRY_ADDRESS.to_managed_address());
+
+            let election_id = sc.create_election(
+                managed_buffer!(b"Municipales"),
+                managed_buffer!(b"QmTest"),
+                START_TIME,
+                END_TIME,
+                false,
+                0u8,
+                false,
+                OptionalValue::None,
+            );
+            assert_eq!(election_id, 1u64);
+
+            sc.add_candidate(1u64, 1u32, managed_buffer!(b"Candidate A"), managed_buffer!(b"QmCandidate"));
+            sc.add_candidate(1u64, 2u32, managed_buffer!(b"Candidate B"), managed_buffer!(b"QmCandidate"));
+        });
+
+    world.current_block().block_timestamp(START_TIME);
+
+    world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(VOTING_ADDRESS)
+        .whitebox(voting::contract_obj, |sc| {
+            sc.activate_election(1u64);
+        });
+
+    for (voter, choice) in VOTERS.into_iter().zip([1u32, 2u32, 1u32]) {
+        let (voting_token, token_signature) = issue_voting_token(world, voter, &voter.eval_to_array());
+
+        world
+            .tx()
+            .from(voter)
+            .to(VOTING_ADDRESS)
+            .whitebox(voting::contract_obj, |sc| {
+                sc.cast_vote(
+                    1u64,
+                    managed_buffer!(&voting_token),
+                    managed_buffer!(&token_signature),
+                    voting::EncryptedVote {
+                        encrypted_choice: managed_buffer!(&choice.to_be_bytes()[..]),
+                        proof: managed_buffer!(b"zk_snark_proof"),
+                        timestamp: START_TIME,
+                    },
+                );
+            });
+    }
+
+    world.current_block().block_timestamp(END_TIME + 1);
+
+    world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(VOTING_ADDRESS)
+        .whitebox(voting::contract_obj, |sc| {
+            sc.close_election(1u64);
+            assert_eq!(sc.tally_plaintext(1u64, 10u64), 0u64);
+        });
+
+    world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(RESULTS_ADDRESS)
+        .whitebox(results::contract_obj, |sc| {
+            sc.set_voting_contract(VOTING_ADDRESS.to_managed_address());
+        });
+}
+
+/// Importe les résultats de l'élection 1 depuis `from`, avec l'erreur attendue le cas échéant
+fn try_import_results(world: &mut ScenarioWorld, from: TestAddress, election_id: u64, expected_error: Option<&str>) {
+    let tx = world.tx().from(from).to(RESULTS_ADDRESS);
+    let call = |sc: results::ContractObj<DebugApi>| {
+        sc.import_results(election_id);
+    };
+    match expected_error {
+        Some(message) => tx.returns(ExpectError(4, message)).whitebox(results::contract_obj, call),
+        None => tx.whitebox(results::contract_obj, call),
+    }
+}
+
+/// Importe les résultats une fois le délai de contestation du contrat de vote écoulé
+fn import_results(world: &mut ScenarioWorld) {
+    world.current_block().block_timestamp(VOTING_DISPUTE_DEADLINE + 1);
+    try_import_results(world, OWNER_ADDRESS, 1u64, None);
+}
+
+fn accredit_observer(world: &mut ScenarioWorld, observer: TestAddress) {
+    world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(RESULTS_ADDRESS)
+        .whitebox(results::contract_obj, |sc| {
+            sc.accredit_observer(
+                observer.to_managed_address(),
+                managed_buffer!(b"Observatoire citoyen"),
+                results::ObserverType::Ngo,
+            );
+        });
+}
+
+fn set_contest_settings(world: &mut ScenarioWorld) {
+    world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(RESULTS_ADDRESS)
+        .whitebox(results::contract_obj, |sc| {
+            sc.set_contest_settings(1_000u64, 500u64);
+        });
+}
+
+fn certify(world: &mut ScenarioWorld, observer: TestAddress, expected_error: Option<&str>) {
+    let tx = world.tx().from(observer).to(RESULTS_ADDRESS);
+    let call = |sc: results::ContractObj<DebugApi>| {
+        sc.certify_results(1u64, OptionalValue::Some(managed_buffer!(REPORT_IPFS)));
+    };
+    match expected_error {
+        Some(message) => tx.returns(ExpectError(4, message)).whitebox(results::contract_obj, call),
+        None => tx.whitebox(results::contract_obj, call),
+    }
+}
+
+fn contest(world: &mut ScenarioWorld, observer: TestAddress, reason_ipfs: &[u8], expected_error: Option<&str>) {
+    let tx = world.tx().from(observer).to(RESULTS_ADDRESS);
+    let call = |sc: results::ContractObj<DebugApi>| {
+        sc.contest_results(1u64, managed_buffer!(reason_ipfs));
+    };
+    match expected_error {
+        Some(message) => tx.returns(ExpectError(4, message)).whitebox(results::contract_obj, call),
+        None => tx.whitebox(results::contract_obj, call),
+    }
+}
+
+fn resolve(world: &mut ScenarioWorld, upheld: bool, expected_error: Option<&str>) {
+    let tx = world.tx().from(OWNER_ADDRESS).to(RESULTS_ADDRESS);
+    let call = |sc: results::ContractObj<DebugApi>| {
+        sc.resolve_contestation(1u64, upheld);
+    };
+    match expected_error {
+        Some(message) => tx.returns(ExpectError(4, message)).whitebox(results::contract_obj, call),
+        None => tx.whitebox(results::contract_obj, call),
+    }
+}
+
+fn check_certified(world: &mut ScenarioWorld, expected: bool) {
+    world
+        .query()
+        .to(RESULTS_ADDRESS)
+        .whitebox(results::contract_obj, |sc| {
+            assert_eq!(sc.is_certified(1u64), expected);
+        });
+}
+
+#[test]
+fn test_import_results_once() {
+    let mut world = world();
+    setup(&mut world);
+
+    try_import_results(&mut world, PUBLISHER_ADDRESS, 1u64, Some("Non autorisé à publier des résultats"));
+
+    world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(RESULTS_ADDRESS)
+        .whitebox(results::contract_obj, |sc| {
+            sc.add_publisher(PUBLISHER_ADDRESS.to_managed_address());
+        });
+
+    // Results are read from the voting contract, which withholds them during its dispute window
+    try_import_results(&mut world, PUBLISHER_ADDRESS, 1u64, Some("Délai de contestation en cours"));
+
+    world.current_block().block_timestamp(VOTING_DISPUTE_DEADLINE + 1);
+
+    try_import_results(&mut world, PUBLISHER_ADDRESS, 1u64, None);
+
+    // Percentages are in basis points of the votes cast
+    world
+        .query()
+        .to(RESULTS_ADDRESS)
+        .whitebox(results::contract_obj, |sc| {
+            let candidate_results: Vec<(u32, u64, u64)> = sc
+                .get_candidate_results(1u64)
+                .into_iter()
+                .map(|result| (result.candidate_id, result.vote_count, result.percentage))
+                .collect();
+            assert_eq!(candidate_results, vec![(1, 2, 6666), (2, 1, 3333)]);
+        });
+
+    // Imported results are immutable
+    try_import_results(&mut world, OWNER_ADDRESS, 1u64, Some("Résultats déjà publiés"));
+
+    world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(RESULTS_ADDRESS)
+        .whitebox(results::contract_obj, |sc| {
+            sc.remove_publisher(PUBLISHER_ADDRESS.to_managed_address());
+        });
+
+    try_import_results(&mut world, PUBLISHER_ADDRESS, 2u64, Some("Non autorisé à publier des résultats"));
+}
+
+#[test]
+fn test_observer_management() {
+    let mut world = world();
+    setup(&mut world);
+    import_results(&mut world);
+
+    // The owner check lives in the endpoint wrapper, so it is called through the endpoint
+    world
+        .tx()
+        .from(OBSERVER1_ADDRESS)
+        .to(RESULTS_ADDRESS)
+        .raw_call("accreditObserver")
+        .argument(&OBSERVER1_ADDRESS)
+        .argument(&"Observatoire citoyen")
+        .argument(&results::ObserverType::Ngo)
+        .returns(ExpectError(4, "Endpoint can only be called by owner"))
+        .run();
+
+    world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(RESULTS_ADDRESS)
+        .returns(ExpectError(4, "Nom de l'observateur requis"))
+        .whitebox(results::contract_obj, |sc| {
+            sc.accredit_observer(
+                OBSERVER1_ADDRESS.to_managed_address(),
+                managed_buffer!(b""),
+                results::ObserverType::Bailiff,
+            );
+        });
+
+    certify(&mut world, OBSERVER1_ADDRESS, Some("Observateur non accrédité"));
+
+    accredit_observer(&mut world, OBSERVER1_ADDRESS);
+
+    certify(&mut world, OBSERVER1_ADDRESS, None);
+    certify(&mut world, OBSERVER1_ADDRESS, Some("Résultats déjà certifiés par cet observateur"));
+
+    world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(RESULTS_ADDRESS)
+        .returns(ExpectError(4, "Observateur inconnu"))
+        .whitebox(results::contract_obj, |sc| {
+            sc.revoke_observer(OBSERVER2_ADDRESS.to_managed_address());
+        });
+
+    // A revoked observer can no longer certify or contest
+    accredit_observer(&mut world, OBSERVER2_ADDRESS);
+
+    world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(RESULTS_ADDRESS)
+        .whitebox(results::contract_obj, |sc| {
+            sc.revoke_observer(OBSERVER2_ADDRESS.to_managed_address());
+        });
+
+    certify(&mut world, OBSERVER2_ADDRESS, Some("Observateur non accrédité"));
+    contest(&mut world, OBSERVER2_ADDRESS, REPORT_IPFS, Some("Observateur non accrédité"));
+
+    check_certified(&mut world, false);
+}
+
+#[test]
+fn test_certification_quorum() {
+    let mut world = world();
+    setup(&mut world);
+
+    for observer in [OBSERVER1_ADDRESS, OBSERVER2_ADDRESS, OBSERVER3_ADDRESS] {
+        accredit_observer(&mut world, observer);
+    }
+
+    world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(RESULTS_ADDRESS)
+        .returns(ExpectError(4, "Le quorum doit être positif"))
+        .whitebox(results::contract_obj, |sc| {
+            sc.set_certification_quorum(0u32);
+        });
+
+    world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(RESULTS_ADDRESS)
+        .whitebox(results::contract_obj, |sc| {
+            sc.set_certification_quorum(3u32);
+        });
+
+    certify(&mut world, OBSERVER1_ADDRESS, Some("Résultats non disponibles"));
+
+    import_results(&mut world);
+
+    // The results stay Published until the third certification
+    certify(&mut world, OBSERVER1_ADDRESS, None);
+    certify(&mut world, OBSERVER2_ADDRESS, None);
+    check_certified(&mut world, false);
+
+    certify(&mut world, OBSERVER3_ADDRESS, None);
+    check_certified(&mut world, true);
+}
+
+#[test]
+fn test_contest_and_resolve_rejected() {
+    let mut world = world();
+    setup(&mut world);
+
+    for observer in [OBSERVER1_ADDRESS, OBSERVER2_ADDRESS] {
+        accredit_observer(&mut world, observer);
+    }
+
+    set_contest_settings(&mut world);
+    import_results(&mut world);
+
+    contest(&mut world, OBSERVER1_ADDRESS, b"QmShort", Some("Invalid IPFS CID"));
+
+    contest(&mut world, OBSERVER1_ADDRESS, REPORT_IPFS, None);
+    contest(&mut world, OBSERVER2_ADDRESS, REPORT_IPFS, Some("Résultats déjà contestés"));
+
+    // Certifications are still recorded but do not certify contested results
+    certify(&mut world, OBSERVER1_ADDRESS, None);
+    certify(&mut world, OBSERVER2_ADDRESS, None);
+    check_certified(&mut world, false);
+
+    resolve(&mut world, false, Some("Fenêtre de contestation encore ouverte"));
+
+    world.current_block().block_timestamp(VOTING_DISPUTE_DEADLINE + 1 + 500);
+
+    // Rejected: the quorum was reached meanwhile, so the results become certified
+    resolve(&mut world, false, None);
+    check_certified(&mut world, true);
+
+    resolve(&mut world, true, Some("Contestation déjà tranchée"));
+}
+
+#[test]
+fn test_contest_and_resolve_upheld() {
+    let mut world = world();
+    setup(&mut world);
+    accredit_observer(&mut world, OBSERVER1_ADDRESS);
+    set_contest_settings(&mut world);
+
+    resolve(&mut world, true, Some("Aucune contestation"));
+
+    import_results(&mut world);
+
+    contest(&mut world, OBSERVER1_ADDRESS, REPORT_IPFS, None);
+
+    world.current_block().block_timestamp(VOTING_DISPUTE_DEADLINE + 1 + 500);
+
+    resolve(&mut world, true, None);
+
+    // Invalidated results cannot be certified anymore
+    certify(&mut world, OBSERVER1_ADDRESS, Some("Résultats invalidés"));
+    check_certified(&mut world, false);
+}
+
+#[test]
+fn test_contest_period_expires() {
+    let mut world = world();
+    setup(&mut world);
+    accredit_observer(&mut world, OBSERVER1_ADDRESS);
+    set_contest_settings(&mut world);
+
+    import_results(&mut world);
+
+    world.current_block().block_timestamp(VOTING_DISPUTE_DEADLINE + 1 + 1_001);
+
+    contest(&mut world, OBSERVER1_ADDRESS, REPORT_IPFS, Some("Délai de contestation expiré"));
+}
@@ -3,16 +3,9 @@
 multiversx_sc::imports!();
 multiversx_sc::derive_imports!();
 
-// ==================== TYPES (AVANT LE TRAIT) ====================
+pub use democratix_common::{NumericTerritorialScope, RICType, TerritorialScope};
 
-#[type_abi]
-#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, PartialEq, Clone, Debug)]
-pub enum RICType {
-    Legislatif,       // Nouvelle loi
-    Abrogatoire,      // Suppression loi existante
-    Revocatoire,      // Destitution élu
-    Constitutionnel,  // Modification constitution
-}
+// ==================== TYPES (AVANT LE TRAIT) ====================
 
 #[type_abi]
 #[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, PartialEq, Clone, Debug)]
@@ -30,21 +23,12 @@ pub enum RICStatus {
     Implemented,             // Loi promulguée
 }
 
-#[type_abi]
-#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, PartialEq, Clone, Debug)]
-pub enum TerritorialScope {
-    National,
-    Regional { region_id: u32 },
-    Departmental { department_id: u32 },
-    Municipal { city_code: u32 },
-}
-
 #[type_abi]
 #[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone)]
 pub struct RICProposal<M: ManagedTypeApi> {
     pub id: u64,
     pub ric_type: RICType,
-    pub scope: TerritorialScope,
+    pub scope: NumericTerritorialScope,
     pub title: ManagedBuffer<M>,
     pub proposed_law_ipfs: ManagedBuffer<M>,      // Hash IPFS du texte de loi complet
     pub justification_ipfs: ManagedBuffer<M>,     // Exposé des motifs
@@ -59,6 +43,14 @@ pub struct RICProposal<M: ManagedTypeApi> {
     pub validation_decision_ipfs: Option<ManagedBuffer<M>>, // Décision Conseil Constit.
 }
 
+/// Lien vers la pétition à l'origine d'un RIC
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone)]
+pub struct PetitionLink<M: ManagedTypeApi> {
+    pub petition_contract: ManagedAddress<M>,
+    pub petition_id: u64,
+}
+
 #[type_abi]
 #[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone)]
 pub struct RICReferendum<M: ManagedTypeApi> {
@@ -143,7 +135,22 @@ pub trait RicContract {
     fn constitutional_council(&self) -> UnorderedSetMapper<ManagedAddress>;
 
     #[storage_mapper("electorate_sizes")]
-    fn electorate_sizes(&self, scope: &TerritorialScope) -> SingleValueMapper<u64>;
+    fn electorate_sizes(&self, scope: &NumericTerritorialScope) -> SingleValueMapper<u64>;
+
+    /// Contrats de pétition autorisés à appeler `createRICFromPetition`
+    #[storage_mapper("petition_contracts")]
+    fn petition_contracts(&self) -> UnorderedSetMapper<ManagedAddress>;
+
+    #[storage_mapper("proposal_by_petition")]
+    fn proposal_by_petition(
+        &self,
+        petition_contract: &ManagedAddress,
+        petition_id: u64,
+    ) -> SingleValueMapper<u64>;
+
+    /// Pétition d'origine d'un RIC converti (hors de `RICProposal` pour garder son encodage)
+    #[storage_mapper("proposal_source_petition")]
+    fn proposal_source_petition(&self, proposal_id: u64) -> SingleValueMapper<PetitionLink<Self::Api>>;
 
     // ==================== ENDPOINTS ====================
 
@@ -152,7 +159,7 @@ pub trait RicContract {
     fn submit_ric_proposal(
         &self,
         ric_type: RICType,
-        scope: TerritorialScope,
+        scope: NumericTerritorialScope,
         title: ManagedBuffer,
         proposed_law_ipfs: ManagedBuffer,
         justification_ipfs: ManagedBuffer,
@@ -166,10 +173,10 @@ pub trait RicContract {
 
         // Calculer la deadline (30-90 jours selon scope)
         let signature_deadline = match &scope {
-            TerritorialScope::National => current_time + 7_776_000, // 90 jours
-            TerritorialScope::Regional { .. } => current_time + 5_184_000, // 60 jours
-            TerritorialScope::Departmental { .. } => current_time + 5_184_000, // 60 jours
-            TerritorialScope::Municipal { .. } => current_time + 2_592_000, // 30 jours
+            NumericTerritorialScope::National => current_time + 7_776_000, // 90 jours
+            NumericTerritorialScope::Regional { .. } => current_time + 5_184_000, // 60 jours
+            NumericTerritorialScope::Departmental { .. } => current_time + 5_184_000, // 60 jours
+            NumericTerritorialScope::Municipal { .. } => current_time + 2_592_000, // 30 jours
         };
 
         let proposal_id = self.next_proposal_id().get();
@@ -206,6 +213,83 @@ pub trait RicContract {
         proposal_id
     }
 
+    /// Créer un RIC à partir d'une pétition éligible
+    /// Réservé aux contrats de pétition enregistrés ; la collecte a déjà eu lieu sur la
+    /// pétition, le RIC est donc créé directement en attente de validation, à condition
+    /// que ses signatures atteignent le seuil RIC du territoire
+    #[endpoint(createRICFromPetition)]
+    fn create_ric_from_petition(
+        &self,
+        petition_id: u64,
+        ric_type: RICType,
+        title: ManagedBuffer,
+        description_ipfs: ManagedBuffer,
+        scope: TerritorialScope<Self::Api>,
+        signatures_count: u64,
+    ) -> u64 {
+        let caller = self.blockchain().get_caller();
+        require!(
+            self.petition_contracts().contains(&caller),
+            "Contrat de pétition non autorisé"
+        );
+        require!(
+            self.proposal_by_petition(&caller, petition_id).is_empty(),
+            "Pétition déjà convertie"
+        );
+
+        // Les codes non numériques (Corse : 2A, 2B) n'ont pas d'équivalent côté RIC
+        let numeric_scope = scope.to_numeric();
+        require!(numeric_scope.is_some(), "Périmètre territorial non supporté");
+        let scope = numeric_scope.unwrap();
+
+        // Pas de seuil par défaut : la collecte a déjà eu lieu, le seuil doit être réel
+        require!(
+            !self.electorate_sizes(&scope).is_empty(),
+            "Taille de l'électorat non configurée pour ce territoire"
+        );
+
+        let current_time = self.blockchain().get_block_timestamp();
+        let target_signatures = self.calculate_signature_threshold(&scope);
+        require!(
+            signatures_count >= target_signatures,
+            "Signatures insuffisantes pour le seuil RIC du territoire"
+        );
+        let proposal_id = self.next_proposal_id().get();
+
+        let proposal = RICProposal {
+            id: proposal_id,
+            ric_type,
+            scope,
+            title: title.clone(),
+            proposed_law_ipfs: description_ipfs.clone(),
+            justification_ipfs: description_ipfs,
+            impact_study_ipfs: ManagedBuffer::new(),
+            signatures_count,
+            target_signatures,
+            status: RICStatus::SignaturesReached,
+            proposer: caller.clone(),
+            created_at: current_time,
+            signature_deadline: current_time,
+            referendum_id: None,
+            validation_decision_ipfs: None,
+        };
+
+        self.proposals().insert(proposal_id, proposal);
+        self.proposal_source_petition(proposal_id).set(PetitionLink {
+            petition_contract: caller.clone(),
+            petition_id,
+        });
+        self.proposal_by_petition(&caller, petition_id).set(proposal_id);
+        self.next_proposal_id().set(proposal_id + 1);
+
+        // Event
+        self.ric_proposal_created_event(proposal_id, title, target_signatures, current_time);
+        self.ric_created_from_petition_event(proposal_id, &caller, petition_id);
+        self.ric_threshold_reached_event(proposal_id, signatures_count);
+
+        proposal_id
+    }
+
     /// Signer une proposition de RIC
     /// Utilise un système de nullifier pour empêcher double signature
     #[endpoint(signRICProposal)]
@@ -496,6 +580,28 @@ pub trait RicContract {
         args
     }
 
+    #[view(getProposalStatus)]
+    fn get_proposal_status(&self, proposal_id: u64) -> RICStatus {
+        self.proposals().get(&proposal_id).unwrap().status
+    }
+
+    /// ID du RIC créé depuis une pétition (0 si aucun)
+    #[view(getProposalByPetition)]
+    fn get_proposal_by_petition(&self, petition_contract: ManagedAddress, petition_id: u64) -> u64 {
+        self.proposal_by_petition(&petition_contract, petition_id).get()
+    }
+
+    /// Pétition d'origine d'un RIC (aucune valeur s'il n'a pas été converti)
+    #[view(getProposalSourcePetition)]
+    fn get_proposal_source_petition(&self, proposal_id: u64) -> OptionalValue<PetitionLink<Self::Api>> {
+        let source_petition = self.proposal_source_petition(proposal_id);
+        if source_petition.is_empty() {
+            OptionalValue::None
+        } else {
+            OptionalValue::Some(source_petition.get())
+        }
+    }
+
     #[view(getSignatureProgress)]
     fn get_signature_progress(&self, proposal_id: u64) -> (u64, u64) {
         let proposal = self.proposals().get(&proposal_id).unwrap();
@@ -510,15 +616,31 @@ pub trait RicContract {
         self.constitutional_council().insert(member);
     }
 
+    #[only_owner]
+    #[endpoint(addPetitionContract)]
+    fn add_petition_contract(&self, petition_contract: ManagedAddress) {
+        require!(
+            self.blockchain().is_smart_contract(&petition_contract),
+            "Le contrat de pétition doit être un smart contract"
+        );
+        self.petition_contracts().insert(petition_contract);
+    }
+
+    #[only_owner]
+    #[endpoint(removePetitionContract)]
+    fn remove_petition_contract(&self, petition_contract: ManagedAddress) {
+        self.petition_contracts().swap_remove(&petition_contract);
+    }
+
     #[only_owner]
     #[endpoint(setElectorateSize)]
-    fn set_electorate_size(&self, scope: TerritorialScope, size: u64) {
+    fn set_electorate_size(&self, scope: NumericTerritorialScope, size: u64) {
         self.electorate_sizes(&scope).set(size);
     }
 
     // ==================== PRIVATE FUNCTIONS ====================
 
-    fn calculate_signature_threshold(&self, scope: &TerritorialScope) -> u64 {
+    fn calculate_signature_threshold(&self, scope: &NumericTerritorialScope) -> u64 {
         let electorate = self.electorate_sizes(scope).get();
 
         if electorate == 0 {
@@ -526,10 +648,10 @@ pub trait RicContract {
         }
 
         match scope {
-            TerritorialScope::National => electorate / 100,          // 1%
-            TerritorialScope::Regional { .. } => electorate * 2 / 100, // 2%
-            TerritorialScope::Departmental { .. } => electorate * 2 / 100, // 2%
-            TerritorialScope::Municipal { .. } => electorate * 5 / 100,    // 5%
+            NumericTerritorialScope::National => electorate / 100,          // 1%
+            NumericTerritorialScope::Regional { .. } => electorate * 2 / 100, // 2%
+            NumericTerritorialScope::Departmental { .. } => electorate * 2 / 100, // 2%
+            NumericTerritorialScope::Municipal { .. } => electorate * 5 / 100,    // 5%
         }
     }
 
@@ -559,6 +681,14 @@ pub trait RicContract {
         final_signatures: u64,
     );
 
+    #[event("ric_created_from_petition")]
+    fn ric_created_from_petition_event(
+        &self,
+        #[indexed] proposal_id: u64,
+        #[indexed] petition_contract: &ManagedAddress,
+        #[indexed] petition_id: u64,
+    );
+
     #[event("ric_validated")]
     fn ric_validated_event(
         &self,
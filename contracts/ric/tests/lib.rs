@@ -0,0 +1 @@
+mod ric_petition_test;
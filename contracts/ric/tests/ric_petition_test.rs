@@ -0,0 +1,259 @@
+use institution_registry::InstitutionRegistryContract;
+use multiversx_sc_scenario::imports::*;
+use petition::PetitionContract;
+use ric::RicContract;
+
+const RIC_CODE: MxscPath = MxscPath::new("output/ric.mxsc.json");
+const PETITION_CODE: MxscPath = MxscPath::new("../petition/output/petition.mxsc.json");
+const INSTITUTION_REGISTRY_CODE: MxscPath =
+    MxscPath::new("../institution_registry/output/institution_registry.mxsc.json");
+
+const RIC_ADDRESS: TestSCAddress = TestSCAddress::new("ric");
+const PETITION_ADDRESS: TestSCAddress = TestSCAddress::new("petition");
+const INSTITUTION_REGISTRY_ADDRESS: TestSCAddress = TestSCAddress::new("institution-registry");
+
+const OWNER_ADDRESS: TestAddress = TestAddress::new("owner");
+const CREATOR_ADDRESS: TestAddress = TestAddress::new("creator");
+const CITIZEN_ADDRESS: TestAddress = TestAddress::new("citizen");
+const VERIFIER_ADDRESS: TestAddress = TestAddress::new("verifier");
+
+const LYON_INSEE_CODE: &[u8] = b"69123";
+const DESCRIPTION_IPFS: &[u8] = b"QmYwAPJzv5CZsnA625s3Xf2nemtYgPpHdWEz79ojWnPbdG";
+
+fn world() -> ScenarioWorld {
+    let mut blockchain = ScenarioWorld::new();
+    blockchain.register_contract(RIC_CODE, ric::ContractBuilder);
+    blockchain.register_contract(PETITION_CODE, petition::ContractBuilder);
+    blockchain.register_contract(INSTITUTION_REGISTRY_CODE, institution_registry::ContractBuilder);
+    blockchain
+}
+
+fn lyon_scope() -> petition::TerritorialScope<DebugApi> {
+    petition::TerritorialScope::Commune {
+        insee_code: managed_buffer!(LYON_INSEE_CODE),
+    }
+}
+
+/// Déploie RIC, pétition et registre des institutions, puis les connecte entre eux
+fn setup(world: &mut ScenarioWorld) {
+    for account in [OWNER_ADDRESS, CREATOR_ADDRESS, CITIZEN_ADDRESS] {
+        world.account(account).nonce(1);
+    }
+    world.current_block().block_timestamp(1000);
+
+    world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .raw_deploy()
+        .code(RIC_CODE)
+        .new_address(RIC_ADDRESS)
+        .whitebox(ric::contract_obj, |sc| {
+            sc.init();
+        });
+
+    world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .raw_deploy()
+        .code(PETITION_CODE)
+        .new_address(PETITION_ADDRESS)
+        .whitebox(petition::contract_obj, |sc| {
+            sc.init();
+        });
+
+    world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .raw_deploy()
+        .code(INSTITUTION_REGISTRY_CODE)
+        .new_address(INSTITUTION_REGISTRY_ADDRESS)
+        .whitebox(institution_registry::contract_obj, |sc| {
+            sc.init();
+        });
+
+    // Lyon, avec RIC local activé : une pétition sans réponse devient éligible RIC
+    world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(INSTITUTION_REGISTRY_ADDRESS)
+        .whitebox(institution_registry::contract_obj, |sc| {
+            let institution_id = sc.register_institution(
+                managed_buffer!(LYON_INSEE_CODE),
+                managed_buffer!(b"216901231"),
+                managed_buffer!(b"Lyon"),
+                managed_buffer!(b"lyon"),
+                institution_registry::InstitutionLevel::Commune,
+                522_000u64,
+                1_000u64,
+                managed_buffer!(b"84"),
+                managed_buffer!(b"69"),
+                managed_buffer!(b"45.7578"),
+                managed_buffer!(b"4.8320"),
+            );
+            assert_eq!(institution_id, 1u64);
+
+            sc.enable_democratix(1u64, true, 5u32, 5u32);
+        });
+
+    world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(PETITION_ADDRESS)
+        .whitebox(petition::contract_obj, |sc| {
+            sc.set_institution_registry(INSTITUTION_REGISTRY_ADDRESS.to_managed_address());
+            sc.set_ric_contract(RIC_ADDRESS.to_managed_address());
+            sc.set_proof_verifier(VERIFIER_ADDRESS.to_managed_address());
+            sc.set_electorate_root(lyon_scope(), managed_buffer!(&[7u8; 32][..]));
+        });
+
+    world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(RIC_ADDRESS)
+        .whitebox(ric::contract_obj, |sc| {
+            sc.add_petition_contract(PETITION_ADDRESS.to_managed_address());
+        });
+}
+
+/// Convertit la pétition 1 en RIC abrogatoire depuis `from`, avec l'erreur attendue le cas échéant
+fn convert_to_ric(world: &mut ScenarioWorld, from: TestAddress, expected_error: Option<&str>) {
+    let tx = world.tx().from(from).to(PETITION_ADDRESS);
+    let call = |sc: petition::ContractObj<DebugApi>| {
+        let proposal_id = sc.convert_to_ric(1u64, petition::RICType::Abrogatoire);
+        assert_eq!(proposal_id, 1u64);
+    };
+    match expected_error {
+        Some(message) => tx.returns(ExpectError(4, message)).whitebox(petition::contract_obj, call),
+        None => tx.whitebox(petition::contract_obj, call),
+    }
+}
+
+fn set_electorate_size(world: &mut ScenarioWorld, size: u64) {
+    world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(RIC_ADDRESS)
+        .whitebox(ric::contract_obj, |sc| {
+            sc.set_electorate_size(ric::NumericTerritorialScope::Municipal { city_code: 69123 }, size);
+        });
+}
+
+#[test]
+fn test_create_ric_from_petition_requires_registered_contract() {
+    let mut world = world();
+    setup(&mut world);
+
+    world
+        .tx()
+        .from(CITIZEN_ADDRESS)
+        .to(RIC_ADDRESS)
+        .returns(ExpectError(4, "Contrat de pétition non autorisé"))
+        .whitebox(ric::contract_obj, |sc| {
+            sc.create_ric_from_petition(
+                1u64,
+                ric::RICType::Legislatif,
+                managed_buffer!(b"Pistes cyclables"),
+                managed_buffer!(DESCRIPTION_IPFS),
+                lyon_scope(),
+                100u64,
+            );
+        });
+}
+
+#[test]
+fn test_petition_to_ric_flow() {
+    let mut world = world();
+    setup(&mut world);
+
+    world
+        .tx()
+        .from(CREATOR_ADDRESS)
+        .to(PETITION_ADDRESS)
+        .whitebox(petition::contract_obj, |sc| {
+            let petition_id = sc.create_petition(
+                managed_buffer!(b"Pistes cyclables"),
+                managed_buffer!(DESCRIPTION_IPFS),
+                lyon_scope(),
+                petition::PetitionType::Local,
+                30u64,
+            );
+            assert_eq!(petition_id, 1u64);
+        });
+
+    // 5% de 1 000 électeurs, relevé au minimum de 100 signatures
+    for i in 0..100u32 {
+        let nullifier = format!("{:064}", i);
+
+        world
+            .tx()
+            .from(CITIZEN_ADDRESS)
+            .to(PETITION_ADDRESS)
+            .whitebox(petition::contract_obj, |sc| {
+                sc.sign_petition(
+                    1u64,
+                    managed_buffer!(nullifier.as_bytes()),
+                    managed_buffer!(b"groth16_membership_proof"),
+                );
+            });
+    }
+
+    // Seuil atteint, mais la mairie a encore 30 jours pour répondre
+    convert_to_ric(
+        &mut world,
+        CREATOR_ADDRESS,
+        Some("Petition not eligible for RIC (need 1M signatures)"),
+    );
+
+    world.current_block().block_timestamp(1000 + 30 * 86400 + 1);
+
+    world
+        .tx()
+        .from(CITIZEN_ADDRESS)
+        .to(PETITION_ADDRESS)
+        .whitebox(petition::contract_obj, |sc| {
+            sc.check_response_deadline(1u64);
+        });
+
+    // Seul le créateur choisit le type de RIC
+    convert_to_ric(&mut world, CITIZEN_ADDRESS, Some("Only creator can convert to RIC"));
+
+    // Sans électorat configuré pour le territoire, aucun seuil RIC n'est appliqué par défaut
+    convert_to_ric(
+        &mut world,
+        CREATOR_ADDRESS,
+        Some("Taille de l'électorat non configurée pour ce territoire"),
+    );
+
+    // Le RIC exige son propre seuil : 5% de 10 000 électeurs, soit 500 signatures
+    set_electorate_size(&mut world, 10_000u64);
+    convert_to_ric(
+        &mut world,
+        CREATOR_ADDRESS,
+        Some("Signatures insuffisantes pour le seuil RIC du territoire"),
+    );
+
+    set_electorate_size(&mut world, 2_000u64);
+    convert_to_ric(&mut world, CREATOR_ADDRESS, None);
+
+    // Le RIC est créé en attente de validation, avec un lien vers la pétition
+    world
+        .query()
+        .to(RIC_ADDRESS)
+        .whitebox(ric::contract_obj, |sc| {
+            assert_eq!(sc.get_proposal_status(1u64), ric::RICStatus::SignaturesReached);
+            assert_eq!(sc.get_proposal_by_petition(PETITION_ADDRESS.to_managed_address(), 1u64), 1u64);
+
+            let source_petition = sc.get_proposal_source_petition(1u64).into_option().unwrap();
+            assert_eq!(source_petition.petition_contract, PETITION_ADDRESS.to_managed_address());
+            assert_eq!(source_petition.petition_id, 1u64);
+        });
+
+    world
+        .query()
+        .to(PETITION_ADDRESS)
+        .whitebox(petition::contract_obj, |sc| {
+            assert_eq!(sc.get_petition_ric_id(1u64), 1u64);
+        });
+
+    convert_to_ric(&mut world, CREATOR_ADDRESS, Some("Petition already converted"));
+}
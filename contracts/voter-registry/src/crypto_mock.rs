@@ -1,65 +0,0 @@
-multiversx_sc::imports!();
-
-/// Module de vérification cryptographique (MOCK pour le développement)
-///
-/// AVERTISSEMENT: Cette implémentation est un MOCK pour le POC.
-/// Pour la production, elle DOIT être remplacée par une vraie implémentation zk-SNARK.
-pub mod crypto_verification {
-    use multiversx_sc::api::ManagedTypeApi;
-    use multiversx_sc::types::ManagedBuffer;
-
-    /// Vérifie une preuve d'éligibilité d'électeur (VERSION MOCK)
-    ///
-    /// Dans la version production, cette fonction devrait vérifier:
-    /// 1. L'électeur est dans le registre électoral (Merkle proof)
-    /// 2. L'électeur n'a pas déjà un token actif
-    /// 3. Les credentials sont valides (signature gouvernementale)
-    /// 4. Sans révéler l'identité de l'électeur
-    ///
-    /// # Arguments
-    /// * `credential_proof` - Preuve zk d'identité de l'électeur
-    ///
-    /// # Returns
-    /// `true` si l'électeur est éligible
-    pub fn verify_voter_eligibility<M: ManagedTypeApi>(
-        credential_proof: &ManagedBuffer<M>,
-    ) -> bool {
-        // MOCK: Accepte toute preuve d'au moins 32 bytes
-        // TODO: Implémenter vraie vérification avec:
-        // - Vérification de signature numérique (FranceConnect)
-        // - Merkle proof que l'électeur est dans l'arbre des électeurs éligibles
-        // - Preuve zk que les credentials sont valides sans révéler l'identité
-
-        credential_proof.len() >= 32
-    }
-}
-
-#[cfg(test)]
-mod tests {
-    use super::crypto_verification::*;
-    use multiversx_sc::types::ManagedBuffer;
-    use multiversx_sc_scenario::api::StaticApi;
-
-    #[test]
-    fn test_verify_voter_eligibility_valid() {
-        // 32+ bytes credential
-        let credential = ManagedBuffer::<StaticApi>::from(b"12345678901234567890123456789012");
-
-        assert!(verify_voter_eligibility(&credential));
-    }
-
-    #[test]
-    fn test_verify_voter_eligibility_invalid_short() {
-        // Less than 32 bytes
-        let credential = ManagedBuffer::<StaticApi>::from(b"short");
-
-        assert!(!verify_voter_eligibility(&credential));
-    }
-
-    #[test]
-    fn test_verify_voter_eligibility_empty() {
-        let credential = ManagedBuffer::<StaticApi>::new();
-
-        assert!(!verify_voter_eligibility(&credential));
-    }
-}
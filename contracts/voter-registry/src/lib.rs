@@ -2,22 +2,46 @@
 
 use multiversx_sc::{derive_imports::*, imports::*};
 
-mod crypto_mock;
+use crypto_verifier::{DefaultVerifier, ProofVerifier};
 
 /// Structure représentant un électeur
+///
+/// Seuls le token aveuglé et sa signature aveugle sont conservés : le registre ne
+/// connaît jamais le token réellement utilisé pour voter.
 #[type_abi]
 #[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Debug)]
 pub struct Voter<M: ManagedTypeApi> {
     pub credential_hash: ManagedBuffer<M>,
     pub is_registered: bool,
     pub has_voted: bool,
-    pub voting_token: ManagedBuffer<M>,
+    pub blinded_token: ManagedBuffer<M>,
+    pub blind_signature: ManagedBuffer<M>, // vide tant que l'autorité n'a pas signé
+}
+
+/// Cycle de vie d'un token de vote
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum TokenStatus {
+    Unknown,
+    Issued,
+    Used,
+    Expired,
 }
 
 /// Smart Contract d'Enregistrement des Électeurs
 ///
 /// Ce contrat gère l'enregistrement des électeurs éligibles pour une élection.
 /// Il utilise des preuves zk-SNARK pour vérifier l'éligibilité sans révéler l'identité.
+///
+/// Les tokens de vote sont des signatures RSA aveugles (RSA-FDH) :
+/// 1. L'électeur choisit un token `t` et un facteur `r`, puis soumet
+///    `m' = H(election_id || t) * r^e mod n` avec `registerVoter`
+/// 2. L'autorité signe `m'` hors-chaîne et publie `s' = m'^d mod n` avec `signBlindedToken`
+/// 3. L'électeur calcule `s = s' * r^-1 mod n` et présente `(t, s)` au contrat de vote,
+///    qui le transmet au registre : celui-ci vérifie `s^e mod n == H(election_id || t) mod n`
+///    avant d'accepter ou de consommer le token
+///
+/// Le registre ne voit jamais `t` avant le vote et ne peut pas le relier à `m'`.
 #[multiversx_sc::contract]
 pub trait VoterRegistry {
     #[init]
@@ -29,22 +53,33 @@ pub trait VoterRegistry {
 
     /// Enregistre un nouvel électeur avec une preuve zk-SNARK
     ///
+    /// L'électeur soumet un token aveuglé que l'autorité d'émission signera via
+    /// `signBlindedToken`. L'attestation d'éligibilité porte sur
+    /// `election_id || blinded_token` : une preuve observée dans le mempool ne peut pas
+    /// être rejouée avec un autre token.
+    ///
     /// # Arguments
     /// * `election_id` - ID de l'élection
     /// * `credential_proof` - Preuve zk-SNARK d'éligibilité
+    /// * `blinded_token` - Token de vote aveuglé à faire signer
     #[endpoint(registerVoter)]
     fn register_voter(
         &self,
         election_id: u64,
         credential_proof: ManagedBuffer,
-    ) -> ManagedBuffer {
-        // Vérifier la preuve d'éligibilité (version MOCK pour POC)
+        blinded_token: ManagedBuffer,
+    ) {
+        require!(!blinded_token.is_empty(), "Token aveuglé requis");
+
+        // Vérifier la preuve d'éligibilité, liée au token aveuglé
+        let mut binding = ManagedBuffer::new();
+        binding.append_bytes(&election_id.to_be_bytes()[..]);
+        binding.append(&blinded_token);
         require!(
-            crypto_mock::crypto_verification::verify_voter_eligibility(&credential_proof),
+            self.proof_verifier().verify_voter_eligibility(&credential_proof, &binding),
             "Preuve d'éligibilité invalide"
         );
 
-        let caller = self.blockchain().get_caller();
         let credential_hash_bytes = self.crypto().sha256(&credential_proof);
         let credential_hash = credential_hash_bytes.as_managed_buffer().clone();
 
@@ -54,39 +89,223 @@ pub trait VoterRegistry {
             "Électeur déjà enregistré"
         );
 
-        // Générer un token de vote aveugle unique
-        let token = self.generate_voting_token(election_id, &caller);
-
         let voter = Voter {
             credential_hash: credential_hash.clone(),
             is_registered: true,
             has_voted: false,
-            voting_token: token.clone(),
+            blinded_token: blinded_token.clone(),
+            blind_signature: ManagedBuffer::new(),
         };
 
         self.voters(election_id, &credential_hash).set(&voter);
+        self.registered_voters_count(election_id).update(|count| *count += 1);
 
-        // Événement
+        // Événements
         self.voter_registered_event(election_id, credential_hash);
+        self.blind_token_requested_event(election_id, &blinded_token);
+    }
+
+    /// Publie la signature aveugle de l'autorité pour un électeur inscrit
+    ///
+    /// Seule l'autorité de signature peut appeler cette fonction, une seule fois par
+    /// électeur, ce qui limite le nombre de tokens au nombre d'inscrits.
+    ///
+    /// # Arguments
+    /// * `election_id` - ID de l'élection
+    /// * `credential_hash` - Hash du credential de l'électeur
+    /// * `blind_signature` - Signature `s' = m'^d mod n` du token aveuglé
+    #[endpoint(signBlindedToken)]
+    fn sign_blinded_token(
+        &self,
+        election_id: u64,
+        credential_hash: ManagedBuffer,
+        blind_signature: ManagedBuffer,
+    ) {
+        require!(
+            !self.blind_signature_authority().is_empty()
+                && self.blockchain().get_caller() == self.blind_signature_authority().get(),
+            "Seule l'autorité de signature peut signer les tokens"
+        );
+        require!(
+            !self.voters(election_id, &credential_hash).is_empty(),
+            "Électeur non enregistré"
+        );
+        require!(!blind_signature.is_empty(), "Signature aveugle invalide");
+        require!(
+            BigUint::from_bytes_be_buffer(&blind_signature) < self.rsa_modulus().get(),
+            "Signature aveugle invalide"
+        );
+
+        let mut voter = self.voters(election_id, &credential_hash).get();
+        require!(voter.blind_signature.is_empty(), "Token déjà signé");
+
+        voter.blind_signature = blind_signature;
+        self.voters(election_id, &credential_hash).set(&voter);
+        self.blinded_token_status(election_id, &voter.blinded_token).set(TokenStatus::Issued);
+        self.issued_tokens_count(election_id).update(|count| *count += 1);
 
-        token
+        self.blind_token_signed_event(election_id, &credential_hash);
     }
 
-    /// Vérifie si un token de vote est valide
+    /// Configure l'autorité de signature aveugle et sa clé publique RSA (admin)
+    ///
+    /// # Arguments
+    /// * `authority` - Adresse du backend autorisé à signer
+    /// * `modulus` - Module RSA `n`
+    /// * `public_exponent` - Exposant public `e` (ex: 65537)
+    #[only_owner]
+    #[endpoint(setBlindSignatureAuthority)]
+    fn set_blind_signature_authority(
+        &self,
+        authority: ManagedAddress,
+        modulus: BigUint,
+        public_exponent: u64,
+    ) {
+        require!(modulus > 0u64, "Module RSA invalide");
+        require!(
+            public_exponent >= 3 && public_exponent % 2 == 1,
+            "Exposant public RSA invalide"
+        );
+
+        self.blind_signature_authority().set(&authority);
+        self.rsa_modulus().set(&modulus);
+        self.rsa_public_exponent().set(public_exponent);
+
+        self.blind_signature_authority_set_event(&authority);
+    }
+
+    /// Retourne la clé publique RSA de l'autorité (modulus, exposant)
+    #[view(getBlindSignatureKey)]
+    fn get_blind_signature_key(&self) -> MultiValue2<BigUint, u64> {
+        (self.rsa_modulus().get(), self.rsa_public_exponent().get()).into()
+    }
+
+    /// Retourne la signature aveugle publiée pour un électeur (vide si non signée)
+    #[view(getBlindSignature)]
+    fn get_blind_signature(&self, election_id: u64, credential_hash: ManagedBuffer) -> ManagedBuffer {
+        let voter_mapper = self.voters(election_id, &credential_hash);
+        if voter_mapper.is_empty() {
+            return ManagedBuffer::new();
+        }
+
+        voter_mapper.get().blind_signature
+    }
+
+    /// Vérifie si un token de vote peut encore être utilisé (signé, non utilisé et non expiré)
     #[view(isTokenValid)]
-    fn is_token_valid(&self, election_id: u64, token: ManagedBuffer) -> bool {
-        // TODO: Implémenter la vérification du token
-        !token.is_empty()
+    fn is_token_valid(&self, election_id: u64, token: ManagedBuffer, signature: ManagedBuffer) -> bool {
+        self.get_token_status(election_id, token, signature) == TokenStatus::Issued
+    }
+
+    /// Retourne l'état d'un token de vote
+    ///
+    /// Un token dont la signature RSA-FDH de l'autorité est absente ou invalide est
+    /// `Unknown` ; une signature valide atteste l'émission. L'état `Used` est celui
+    /// enregistré par `revokeToken`.
+    ///
+    /// # Arguments
+    /// * `election_id` - ID de l'élection
+    /// * `token` - Token de vote désaveuglé
+    /// * `signature` - Signature désaveuglée de l'autorité sur le token
+    #[view(getTokenStatus)]
+    fn get_token_status(&self, election_id: u64, token: ManagedBuffer, signature: ManagedBuffer) -> TokenStatus {
+        if token.is_empty() || !self.is_token_signed(election_id, &token, &signature) {
+            return TokenStatus::Unknown;
+        }
+
+        let token_hash = self.hash_token(&token);
+        if self.token_status(election_id, &token_hash).get() == TokenStatus::Used {
+            return TokenStatus::Used;
+        }
+
+        if self.is_token_expired(election_id) {
+            return TokenStatus::Expired;
+        }
+
+        TokenStatus::Issued
+    }
+
+    /// Retourne l'état d'un token aveuglé, enregistré lors de sa signature par l'autorité
+    ///
+    /// Le token désaveuglé n'est pas relié au token aveuglé : son utilisation se lit
+    /// avec `getTokenStatus`.
+    #[view(getBlindedTokenStatus)]
+    fn get_blinded_token_status(&self, election_id: u64, blinded_token: ManagedBuffer) -> TokenStatus {
+        let status = self.blinded_token_status(election_id, &blinded_token).get();
+        if status == TokenStatus::Issued && self.is_token_expired(election_id) {
+            return TokenStatus::Expired;
+        }
+
+        status
+    }
+
+    /// Définit la date d'expiration des tokens d'une élection (admin)
+    ///
+    /// `0` désactive l'expiration.
+    #[only_owner]
+    #[endpoint(setTokenExpiration)]
+    fn set_token_expiration(&self, election_id: u64, expires_at: u64) {
+        self.token_expiration(election_id).set(expires_at);
     }
 
     /// Marque un token comme utilisé (après vote)
+    ///
+    /// Seuls les contrats de vote enregistrés via `addVotingContract` peuvent révoquer un
+    /// token, et uniquement avec une signature valide de l'autorité.
     #[endpoint(revokeToken)]
-    fn revoke_token(&self, election_id: u64, token: ManagedBuffer) {
-        // TODO: Vérifier que l'appelant est le contrat de vote
-        // TODO: Marquer le token comme utilisé
+    fn revoke_token(&self, election_id: u64, token: ManagedBuffer, signature: ManagedBuffer) {
+        let caller = self.blockchain().get_caller();
+        require!(
+            self.voting_contracts().contains(&caller),
+            "Seul un contrat de vote enregistré peut révoquer un token"
+        );
         require!(!token.is_empty(), "Token invalide");
 
-        self.used_tokens(election_id, &token).set(true);
+        match self.get_token_status(election_id, token.clone(), signature) {
+            TokenStatus::Issued => {},
+            TokenStatus::Used => sc_panic!("Token déjà utilisé"),
+            TokenStatus::Expired => sc_panic!("Token expiré"),
+            TokenStatus::Unknown => sc_panic!("Token inconnu"),
+        }
+
+        // Pas plus de votes que de signatures aveugles publiées
+        require!(
+            self.used_tokens_count(election_id).get() < self.issued_tokens_count(election_id).get(),
+            "Tous les tokens émis ont déjà été utilisés"
+        );
+
+        let token_hash = self.hash_token(&token);
+        self.token_status(election_id, &token_hash).set(TokenStatus::Used);
+        self.used_tokens_count(election_id).update(|count| *count += 1);
+
+        self.token_revoked_event(election_id, &caller);
+    }
+
+    /// Configure l'adresse du vérificateur qui atteste les preuves d'éligibilité (admin)
+    #[only_owner]
+    #[endpoint(setEligibilityVerifier)]
+    fn set_eligibility_verifier(&self, address: ManagedAddress) {
+        self.eligibility_verifier().set(address);
+    }
+
+    /// Enregistre un contrat de vote autorisé à révoquer des tokens (admin)
+    #[only_owner]
+    #[endpoint(addVotingContract)]
+    fn add_voting_contract(&self, address: ManagedAddress) {
+        self.voting_contracts().insert(address);
+    }
+
+    /// Retire un contrat de vote autorisé (admin)
+    #[only_owner]
+    #[endpoint(removeVotingContract)]
+    fn remove_voting_contract(&self, address: ManagedAddress) {
+        self.voting_contracts().swap_remove(&address);
+    }
+
+    /// Vérifie si une adresse est un contrat de vote enregistré
+    #[view(isVotingContract)]
+    fn is_voting_contract(&self, address: ManagedAddress) -> bool {
+        self.voting_contracts().contains(&address)
     }
 
     // === STORAGE ===
@@ -98,21 +317,135 @@ pub trait VoterRegistry {
         credential_hash: &ManagedBuffer,
     ) -> SingleValueMapper<Voter<Self::Api>>;
 
-    #[storage_mapper("usedTokens")]
-    fn used_tokens(&self, election_id: u64, token: &ManagedBuffer) -> SingleValueMapper<bool>;
+    /// Statut des tokens désaveuglés, par hash (`Used` une fois révoqués)
+    #[storage_mapper("tokenStatus")]
+    fn token_status(&self, election_id: u64, token_hash: &ManagedBuffer) -> SingleValueMapper<TokenStatus>;
+
+    /// Statut des tokens aveuglés (`Issued` une fois signés par l'autorité)
+    #[storage_mapper("blindedTokenStatus")]
+    fn blinded_token_status(&self, election_id: u64, blinded_token: &ManagedBuffer) -> SingleValueMapper<TokenStatus>;
+
+    #[view(getRegisteredVotersCount)]
+    #[storage_mapper("registeredVotersCount")]
+    fn registered_voters_count(&self, election_id: u64) -> SingleValueMapper<u64>;
+
+    #[view(getIssuedTokensCount)]
+    #[storage_mapper("issuedTokensCount")]
+    fn issued_tokens_count(&self, election_id: u64) -> SingleValueMapper<u64>;
+
+    #[view(getUsedTokensCount)]
+    #[storage_mapper("usedTokensCount")]
+    fn used_tokens_count(&self, election_id: u64) -> SingleValueMapper<u64>;
+
+    #[view(getTokenExpiration)]
+    #[storage_mapper("tokenExpiration")]
+    fn token_expiration(&self, election_id: u64) -> SingleValueMapper<u64>;
+
+    #[view(getBlindSignatureAuthority)]
+    #[storage_mapper("blindSignatureAuthority")]
+    fn blind_signature_authority(&self) -> SingleValueMapper<ManagedAddress>;
+
+    #[storage_mapper("rsaModulus")]
+    fn rsa_modulus(&self) -> SingleValueMapper<BigUint>;
+
+    #[storage_mapper("rsaPublicExponent")]
+    fn rsa_public_exponent(&self) -> SingleValueMapper<u64>;
+
+    #[view(getEligibilityVerifier)]
+    #[storage_mapper("eligibilityVerifier")]
+    fn eligibility_verifier(&self) -> SingleValueMapper<ManagedAddress>;
+
+    /// Contrats de vote autorisés à appeler `revokeToken`
+    #[storage_mapper("votingContracts")]
+    fn voting_contracts(&self) -> UnorderedSetMapper<ManagedAddress>;
 
     // === EVENTS ===
 
     #[event("voterRegistered")]
     fn voter_registered_event(&self, #[indexed] election_id: u64, #[indexed] credential_hash: ManagedBuffer);
 
+    #[event("blindTokenRequested")]
+    fn blind_token_requested_event(&self, #[indexed] election_id: u64, #[indexed] blinded_token: &ManagedBuffer);
+
+    #[event("blindTokenSigned")]
+    fn blind_token_signed_event(&self, #[indexed] election_id: u64, #[indexed] credential_hash: &ManagedBuffer);
+
+    #[event("blindSignatureAuthoritySet")]
+    fn blind_signature_authority_set_event(&self, #[indexed] authority: &ManagedAddress);
+
+    #[event("tokenRevoked")]
+    fn token_revoked_event(&self, #[indexed] election_id: u64, #[indexed] voting_contract: &ManagedAddress);
+
     // === PRIVATE ===
 
-    fn generate_voting_token(&self, election_id: u64, caller: &ManagedAddress) -> ManagedBuffer {
+    fn proof_verifier(&self) -> DefaultVerifier<Self::Api> {
+        require!(
+            !self.eligibility_verifier().is_empty(),
+            "Vérificateur d'éligibilité non configuré"
+        );
+
+        DefaultVerifier::new(self.eligibility_verifier().get())
+    }
+
+    fn hash_token(&self, token: &ManagedBuffer) -> ManagedBuffer {
+        self.crypto().sha256(token).as_managed_buffer().clone()
+    }
+
+    fn is_token_expired(&self, election_id: u64) -> bool {
+        let expires_at = self.token_expiration(election_id).get();
+        expires_at > 0 && self.blockchain().get_block_timestamp() >= expires_at
+    }
+
+    /// Vérifie la signature RSA-FDH de l'autorité sur un token désaveuglé
+    fn is_token_signed(&self, election_id: u64, token: &ManagedBuffer, signature: &ManagedBuffer) -> bool {
+        if self.rsa_modulus().is_empty() {
+            return false;
+        }
+
+        let modulus = self.rsa_modulus().get();
+        let message = self.blind_token_message(election_id, token, &modulus);
+        self.verify_rsa_signature(&message, signature, &modulus, self.rsa_public_exponent().get())
+    }
+
+    /// Message signé par l'autorité pour un token : `m = sha256(election_id || token) mod n`
+    fn blind_token_message(&self, election_id: u64, token: &ManagedBuffer, modulus: &BigUint) -> BigUint {
         let mut data = ManagedBuffer::new();
         data.append_bytes(&election_id.to_be_bytes()[..]);
-        data.append(caller.as_managed_buffer());
-        data.append_bytes(&self.blockchain().get_block_timestamp().to_be_bytes()[..]);
-        self.crypto().sha256(&data).as_managed_buffer().clone()
+        data.append(token);
+        let digest = self.crypto().sha256(&data);
+
+        BigUint::from_bytes_be_buffer(digest.as_managed_buffer()) % modulus
+    }
+
+    /// Vérifie une signature RSA `s^e mod n == m`
+    fn verify_rsa_signature(
+        &self,
+        message: &BigUint,
+        signature: &ManagedBuffer,
+        modulus: &BigUint,
+        public_exponent: u64,
+    ) -> bool {
+        if signature.is_empty() {
+            return false;
+        }
+
+        let signature = BigUint::from_bytes_be_buffer(signature);
+        if &signature >= modulus {
+            return false;
+        }
+
+        // Exponentiation modulaire (square-and-multiply)
+        let mut result = BigUint::from(1u64);
+        let mut base = signature;
+        let mut exponent = public_exponent;
+        while exponent > 0 {
+            if exponent & 1 == 1 {
+                result = (&result * &base) % modulus;
+            }
+            base = (&base * &base) % modulus;
+            exponent >>= 1;
+        }
+
+        &result == message
     }
 }
@@ -1,346 +1,452 @@
-use multiversx_sc_scenario::*;
+use multiversx_sc_scenario::imports::*;
+use multiversx_sc_scenario::multiversx_chain_vm::crypto_functions::sha256;
+use voter_registry::{TokenStatus, VoterRegistry};
+
+const CODE_PATH: MxscPath = MxscPath::new("output/voter-registry.mxsc.json");
+
+const OWNER_ADDRESS: TestAddress = TestAddress::new("owner");
+const VOTER1_ADDRESS: TestAddress = TestAddress::new("voter1");
+const VOTER2_ADDRESS: TestAddress = TestAddress::new("voter2");
+const VOTING_ADDRESS: TestAddress = TestAddress::new("voting");
+const ELIGIBILITY_VERIFIER_ADDRESS: TestAddress = TestAddress::new("eligibility-verifier");
+const REGISTRY_ADDRESS: TestSCAddress = TestSCAddress::new("voter-registry");
 
 fn world() -> ScenarioWorld {
     let mut blockchain = ScenarioWorld::new();
-    blockchain.register_contract(
-        "mxsc:output/voter-registry.mxsc.json",
-        voter_registry::ContractBuilder,
-    );
+    blockchain.register_contract(CODE_PATH, voter_registry::ContractBuilder);
     blockchain
 }
 
+/// Crée les comptes puis déploie le registre
+fn setup(world: &mut ScenarioWorld) {
+    world.account(OWNER_ADDRESS).nonce(1);
+    world.account(VOTER1_ADDRESS).nonce(1);
+    world.account(VOTER2_ADDRESS).nonce(1);
+    world.account(VOTING_ADDRESS).nonce(1);
+
+    world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .raw_deploy()
+        .code(CODE_PATH)
+        .new_address(REGISTRY_ADDRESS)
+        .whitebox(voter_registry::contract_obj, |sc| {
+            sc.init();
+        });
+}
+
+// Toy RSA key (n = 61 * 53) used for the blind signature flow
+const RSA_MODULUS: u64 = 3233;
+const RSA_PUBLIC_EXPONENT: u64 = 17;
+const RSA_PRIVATE_EXPONENT: u64 = 2753;
+
+fn mod_pow(mut base: u64, mut exponent: u64, modulus: u64) -> u64 {
+    let mut result = 1u64;
+    base %= modulus;
+    while exponent > 0 {
+        if exponent & 1 == 1 {
+            result = result * base % modulus;
+        }
+        base = base * base % modulus;
+        exponent >>= 1;
+    }
+    result
+}
+
+/// Unblinded authority signature on `H(election_id || token) mod n`
+fn token_signature(election_id: u64, token: &[u8]) -> ManagedBuffer<DebugApi> {
+    let mut data = election_id.to_be_bytes().to_vec();
+    data.extend_from_slice(token);
+    let message = sha256(&data)
+        .iter()
+        .fold(0u64, |acc, byte| (acc * 256 + *byte as u64) % RSA_MODULUS);
+
+    managed_buffer!(&mod_pow(message, RSA_PRIVATE_EXPONENT, RSA_MODULUS).to_be_bytes()[..])
+}
+
+fn setup_blind_signature_authority(world: &mut ScenarioWorld) {
+    world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(REGISTRY_ADDRESS)
+        .whitebox(voter_registry::contract_obj, |sc| {
+            sc.set_blind_signature_authority(
+                OWNER_ADDRESS.to_managed_address(),
+                BigUint::from(RSA_MODULUS),
+                RSA_PUBLIC_EXPONENT,
+            );
+        });
+}
+
+fn setup_eligibility_verifier(world: &mut ScenarioWorld) {
+    world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(REGISTRY_ADDRESS)
+        .whitebox(voter_registry::contract_obj, |sc| {
+            sc.set_eligibility_verifier(ELIGIBILITY_VERIFIER_ADDRESS.to_managed_address());
+        });
+}
+
+fn credential_hash(credential_proof: &[u8]) -> [u8; 32] {
+    sha256(credential_proof)
+}
+
+fn register_voter(
+    world: &mut ScenarioWorld,
+    voter: TestAddress,
+    credential_proof: &'static [u8],
+    blinded_token: &'static [u8],
+) {
+    world
+        .tx()
+        .from(voter)
+        .to(REGISTRY_ADDRESS)
+        .whitebox(voter_registry::contract_obj, |sc| {
+            sc.register_voter(1u64, managed_buffer!(credential_proof), managed_buffer!(blinded_token));
+        });
+}
+
+fn sign_blinded_token(world: &mut ScenarioWorld, from: TestAddress, credential_proof: &'static [u8]) {
+    world
+        .tx()
+        .from(from)
+        .to(REGISTRY_ADDRESS)
+        .whitebox(voter_registry::contract_obj, |sc| {
+            sc.sign_blinded_token(
+                1u64,
+                managed_buffer!(&credential_hash(credential_proof)[..]),
+                managed_buffer!(&[0x01, 0x02][..]),
+            );
+        });
+}
+
+fn check_token_status(world: &mut ScenarioWorld, token: &'static [u8], expected: TokenStatus) {
+    world
+        .query()
+        .to(REGISTRY_ADDRESS)
+        .whitebox(voter_registry::contract_obj, |sc| {
+            assert_eq!(sc.get_token_status(1u64, managed_buffer!(token), token_signature(1u64, token)), expected);
+        });
+}
+
+fn check_blinded_token_status(world: &mut ScenarioWorld, blinded_token: &'static [u8], expected: TokenStatus) {
+    world
+        .query()
+        .to(REGISTRY_ADDRESS)
+        .whitebox(voter_registry::contract_obj, |sc| {
+            assert_eq!(sc.get_blinded_token_status(1u64, managed_buffer!(blinded_token)), expected);
+        });
+}
+
+fn check_token_valid(
+    world: &mut ScenarioWorld,
+    token: &'static [u8],
+    signed_token: &'static [u8],
+    expected: bool,
+) {
+    world
+        .query()
+        .to(REGISTRY_ADDRESS)
+        .whitebox(voter_registry::contract_obj, |sc| {
+            assert_eq!(
+                sc.is_token_valid(1u64, managed_buffer!(token), token_signature(1u64, signed_token)),
+                expected
+            );
+        });
+}
+
 #[test]
 fn test_init() {
     let mut world = world();
-
-    world
-        .start_trace()
-        .set_state_step(
-            SetStateStep::new()
-                .put_account("address:owner", Account::new().nonce(1))
-                .new_address("address:owner", 1, "sc:voter-registry"),
-        )
-        .sc_deploy(
-            ScDeployStep::new()
-                .from("address:owner")
-                .contract_code("mxsc:output/voter-registry.mxsc.json", &[])
-                .call(voter_registry::contract_obj::<DebugApi>().init())
-                .expect(TxExpect::ok().no_result()),
-        );
+    setup(&mut world);
 }
 
 #[test]
 fn test_register_voter() {
     let mut world = world();
-    let owner_address = "address:owner";
-    let voter_address = "address:voter1";
-    let registry_sc_address = "sc:voter-registry";
-
-    // Setup
-    world
-        .start_trace()
-        .set_state_step(
-            SetStateStep::new()
-                .put_account(owner_address, Account::new().nonce(1))
-                .put_account(voter_address, Account::new().nonce(1))
-                .new_address(owner_address, 1, registry_sc_address),
-        )
-        .sc_deploy(
-            ScDeployStep::new()
-                .from(owner_address)
-                .contract_code("mxsc:output/voter-registry.mxsc.json", &[])
-                .call(voter_registry::contract_obj::<DebugApi>().init())
-                .expect(TxExpect::ok().no_result()),
-        );
+    setup(&mut world);
+    setup_eligibility_verifier(&mut world);
 
     // Register voter
-    let election_id = 1u64;
-    let credential_proof = managed_buffer!(b"zk_snark_proof_data_voter1");
-
-    world.sc_call_step(
-        ScCallStep::new()
-            .from(voter_address)
-            .to(registry_sc_address)
-            .call(
-                voter_registry::contract_obj::<DebugApi>()
-                    .register_voter(election_id, credential_proof),
-            )
-            .expect(TxExpect::ok()), // Should return a voting token
-    );
+    register_voter(&mut world, VOTER1_ADDRESS, b"proof_voter1_credential_32_bytes", b"blinded_token_voter1");
+
+    world
+        .query()
+        .to(REGISTRY_ADDRESS)
+        .whitebox(voter_registry::contract_obj, |sc| {
+            assert_eq!(sc.registered_voters_count(1u64).get(), 1u64);
+        });
 }
 
 #[test]
-fn test_register_multiple_voters() {
+fn test_register_voter_requires_verifier() {
     let mut world = world();
-    let owner_address = "address:owner";
-    let voter1_address = "address:voter1";
-    let voter2_address = "address:voter2";
-    let registry_sc_address = "sc:voter-registry";
+    setup(&mut world);
 
-    // Setup
+    // Without a configured verifier, no proof can be accepted
     world
-        .start_trace()
-        .set_state_step(
-            SetStateStep::new()
-                .put_account(owner_address, Account::new().nonce(1))
-                .put_account(voter1_address, Account::new().nonce(1))
-                .put_account(voter2_address, Account::new().nonce(1))
-                .new_address(owner_address, 1, registry_sc_address),
-        )
-        .sc_deploy(
-            ScDeployStep::new()
-                .from(owner_address)
-                .contract_code("mxsc:output/voter-registry.mxsc.json", &[])
-                .call(voter_registry::contract_obj::<DebugApi>().init())
-                .expect(TxExpect::ok().no_result()),
-        );
-
-    let election_id = 1u64;
+        .tx()
+        .from(VOTER1_ADDRESS)
+        .to(REGISTRY_ADDRESS)
+        .returns(ExpectError(4, "Vérificateur d'éligibilité non configuré"))
+        .whitebox(voter_registry::contract_obj, |sc| {
+            sc.register_voter(
+                1u64,
+                managed_buffer!(b"proof_voter1_credential_32_bytes"),
+                managed_buffer!(b"blinded_token_voter1"),
+            );
+        });
+}
+
+#[test]
+fn test_register_multiple_voters() {
+    let mut world = world();
+    setup(&mut world);
+    setup_eligibility_verifier(&mut world);
 
     // Register first voter
-    world.sc_call_step(
-        ScCallStep::new()
-            .from(voter1_address)
-            .to(registry_sc_address)
-            .call(
-                voter_registry::contract_obj::<DebugApi>().register_voter(
-                    election_id,
-                    managed_buffer!(b"proof_voter1"),
-                ),
-            )
-            .expect(TxExpect::ok()),
-    );
+    register_voter(&mut world, VOTER1_ADDRESS, b"proof_voter1_credential_32_bytes", b"blinded_token_voter1");
 
     // Register second voter
-    world.sc_call_step(
-        ScCallStep::new()
-            .from(voter2_address)
-            .to(registry_sc_address)
-            .call(
-                voter_registry::contract_obj::<DebugApi>().register_voter(
-                    election_id,
-                    managed_buffer!(b"proof_voter2"),
-                ),
-            )
-            .expect(TxExpect::ok()),
-    );
+    register_voter(&mut world, VOTER2_ADDRESS, b"proof_voter2_credential_32_bytes", b"blinded_token_voter2");
+
+    // The same credential cannot register twice
+    world
+        .tx()
+        .from(VOTER2_ADDRESS)
+        .to(REGISTRY_ADDRESS)
+        .returns(ExpectError(4, "Électeur déjà enregistré"))
+        .whitebox(voter_registry::contract_obj, |sc| {
+            sc.register_voter(
+                1u64,
+                managed_buffer!(b"proof_voter2_credential_32_bytes"),
+                managed_buffer!(b"blinded_token_voter2"),
+            );
+        });
 }
 
 #[test]
 fn test_is_token_valid() {
     let mut world = world();
-    let owner_address = "address:owner";
-    let voter_address = "address:voter1";
-    let registry_sc_address = "sc:voter-registry";
+    setup(&mut world);
+    setup_eligibility_verifier(&mut world);
+
+    // Register voter with a blinded token
+    register_voter(&mut world, VOTER1_ADDRESS, b"proof_voter1_credential_32_bytes", b"blinded_token_voter1");
+
+    // Without an authority key, no token can be valid
+    check_token_valid(&mut world, b"unblinded_token", b"unblinded_token", false);
 
-    // Setup
+    setup_blind_signature_authority(&mut world);
+
+    // The blinded token is not issued until the authority publishes its signature
+    check_blinded_token_status(&mut world, b"blinded_token_voter1", TokenStatus::Unknown);
+
+    // The authority signs the blinded token, which is recorded as issued
+    sign_blinded_token(&mut world, OWNER_ADDRESS, b"proof_voter1_credential_32_bytes");
+
+    check_token_valid(&mut world, b"unblinded_token", b"unblinded_token", true);
+    check_blinded_token_status(&mut world, b"blinded_token_voter1", TokenStatus::Issued);
+
+    // Only one blind signature per registered voter
     world
-        .start_trace()
-        .set_state_step(
-            SetStateStep::new()
-                .put_account(owner_address, Account::new().nonce(1))
-                .put_account(voter_address, Account::new().nonce(1))
-                .new_address(owner_address, 1, registry_sc_address),
-        )
-        .sc_deploy(
-            ScDeployStep::new()
-                .from(owner_address)
-                .contract_code("mxsc:output/voter-registry.mxsc.json", &[])
-                .call(voter_registry::contract_obj::<DebugApi>().init())
-                .expect(TxExpect::ok().no_result()),
-        );
-
-    let election_id = 1u64;
-
-    // Register voter and get token
-    let token_result = world.sc_call_get_result(
-        ScCallStep::new()
-            .from(voter_address)
-            .to(registry_sc_address)
-            .call(
-                voter_registry::contract_obj::<DebugApi>().register_voter(
-                    election_id,
-                    managed_buffer!(b"proof_voter1"),
-                ),
-            )
-            .expect(TxExpect::ok()),
-    );
-
-    // Check if token is valid (should return true for non-empty token)
-    world.sc_query_step(
-        ScQueryStep::new()
-            .to(registry_sc_address)
-            .call(
-                voter_registry::contract_obj::<DebugApi>().is_token_valid(
-                    election_id,
-                    managed_buffer!(b"some_token"),
-                ),
-            )
-            .expect(TxExpect::ok().result("true")),
-    );
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(REGISTRY_ADDRESS)
+        .returns(ExpectError(4, "Token déjà signé"))
+        .whitebox(voter_registry::contract_obj, |sc| {
+            sc.sign_blinded_token(
+                1u64,
+                managed_buffer!(&credential_hash(b"proof_voter1_credential_32_bytes")[..]),
+                managed_buffer!(&[0x01, 0x02][..]),
+            );
+        });
+
+    // A token not signed by the authority is rejected
+    check_token_valid(&mut world, b"forged_token", b"unblinded_token", false);
 
     // Check invalid token (empty)
-    world.sc_query_step(
-        ScQueryStep::new()
-            .to(registry_sc_address)
-            .call(
-                voter_registry::contract_obj::<DebugApi>().is_token_valid(
-                    election_id,
-                    managed_buffer!(b""),
-                ),
-            )
-            .expect(TxExpect::ok().result("false")),
-    );
+    check_token_valid(&mut world, b"", b"", false);
 }
 
 #[test]
 fn test_revoke_token() {
     let mut world = world();
-    let owner_address = "address:owner";
-    let voter_address = "address:voter1";
-    let registry_sc_address = "sc:voter-registry";
-
-    // Setup
-    world
-        .start_trace()
-        .set_state_step(
-            SetStateStep::new()
-                .put_account(owner_address, Account::new().nonce(1))
-                .put_account(voter_address, Account::new().nonce(1))
-                .new_address(owner_address, 1, registry_sc_address),
-        )
-        .sc_deploy(
-            ScDeployStep::new()
-                .from(owner_address)
-                .contract_code("mxsc:output/voter-registry.mxsc.json", &[])
-                .call(voter_registry::contract_obj::<DebugApi>().init())
-                .expect(TxExpect::ok().no_result()),
-        );
-
-    let election_id = 1u64;
+    setup(&mut world);
+    setup_eligibility_verifier(&mut world);
 
     // Register voter
-    world.sc_call_step(
-        ScCallStep::new()
-            .from(voter_address)
-            .to(registry_sc_address)
-            .call(
-                voter_registry::contract_obj::<DebugApi>().register_voter(
-                    election_id,
-                    managed_buffer!(b"proof_voter1"),
-                ),
-            )
-            .expect(TxExpect::ok()),
-    );
+    register_voter(&mut world, VOTER1_ADDRESS, b"proof_voter1_credential_32_bytes", b"blinded_token_voter1");
+
+    // Register the account acting as voting contract
+    world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(REGISTRY_ADDRESS)
+        .whitebox(voter_registry::contract_obj, |sc| {
+            sc.add_voting_contract(VOTING_ADDRESS.to_managed_address());
+        });
+
+    // Revoking a token before any blind signature was issued fails
+    world
+        .tx()
+        .from(VOTING_ADDRESS)
+        .to(REGISTRY_ADDRESS)
+        .returns(ExpectError(4, "Token inconnu"))
+        .whitebox(voter_registry::contract_obj, |sc| {
+            sc.revoke_token(1u64, managed_buffer!(b"test_token"), token_signature(1u64, b"test_token"));
+        });
+
+    setup_blind_signature_authority(&mut world);
+    sign_blinded_token(&mut world, OWNER_ADDRESS, b"proof_voter1_credential_32_bytes");
+
+    // A forged signature cannot consume a token
+    world
+        .tx()
+        .from(VOTING_ADDRESS)
+        .to(REGISTRY_ADDRESS)
+        .returns(ExpectError(4, "Token inconnu"))
+        .whitebox(voter_registry::contract_obj, |sc| {
+            sc.revoke_token(1u64, managed_buffer!(b"test_token"), managed_buffer!(&[0x01, 0x02][..]));
+        });
 
     // Revoke token (simulating voting contract calling this)
-    let token = managed_buffer!(b"test_token");
-    world.sc_call_step(
-        ScCallStep::new()
-            .from(owner_address) // In real scenario, this would be the voting contract
-            .to(registry_sc_address)
-            .call(
-                voter_registry::contract_obj::<DebugApi>().revoke_token(election_id, token),
-            )
-            .expect(TxExpect::ok().no_result()),
-    );
+    world
+        .tx()
+        .from(VOTING_ADDRESS)
+        .to(REGISTRY_ADDRESS)
+        .whitebox(voter_registry::contract_obj, |sc| {
+            sc.revoke_token(1u64, managed_buffer!(b"test_token"), token_signature(1u64, b"test_token"));
+        });
+
+    // A token can only be revoked once
+    world
+        .tx()
+        .from(VOTING_ADDRESS)
+        .to(REGISTRY_ADDRESS)
+        .returns(ExpectError(4, "Token déjà utilisé"))
+        .whitebox(voter_registry::contract_obj, |sc| {
+            sc.revoke_token(1u64, managed_buffer!(b"test_token"), token_signature(1u64, b"test_token"));
+        });
+
+    check_token_status(&mut world, b"test_token", TokenStatus::Used);
+
+    // A single blind signature was published, so no other token can be consumed
+    world
+        .tx()
+        .from(VOTING_ADDRESS)
+        .to(REGISTRY_ADDRESS)
+        .returns(ExpectError(4, "Tous les tokens émis ont déjà été utilisés"))
+        .whitebox(voter_registry::contract_obj, |sc| {
+            sc.revoke_token(1u64, managed_buffer!(b"other_token"), token_signature(1u64, b"other_token"));
+        });
+
+    check_token_status(&mut world, b"other_token", TokenStatus::Issued);
+}
+
+#[test]
+fn test_revoke_token_unauthorized() {
+    let mut world = world();
+    setup(&mut world);
+
+    // Only registered voting contracts may revoke tokens
+    world
+        .tx()
+        .from(VOTER1_ADDRESS)
+        .to(REGISTRY_ADDRESS)
+        .returns(ExpectError(4, "Seul un contrat de vote enregistré peut révoquer un token"))
+        .whitebox(voter_registry::contract_obj, |sc| {
+            sc.revoke_token(1u64, managed_buffer!(b"test_token"), token_signature(1u64, b"test_token"));
+        });
 }
 
 #[test]
 fn test_revoke_token_invalid() {
     let mut world = world();
-    let owner_address = "address:owner";
-    let registry_sc_address = "sc:voter-registry";
+    setup(&mut world);
 
-    // Setup
     world
-        .start_trace()
-        .set_state_step(
-            SetStateStep::new()
-                .put_account(owner_address, Account::new().nonce(1))
-                .new_address(owner_address, 1, registry_sc_address),
-        )
-        .sc_deploy(
-            ScDeployStep::new()
-                .from(owner_address)
-                .contract_code("mxsc:output/voter-registry.mxsc.json", &[])
-                .call(voter_registry::contract_obj::<DebugApi>().init())
-                .expect(TxExpect::ok().no_result()),
-        );
-
-    let election_id = 1u64;
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(REGISTRY_ADDRESS)
+        .whitebox(voter_registry::contract_obj, |sc| {
+            sc.add_voting_contract(OWNER_ADDRESS.to_managed_address());
+        });
 
     // Try to revoke empty token (should fail)
-    world.sc_call_step(
-        ScCallStep::new()
-            .from(owner_address)
-            .to(registry_sc_address)
-            .call(
-                voter_registry::contract_obj::<DebugApi>().revoke_token(
-                    election_id,
-                    managed_buffer!(b""),
-                ),
-            )
-            .expect(TxExpect::user_error("str:Token invalide")),
-    );
+    world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(REGISTRY_ADDRESS)
+        .returns(ExpectError(4, "Token invalide"))
+        .whitebox(voter_registry::contract_obj, |sc| {
+            sc.revoke_token(1u64, managed_buffer!(b""), managed_buffer!(b""));
+        });
 }
 
 #[test]
 fn test_voter_tokens_are_unique() {
     let mut world = world();
-    let owner_address = "address:owner";
-    let voter1_address = "address:voter1";
-    let voter2_address = "address:voter2";
-    let registry_sc_address = "sc:voter-registry";
+    setup(&mut world);
+    setup_eligibility_verifier(&mut world);
 
-    // Setup
+    // Register two voters, each submitting its own blinded token
+    register_voter(&mut world, VOTER1_ADDRESS, b"proof_voter1_credential_32_bytes", b"blinded_token_voter1");
+    register_voter(&mut world, VOTER2_ADDRESS, b"proof_voter2_credential_32_bytes", b"blinded_token_voter2");
+
+    setup_blind_signature_authority(&mut world);
+
+    // Only the authority can sign blinded tokens
     world
-        .start_trace()
-        .set_state_step(
-            SetStateStep::new()
-                .put_account(owner_address, Account::new().nonce(1))
-                .put_account(voter1_address, Account::new().nonce(1))
-                .put_account(voter2_address, Account::new().nonce(1))
-                .new_address(owner_address, 1, registry_sc_address),
-        )
-        .sc_deploy(
-            ScDeployStep::new()
-                .from(owner_address)
-                .contract_code("mxsc:output/voter-registry.mxsc.json", &[])
-                .call(voter_registry::contract_obj::<DebugApi>().init())
-                .expect(TxExpect::ok().no_result()),
-        );
-
-    let election_id = 1u64;
-
-    // Register two voters - they should get different tokens
-    // This is implicitly tested by the generate_voting_token function
-    // which uses caller address and timestamp to create unique tokens
-    world.sc_call_step(
-        ScCallStep::new()
-            .from(voter1_address)
-            .to(registry_sc_address)
-            .call(
-                voter_registry::contract_obj::<DebugApi>().register_voter(
-                    election_id,
-                    managed_buffer!(b"proof_voter1"),
-                ),
-            )
-            .expect(TxExpect::ok()),
-    );
-
-    world.sc_call_step(
-        ScCallStep::new()
-            .from(voter2_address)
-            .to(registry_sc_address)
-            .call(
-                voter_registry::contract_obj::<DebugApi>().register_voter(
-                    election_id,
-                    managed_buffer!(b"proof_voter2"),
-                ),
-            )
-            .expect(TxExpect::ok()),
-    );
-
-    // Both registrations should succeed, proving tokens are unique
+        .tx()
+        .from(VOTER1_ADDRESS)
+        .to(REGISTRY_ADDRESS)
+        .returns(ExpectError(4, "Seule l'autorité de signature peut signer les tokens"))
+        .whitebox(voter_registry::contract_obj, |sc| {
+            sc.sign_blinded_token(
+                1u64,
+                managed_buffer!(&credential_hash(b"proof_voter1_credential_32_bytes")[..]),
+                managed_buffer!(&[0x01, 0x02][..]),
+            );
+        });
+
+    sign_blinded_token(&mut world, OWNER_ADDRESS, b"proof_voter1_credential_32_bytes");
+    sign_blinded_token(&mut world, OWNER_ADDRESS, b"proof_voter2_credential_32_bytes");
+
+    world
+        .query()
+        .to(REGISTRY_ADDRESS)
+        .whitebox(voter_registry::contract_obj, |sc| {
+            assert_eq!(sc.issued_tokens_count(1u64).get(), 2u64);
+        });
+}
+
+#[test]
+fn test_token_expiration() {
+    let mut world = world();
+    world.current_block().block_timestamp(1_000);
+    setup(&mut world);
+    setup_eligibility_verifier(&mut world);
+
+    world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(REGISTRY_ADDRESS)
+        .whitebox(voter_registry::contract_obj, |sc| {
+            sc.set_token_expiration(1u64, 2_000u64);
+        });
+
+    register_voter(&mut world, VOTER1_ADDRESS, b"proof_voter1_credential_32_bytes", b"blinded_token_voter1");
+
+    setup_blind_signature_authority(&mut world);
+    sign_blinded_token(&mut world, OWNER_ADDRESS, b"proof_voter1_credential_32_bytes");
+
+    check_token_status(&mut world, b"unblinded_token", TokenStatus::Issued);
+
+    world.current_block().block_timestamp(2_000);
+
+    check_token_status(&mut world, b"unblinded_token", TokenStatus::Expired);
+    check_blinded_token_status(&mut world, b"blinded_token_voter1", TokenStatus::Expired);
+    check_token_valid(&mut world, b"unblinded_token", b"unblinded_token", false);
 }
@@ -1,146 +0,0 @@
-multiversx_sc::imports!();
-
-/// Module de vérification cryptographique (MOCK pour le développement)
-///
-/// AVERTISSEMENT: Cette implémentation est un MOCK pour le POC.
-/// Pour la production, elle DOIT être remplacée par une vraie implémentation zk-SNARK
-/// utilisant Groth16 ou Plonk.
-pub mod crypto_verification {
-    use multiversx_sc::api::ManagedTypeApi;
-    use multiversx_sc::types::ManagedBuffer;
-
-    /// Vérifie une preuve zk-SNARK (VERSION MOCK)
-    ///
-    /// Cette fonction simule la vérification d'une preuve zk-SNARK.
-    /// Dans un environnement de production, elle devrait:
-    /// 1. Vérifier la preuve Groth16/Plonk
-    /// 2. Valider le circuit de preuve
-    /// 3. S'assurer que la preuve correspond aux paramètres publics
-    ///
-    /// # Arguments
-    /// * `proof` - La preuve zk-SNARK à vérifier
-    /// * `public_inputs` - Les entrées publiques du circuit
-    ///
-    /// # Returns
-    /// `true` si la preuve est valide (dans le mock, toujours true si non-vide)
-    pub fn verify_zk_snark_proof<M: ManagedTypeApi>(
-        proof: &ManagedBuffer<M>,
-        _public_inputs: &ManagedBuffer<M>,
-    ) -> bool {
-        // MOCK: Accepte toute preuve non-vide
-        // TODO: Implémenter vraie vérification zk-SNARK avec:
-        // - Vérification Groth16 via multiversx-sc-crypto
-        // - Circuit de preuve pour vérifier:
-        //   * L'électeur est éligible
-        //   * L'électeur n'a pas déjà voté
-        //   * Le vote est valide (candidat existe)
-        //   * Sans révéler l'identité de l'électeur
-
-        !proof.is_empty()
-    }
-
-    /// Vérifie une preuve d'éligibilité d'électeur (VERSION MOCK)
-    ///
-    /// Dans la version production, cette fonction devrait vérifier:
-    /// 1. L'électeur est dans le registre électoral
-    /// 2. L'électeur n'a pas déjà un token actif
-    /// 3. Les credentials sont valides
-    ///
-    /// # Arguments
-    /// * `credential_proof` - Preuve d'identité de l'électeur
-    ///
-    /// # Returns
-    /// `true` si l'électeur est éligible
-    pub fn verify_voter_eligibility<M: ManagedTypeApi>(
-        credential_proof: &ManagedBuffer<M>,
-    ) -> bool {
-        // MOCK: Accepte toute preuve d'au moins 32 bytes
-        // TODO: Implémenter vraie vérification avec:
-        // - Vérification de signature
-        // - Vérification contre base de données éligibilité
-        // - Preuve zk que l'électeur est dans le Merkle tree des électeurs éligibles
-
-        credential_proof.len() >= 32
-    }
-
-    /// Vérifie qu'un vote chiffré est bien formé (VERSION MOCK)
-    ///
-    /// Dans la version production:
-    /// 1. Vérifier que le chiffrement est correct (ElGamal/Paillier)
-    /// 2. Vérifier la preuve de connaissance du plaintext
-    /// 3. Vérifier que le choix est dans la plage valide
-    ///
-    /// # Arguments
-    /// * `encrypted_vote` - Vote chiffré
-    /// * `proof` - Preuve zk que le vote est valide
-    ///
-    /// # Returns
-    /// `true` si le vote chiffré est valide
-    pub fn verify_encrypted_vote<M: ManagedTypeApi>(
-        encrypted_vote: &ManagedBuffer<M>,
-        proof: &ManagedBuffer<M>,
-    ) -> bool {
-        // MOCK: Vérifie juste que les données ne sont pas vides
-        // TODO: Implémenter vérification:
-        // - Preuve de connaissance du vote en clair
-        // - Preuve que le vote est pour un candidat valide
-        // - Vérification du chiffrement homomorphique
-
-        !encrypted_vote.is_empty() && !proof.is_empty()
-    }
-}
-
-#[cfg(test)]
-mod tests {
-    use super::crypto_verification::*;
-    use multiversx_sc::types::ManagedBuffer;
-    use multiversx_sc_scenario::api::StaticApi;
-
-    #[test]
-    fn test_verify_zk_snark_proof_valid() {
-        let proof = ManagedBuffer::<StaticApi>::from(b"valid_proof_data");
-        let public_inputs = ManagedBuffer::<StaticApi>::from(b"public_inputs");
-
-        assert!(verify_zk_snark_proof(&proof, &public_inputs));
-    }
-
-    #[test]
-    fn test_verify_zk_snark_proof_empty() {
-        let proof = ManagedBuffer::<StaticApi>::new();
-        let public_inputs = ManagedBuffer::<StaticApi>::from(b"public_inputs");
-
-        assert!(!verify_zk_snark_proof(&proof, &public_inputs));
-    }
-
-    #[test]
-    fn test_verify_voter_eligibility_valid() {
-        // 32+ bytes credential
-        let credential = ManagedBuffer::<StaticApi>::from(b"12345678901234567890123456789012");
-
-        assert!(verify_voter_eligibility(&credential));
-    }
-
-    #[test]
-    fn test_verify_voter_eligibility_invalid() {
-        // Less than 32 bytes
-        let credential = ManagedBuffer::<StaticApi>::from(b"short");
-
-        assert!(!verify_voter_eligibility(&credential));
-    }
-
-    #[test]
-    fn test_verify_encrypted_vote_valid() {
-        let encrypted = ManagedBuffer::<StaticApi>::from(b"encrypted_data");
-        let proof = ManagedBuffer::<StaticApi>::from(b"proof_data");
-
-        assert!(verify_encrypted_vote(&encrypted, &proof));
-    }
-
-    #[test]
-    fn test_verify_encrypted_vote_invalid() {
-        let encrypted = ManagedBuffer::<StaticApi>::new();
-        let proof = ManagedBuffer::<StaticApi>::from(b"proof_data");
-
-        assert!(!verify_encrypted_vote(&encrypted, &proof));
-    }
-}
@@ -5,7 +5,34 @@ use alloc::string::ToString;
 
 use multiversx_sc::{derive_imports::*, imports::*};
 
-mod crypto_mock;
+use crypto_verifier::{DefaultVerifier, ProofVerifier, ED25519_SIGNATURE_LEN};
+pub use democratix_common::{Candidate, CandidateResult};
+
+/// Profondeur maximale d'une preuve de Merkle (2^32 adresses)
+const MAX_MERKLE_PROOF_DEPTH: usize = 32;
+
+/// Gas conservé en fin de lot de dépouillement pour l'écriture du curseur
+/// et, le cas échéant, des résultats finaux
+const TALLY_GAS_RESERVE: u64 = 5_000_000;
+
+/// Taille maximale d'une page pour les views paginées
+const MAX_PAGE_SIZE: u64 = 500;
+
+/// Délai de contestation par défaut après la finalisation (7 jours)
+const DEFAULT_DISPUTE_PERIOD: u64 = 604_800;
+
+/// Calcule les bornes d'une page à partir d'un curseur
+///
+/// Le curseur est la position (0-based) du premier élément à retourner.
+/// Retourne (début, fin exclusive, curseur suivant); le curseur suivant vaut 0
+/// lorsqu'il n'y a plus d'éléments.
+fn page_bounds(cursor: u64, limit: u64, total: usize) -> (usize, usize, u64) {
+    let total = total as u64;
+    let start = cursor.min(total);
+    let end = (start + limit.min(MAX_PAGE_SIZE)).min(total);
+    let next_cursor = if end < total { end } else { 0 };
+    (start as usize, end as usize, next_cursor)
+}
 
 /// Statut d'une élection
 #[type_abi]
@@ -15,23 +42,7 @@ pub enum ElectionStatus {
     Active,     // En cours
     Closed,     // Terminée, en attente de dépouillement
     Finalized,  // Résultats publiés
-}
-
-/// Structure représentant un candidat
-#[type_abi]
-#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, Debug)]
-pub struct Candidate<M: ManagedTypeApi> {
-    pub id: u32,
-    pub name: ManagedBuffer<M>,
-    pub description_ipfs: ManagedBuffer<M>,
-}
-
-/// Structure pour les résultats finaux d'un candidat
-#[type_abi]
-#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, Debug)]
-pub struct CandidateResult {
-    pub candidate_id: u32,
-    pub vote_count: u64,
+    Annulled,   // Annulée suite à une contestation
 }
 
 /// Structure représentant une élection
@@ -54,12 +65,106 @@ pub struct Election<M: ManagedTypeApi> {
 }
 
 /// Structure pour un code d'invitation
+///
+/// Seul le hash keccak256 du secret est stocké on-chain: le secret lui-même
+/// n'est révélé qu'au moment de l'inscription.
+///
+/// Les trois premiers champs reprennent la disposition de `LegacyInvitationCode`,
+/// les suivants sont ajoutés à la fin.
 #[type_abi]
 #[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Debug)]
 pub struct InvitationCode<M: ManagedTypeApi> {
     pub code_hash: ManagedBuffer<M>,
     pub is_used: bool,
     pub used_by: Option<ManagedAddress<M>>,
+    pub code_index: u64,  // Index attribué par le compteur global du contrat (0 = code antérieur aux lots)
+    pub batch_id: u64,  // 0 = code antérieur aux lots
+    pub expires_at: Option<u64>,  // Timestamp d'expiration (None = pas d'expiration)
+    pub is_revoked: bool,
+}
+
+/// Ancienne disposition d'un code d'invitation (clé de storage `invitationCodes`)
+///
+/// Ces codes sont indexés par la valeur transmise à l'inscription, sans secret,
+/// et n'appartiennent à aucun lot. Ils restent lisibles et utilisables.
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Debug)]
+pub struct LegacyInvitationCode<M: ManagedTypeApi> {
+    pub code_hash: ManagedBuffer<M>,
+    pub is_used: bool,
+    pub used_by: Option<ManagedAddress<M>>,
+}
+
+impl<M: ManagedTypeApi> From<LegacyInvitationCode<M>> for InvitationCode<M> {
+    fn from(legacy: LegacyInvitationCode<M>) -> Self {
+        InvitationCode {
+            code_hash: legacy.code_hash,
+            is_used: legacy.is_used,
+            used_by: legacy.used_by,
+            code_index: 0,
+            batch_id: 0,
+            expires_at: None,
+            is_revoked: false,
+        }
+    }
+}
+
+/// Lot de codes d'invitation généré en une transaction
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Debug)]
+pub struct InvitationBatch {
+    pub id: u64,
+    pub election_id: u64,
+    pub first_code_index: u64,
+    pub total_codes: u32,
+    pub used_codes: u32,
+    pub revoked_codes: u32,
+    pub unused_codes: u32,  // Codes encore utilisables (ni utilisés, ni révoqués)
+    pub expires_at: Option<u64>,
+    pub created_at: u64,
+}
+
+/// Avancement du dépouillement on-chain d'une élection sans chiffrement
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Debug)]
+pub struct TallyProgress {
+    pub processed_ballots: u64,
+    pub total_ballots: u64,
+    pub invalid_ballots: u64,
+    pub is_complete: bool,
+}
+
+/// Statut d'une contestation d'élection
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, PartialEq, Eq, Clone, Copy, Debug)]
+pub enum DisputeStatus {
+    Open,           // En attente d'arbitrage
+    Confirmed,      // Résultat confirmé, caution conservée
+    RecountOrdered, // Recomptage ordonné, caution remboursée
+    Annulled,       // Élection annulée, caution remboursée
+}
+
+/// Décision d'arbitrage sur une contestation
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, PartialEq, Eq, Clone, Copy, Debug)]
+pub enum DisputeResolution {
+    ConfirmResult,
+    OrderRecount,
+    AnnulElection,
+}
+
+/// Contestation déposée après la finalisation d'une élection
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Debug)]
+pub struct Dispute<M: ManagedTypeApi> {
+    pub id: u64,
+    pub filer: ManagedAddress<M>,
+    pub evidence_hash: ManagedBuffer<M>,
+    pub bond: BigUint<M>,
+    pub filed_at: u64,
+    pub status: DisputeStatus,
+    pub resolved_by: Option<ManagedAddress<M>>,
+    pub resolved_at: u64,
 }
 
 /// Statistiques d'inscription pour une élection
@@ -144,11 +249,15 @@ pub struct ElGamalVoteWithProof<M: ManagedTypeApi> {
 #[multiversx_sc::contract]
 pub trait VotingContract {
     #[init]
-    fn init(&self) {}
+    fn init(&self) {
+        self.dispute_period().set_if_empty(DEFAULT_DISPUTE_PERIOD);
+    }
 
     /// Fonction appelée lors de l'upgrade du contrat
     #[upgrade]
-    fn upgrade(&self) {}
+    fn upgrade(&self) {
+        self.dispute_period().set_if_empty(DEFAULT_DISPUTE_PERIOD);
+    }
 
     /// Crée une nouvelle élection
     ///
@@ -159,6 +268,7 @@ pub trait VotingContract {
     /// * `end_time` - Timestamp de fin
     /// * `requires_registration` - Inscription obligatoire ou non
     /// * `encryption_type` - Type de chiffrement: 0=none, 1=elgamal, 2=elgamal+zksnark
+    /// * `allow_registration_during_voting` - Autorise les inscriptions pendant la phase Active
     /// * `registration_deadline` - Date limite d'inscription (optionnel)
     #[endpoint(createElection)]
     fn create_election(
@@ -169,6 +279,7 @@ pub trait VotingContract {
         end_time: u64,
         requires_registration: bool,
         encryption_type: u8,
+        allow_registration_during_voting: bool,
         registration_deadline: OptionalValue<u64>,
     ) -> u64 {
         require!(start_time < end_time, "Dates invalides");
@@ -188,9 +299,20 @@ pub trait VotingContract {
         if requires_registration && deadline.is_some() {
             let dl = deadline.unwrap();
             require!(
-                dl < start_time,
-                "La date limite d'inscription doit être avant le début du vote"
+                dl > self.blockchain().get_block_timestamp(),
+                "La date limite d'inscription doit être dans le futur"
             );
+            if allow_registration_during_voting {
+                require!(
+                    dl <= end_time,
+                    "La date limite d'inscription doit être avant la fin du vote"
+                );
+            } else {
+                require!(
+                    dl < start_time,
+                    "La date limite d'inscription doit être avant le début du vote"
+                );
+            }
         }
 
         let election_id = self.election_counter().get() + 1;
@@ -213,6 +335,8 @@ pub trait VotingContract {
         };
 
         self.elections(election_id).set(&election);
+        // Stocké hors de `Election` pour que les élections existantes restent décodables
+        self.registration_during_voting(election_id).set(allow_registration_during_voting);
 
         self.election_created_event(election_id, &election.organizer);
 
@@ -220,6 +344,9 @@ pub trait VotingContract {
     }
 
     /// Ajoute un candidat à une élection
+    ///
+    /// Les IDs sont séquentiels (`1..=num_candidates`) : le dépouillement on-chain et la
+    /// finalisation s'appuient sur cette numérotation.
     #[endpoint(addCandidate)]
     fn add_candidate(
         &self,
@@ -234,6 +361,10 @@ pub trait VotingContract {
             "Seul l'organisateur peut ajouter des candidats"
         );
         require!(election.status == ElectionStatus::Pending, "Élection déjà commencée");
+        require!(
+            candidate_id == election.num_candidates + 1,
+            "Les IDs de candidats doivent être séquentiels à partir de 1"
+        );
 
         let candidate = Candidate {
             id: candidate_id,
@@ -246,10 +377,10 @@ pub trait VotingContract {
         self.elections(election_id).set(&election);
     }
 
-    /// Inscription d'un électeur à une élection
-    ///
-    /// # Arguments
-    /// * `election_id` - ID de l'élection
+    // Inscription d'un électeur à une élection
+    //
+    // # Arguments
+    // * `election_id` - ID de l'élection
     // ❌ DÉSACTIVÉ: registerToVote permet à N'IMPORTE QUI de s'inscrire
     // Pour le flux KYC, on utilise UNIQUEMENT:
     // 1. addToWhitelist (organisateur inscrit les adresses après KYC)
@@ -318,10 +449,7 @@ pub trait VotingContract {
             "Seul l'organisateur peut modifier la liste blanche"
         );
 
-        require!(
-            election.status == ElectionStatus::Pending,
-            "Les inscriptions sont fermées"
-        );
+        self.require_registration_open(&election);
 
         let current_time = self.blockchain().get_block_timestamp();
 
@@ -341,6 +469,128 @@ pub trait VotingContract {
         self.last_registration_time(election_id).set(current_time);
     }
 
+    /// Engage la racine d'un arbre de Merkle des adresses éligibles
+    ///
+    /// Permet d'importer une liste électorale de grande taille en une seule
+    /// transaction: chaque électeur s'inscrit ensuite lui-même avec
+    /// `claimWhitelistRegistration` en fournissant sa preuve de Merkle.
+    ///
+    /// Feuilles: keccak256(adresse). Noeuds: keccak256(min(a, b) || max(a, b)).
+    ///
+    /// # Arguments
+    /// * `election_id` - ID de l'élection
+    /// * `merkle_root` - Racine de l'arbre (32 bytes)
+    /// * `eligible_count` - Nombre d'adresses incluses dans l'arbre
+    #[endpoint(commitWhitelistRoot)]
+    fn commit_whitelist_root(&self, election_id: u64, merkle_root: ManagedBuffer, eligible_count: u64) {
+        let election = self.elections(election_id).get();
+
+        require!(
+            self.blockchain().get_caller() == election.organizer,
+            "Seul l'organisateur peut modifier la liste blanche"
+        );
+        require!(
+            election.requires_registration,
+            "Cette élection ne requiert pas d'inscription"
+        );
+        self.require_registration_open(&election);
+
+        require!(merkle_root.len() == 32, "Racine de Merkle invalide (32 bytes attendus)");
+        require!(eligible_count > 0, "Le nombre d'adresses éligibles doit être > 0");
+
+        self.whitelist_merkle_root(election_id).set(&merkle_root);
+        self.whitelist_committed_count(election_id).set(eligible_count);
+
+        self.whitelist_root_committed_event(election_id, eligible_count, &merkle_root);
+    }
+
+    /// Inscription d'un électeur par preuve d'appartenance à la racine engagée
+    ///
+    /// # Arguments
+    /// * `election_id` - ID de l'élection
+    /// * `proof` - Noeuds frères, de la feuille vers la racine
+    #[endpoint(claimWhitelistRegistration)]
+    fn claim_whitelist_registration(&self, election_id: u64, proof: MultiValueEncoded<ManagedBuffer>) {
+        let caller = self.blockchain().get_caller();
+        let mut election = self.elections(election_id).get();
+        let current_time = self.blockchain().get_block_timestamp();
+
+        self.require_registration_open(&election);
+
+        require!(
+            !self.whitelist_merkle_root(election_id).is_empty(),
+            "Aucune liste blanche engagée pour cette élection"
+        );
+        require!(
+            self.registered_voters(election_id, &caller).is_empty(),
+            "Vous êtes déjà inscrit"
+        );
+        require!(
+            !self.whitelist_claimed(election_id, &caller).get(),
+            "Inscription déjà réclamée pour cette adresse"
+        );
+
+        let proof_vec = proof.to_vec();
+        require!(proof_vec.len() <= MAX_MERKLE_PROOF_DEPTH, "Preuve de Merkle trop longue");
+
+        let leaf = self.crypto().keccak256(caller.as_managed_buffer()).as_managed_buffer().clone();
+        let root = self.whitelist_merkle_root(election_id).get();
+        require!(
+            self.verify_merkle_proof(leaf, &proof_vec, &root),
+            "Preuve de Merkle invalide"
+        );
+
+        self.whitelist_claimed(election_id, &caller).set(true);
+        self.whitelist_claimed_count(election_id).update(|count| *count += 1);
+
+        self.registered_voters(election_id, &caller).set(true);
+        self.all_registered_voters(election_id).insert(caller.clone());
+        election.registered_voters_count += 1;
+        self.elections(election_id).set(&election);
+
+        // Tracker pour dashboard
+        self.last_registration_time(election_id).set(current_time);
+        let day_start = (current_time / 86400) * 86400;
+        let current_count = self.registrations_per_day(election_id, day_start).get();
+        self.registrations_per_day(election_id, day_start).set(current_count + 1);
+
+        self.voter_registered_event(election_id, &caller);
+    }
+
+    /// Vérifie une preuve de Merkle (paires triées, keccak256)
+    fn verify_merkle_proof(
+        &self,
+        leaf: ManagedBuffer,
+        proof: &ManagedVec<ManagedBuffer>,
+        root: &ManagedBuffer,
+    ) -> bool {
+        let mut computed = leaf;
+
+        for sibling in proof.iter() {
+            if sibling.len() != 32 {
+                return false;
+            }
+
+            let mut computed_bytes = [0u8; 32];
+            let mut sibling_bytes = [0u8; 32];
+            computed.load_slice(0, &mut computed_bytes);
+            sibling.load_slice(0, &mut sibling_bytes);
+
+            let mut node = ManagedBuffer::new();
+            if computed_bytes <= sibling_bytes {
+                node.append(&computed);
+                node.append(&sibling);
+            } else {
+                node.append(&sibling);
+                node.append(&computed);
+            }
+
+            computed = self.crypto().keccak256(&node).as_managed_buffer().clone();
+        }
+
+        computed == *root
+    }
+
     /// Retire des adresses de la liste blanche
     #[endpoint(removeFromWhitelist)]
     fn remove_from_whitelist(&self, election_id: u64, addresses: MultiValueEncoded<ManagedAddress>) {
@@ -368,14 +618,26 @@ pub trait VotingContract {
         self.elections(election_id).set(&election);
     }
 
-    /// Génère des codes d'invitation pour une élection
+    /// Enregistre un lot de codes d'invitation pour une élection
+    ///
+    /// Les secrets sont générés off-chain par l'organisateur: seul leur hash
+    /// keccak256 est stocké, de sorte que la lecture du storage ou des
+    /// transactions ne permet pas d'utiliser un code.
     ///
     /// # Arguments
     /// * `election_id` - ID de l'élection
-    /// * `count` - Nombre de codes à générer
-    /// * `batch_offset` - Offset de départ pour éviter les doublons entre batches (0 pour le premier batch, 100 pour le deuxième, etc.)
+    /// * `expires_at` - Timestamp d'expiration des codes du lot (optionnel)
+    /// * `code_hashes` - Hashes keccak256 des secrets d'invitation
+    ///
+    /// # Returns
+    /// ID du lot créé (attribué par le contrat, unique par élection)
     #[endpoint(generateInvitationCodes)]
-    fn generate_invitation_codes(&self, election_id: u64, count: u32, batch_offset: u32) -> MultiValueEncoded<ManagedBuffer> {
+    fn generate_invitation_codes(
+        &self,
+        election_id: u64,
+        expires_at: Option<u64>,
+        code_hashes: MultiValueEncoded<ManagedBuffer>,
+    ) -> u64 {
         let caller = self.blockchain().get_caller();
         let election = self.elections(election_id).get();
 
@@ -389,55 +651,140 @@ pub trait VotingContract {
             "Cette élection ne requiert pas d'inscription"
         );
 
-        require!(
-            election.status == ElectionStatus::Pending,
-            "Les inscriptions sont fermées"
-        );
-
-        let mut codes = MultiValueEncoded::new();
-        let random_seed = self.blockchain().get_block_random_seed();
-        let timestamp = self.blockchain().get_block_timestamp();
+        self.require_registration_open(&election);
 
-        // Utiliser le batch_offset pour démarrer à un compteur spécifique
-        // Cela évite les collisions entre transactions simultanées
-        let batch_start = batch_offset as u64;
-
-        for i in 0..count {
-            let mut code_data = ManagedBuffer::new();
+        if let Some(expiry) = expires_at {
+            require!(
+                expiry > self.blockchain().get_block_timestamp(),
+                "La date d'expiration doit être dans le futur"
+            );
+        }
 
-            // Calculer le compteur unique pour ce code
-            let counter = batch_start + i as u64;
+        // Les identifiants sont dérivés des compteurs du contrat: deux frontends
+        // générant des codes en parallèle obtiennent des lots distincts
+        let batch_id = self.invitation_batch_counter(election_id).get() + 1;
+        self.invitation_batch_counter(election_id).set(batch_id);
 
-            // Ajouter le random seed de manière sûre
-            code_data.append(random_seed.as_managed_buffer());
-            code_data.append_bytes(&timestamp.to_be_bytes()[..]);
-            code_data.append_bytes(&election_id.to_be_bytes()[..]);
-            // Utiliser le compteur basé sur le batch_offset pour garantir l'unicité entre les batches
-            code_data.append_bytes(&counter.to_be_bytes()[..]);
+        let first_code_index = self.invitation_code_counter().get() + 1;
+        let mut code_index = first_code_index;
+        let mut unused_codes = self.invitation_batch_unused_codes(election_id, batch_id);
+        let mut count = 0u32;
 
-            let code_hash_bytes = self.crypto().keccak256(&code_data);
-            let code_hash = code_hash_bytes.as_managed_buffer().clone();
+        for code_hash in code_hashes.into_iter() {
+            require!(code_hash.len() == 32, "Hash de code invalide (32 bytes attendus)");
+            require!(
+                self.invitation_codes(election_id, &code_hash).is_empty(),
+                "Code d'invitation déjà enregistré"
+            );
 
             self.invitation_codes(election_id, &code_hash).set(InvitationCode {
                 code_hash: code_hash.clone(),
                 is_used: false,
                 used_by: None,
+                code_index,
+                batch_id,
+                expires_at,
+                is_revoked: false,
             });
+            unused_codes.insert(code_hash);
+            code_index += 1;
+            count += 1;
+        }
+
+        require!(count > 0, "Aucun code fourni");
+
+        self.invitation_code_counter().set(code_index - 1);
+        self.invitation_batches(election_id, batch_id).set(InvitationBatch {
+            id: batch_id,
+            election_id,
+            first_code_index,
+            total_codes: count,
+            used_codes: 0,
+            revoked_codes: 0,
+            unused_codes: count,
+            expires_at,
+            created_at: self.blockchain().get_block_timestamp(),
+        });
+
+        self.invitation_codes_generated_event(election_id, batch_id, count);
+
+        batch_id
+    }
+
+    /// Révoque des codes d'invitation non utilisés
+    ///
+    /// # Arguments
+    /// * `election_id` - ID de l'élection
+    /// * `code_hashes` - Hashes des codes à révoquer
+    #[endpoint(revokeInvitationCodes)]
+    fn revoke_invitation_codes(&self, election_id: u64, code_hashes: MultiValueEncoded<ManagedBuffer>) {
+        let election = self.elections(election_id).get();
+        require!(
+            self.blockchain().get_caller() == election.organizer,
+            "Seul l'organisateur peut révoquer des codes"
+        );
+
+        for code_hash in code_hashes.into_iter() {
+            require!(
+                !self.invitation_codes(election_id, &code_hash).is_empty(),
+                "Code d'invitation invalide"
+            );
+            self.revoke_invitation_code(election_id, &code_hash);
+        }
+    }
+
+    /// Révoque tous les codes non utilisés d'un lot
+    #[endpoint(revokeInvitationBatch)]
+    fn revoke_invitation_batch(&self, election_id: u64, batch_id: u64) {
+        let election = self.elections(election_id).get();
+        require!(
+            self.blockchain().get_caller() == election.organizer,
+            "Seul l'organisateur peut révoquer des codes"
+        );
+        require!(
+            !self.invitation_batches(election_id, batch_id).is_empty(),
+            "Lot de codes inexistant"
+        );
+
+        let mut unused_codes = ManagedVec::<Self::Api, ManagedBuffer>::new();
+        for code_hash in self.invitation_batch_unused_codes(election_id, batch_id).iter() {
+            unused_codes.push(code_hash);
+        }
+
+        for code_hash in unused_codes.iter() {
+            self.revoke_invitation_code(election_id, &code_hash);
+        }
+    }
 
-            // Retourner le hash en format hex lisible au lieu du code brut
-            codes.push(code_hash);
+    /// Helper: révoque un code non utilisé et le retire de son lot
+    fn revoke_invitation_code(&self, election_id: u64, code_hash: &ManagedBuffer) {
+        let mut code_data = self.invitation_codes(election_id, code_hash).get();
+
+        require!(!code_data.is_used, "Ce code a déjà été utilisé");
+
+        if code_data.is_revoked {
+            return;
         }
 
-        codes
+        code_data.is_revoked = true;
+        self.invitation_codes(election_id, code_hash).set(&code_data);
+        self.invitation_batch_unused_codes(election_id, code_data.batch_id)
+            .swap_remove(code_hash);
+        self.invitation_batches(election_id, code_data.batch_id).update(|batch| {
+            batch.revoked_codes += 1;
+            batch.unused_codes -= 1;
+        });
+
+        self.invitation_code_revoked_event(election_id, code_data.batch_id, code_hash);
     }
 
     /// Inscription avec un code d'invitation
     ///
     /// # Arguments
     /// * `election_id` - ID de l'élection
-    /// * `invitation_code` - Code d'invitation
+    /// * `invitation_secret` - Secret d'invitation (préimage du hash enregistré)
     #[endpoint(registerWithInvitationCode)]
-    fn register_with_invitation_code(&self, election_id: u64, invitation_code: ManagedBuffer) {
+    fn register_with_invitation_code(&self, election_id: u64, invitation_secret: ManagedBuffer) {
         let caller = self.blockchain().get_caller();
         let mut election = self.elections(election_id).get();
         let current_time = self.blockchain().get_block_timestamp();
@@ -447,33 +794,21 @@ pub trait VotingContract {
             "Cette élection ne requiert pas d'inscription"
         );
 
-        require!(
-            election.status == ElectionStatus::Pending,
-            "Les inscriptions sont fermées"
-        );
+        self.require_registration_open(&election);
 
         require!(
             self.registered_voters(election_id, &caller).is_empty(),
             "Vous êtes déjà inscrit"
         );
 
-        // Le code d'invitation reçu est déjà un hash (généré par generateInvitationCodes)
-        // On ne doit pas le hasher à nouveau
-        require!(
-            !self.invitation_codes(election_id, &invitation_code).is_empty(),
-            "Code d'invitation invalide"
-        );
-
-        let mut code_data = self.invitation_codes(election_id, &invitation_code).get();
-
-        require!(
-            !code_data.is_used,
-            "Ce code a déjà été utilisé"
-        );
+        // Seul le hash du secret est stocké: on le recalcule à partir de la préimage
+        let code_hash = self.crypto().keccak256(&invitation_secret).as_managed_buffer().clone();
 
-        code_data.is_used = true;
-        code_data.used_by = Some(caller.clone());
-        self.invitation_codes(election_id, &invitation_code).set(&code_data);
+        if self.invitation_codes(election_id, &code_hash).is_empty() {
+            self.redeem_legacy_invitation_code(election_id, &invitation_secret, &caller);
+        } else {
+            self.redeem_invitation_code(election_id, &code_hash, &caller, current_time);
+        }
 
         self.registered_voters(election_id, &caller).set(true);
         self.all_registered_voters(election_id).insert(caller.clone());
@@ -489,80 +824,229 @@ pub trait VotingContract {
         self.voter_registered_event(election_id, &caller);
     }
 
-    /// Soumet un vote chiffré
-    ///
-    /// # Arguments
-    /// * `election_id` - ID de l'élection
-    /// * `voting_token` - Token de vote (vérifié avec voter-registry)
-    /// * `encrypted_vote` - Vote chiffré
-    #[endpoint(castVote)]
-    fn cast_vote(
+    /// Helper: marque un code d'invitation comme utilisé et le retire de son lot
+    fn redeem_invitation_code(
         &self,
         election_id: u64,
-        _voting_token: ManagedBuffer,
-        encrypted_vote: EncryptedVote<Self::Api>,
+        code_hash: &ManagedBuffer,
+        caller: &ManagedAddress,
+        current_time: u64,
     ) {
-        require!(self.elections(election_id).is_empty() == false, "Élection inexistante");
-
-        let mut election = self.elections(election_id).get();
-        let caller = self.blockchain().get_caller();
+        let mut code_data = self.invitation_codes(election_id, code_hash).get();
 
-        let current_time = self.blockchain().get_block_timestamp();
         require!(
-            current_time >= election.start_time && current_time <= election.end_time,
-            "Élection non active"
+            !code_data.is_used,
+            "Ce code a déjà été utilisé"
         );
-        require!(election.status == ElectionStatus::Active, "Élection non active");
 
-        // Vérifier que l'utilisateur n'a pas déjà voté
-        require!(
-            !self.voters(election_id, &caller).get(),
-            "Vous avez déjà voté pour cette élection"
-        );
+        require!(!code_data.is_revoked, "Ce code a été révoqué");
 
-        // Vérifier l'inscription si l'élection le requiert
-        if election.requires_registration {
-            require!(
-                !self.registered_voters(election_id, &caller).is_empty(),
-                "Vous devez vous inscrire avant de voter"
-            );
+        if let Some(expiry) = code_data.expires_at {
+            require!(current_time <= expiry, "Ce code a expiré");
         }
 
-        // TODO: Vérifier le token avec le contrat voter-registry
+        code_data.is_used = true;
+        code_data.used_by = Some(caller.clone());
+        self.invitation_codes(election_id, code_hash).set(&code_data);
+        self.invitation_batch_unused_codes(election_id, code_data.batch_id)
+            .swap_remove(code_hash);
+        self.invitation_batches(election_id, code_data.batch_id).update(|batch| {
+            batch.used_codes += 1;
+            batch.unused_codes -= 1;
+        });
+    }
+
+    /// Helper: marque comme utilisé un code émis avant les lots (clé `invitationCodes`)
+    ///
+    /// Ces codes sont indexés par la valeur elle-même, transmise telle quelle à l'inscription.
+    fn redeem_legacy_invitation_code(&self, election_id: u64, code: &ManagedBuffer, caller: &ManagedAddress) {
+        let legacy_code = self.legacy_invitation_codes(election_id, code);
+        require!(!legacy_code.is_empty(), "Code d'invitation invalide");
 
-        // Vérifier la preuve zk-SNARK du vote (version MOCK pour POC)
+        let mut code_data = legacy_code.get();
         require!(
-            crypto_mock::crypto_verification::verify_encrypted_vote(
-                &encrypted_vote.encrypted_choice,
-                &encrypted_vote.proof
-            ),
-            "Preuve de vote invalide"
+            !code_data.is_used,
+            "Ce code a déjà été utilisé"
         );
 
-        // Enregistrer que cet utilisateur a voté
-        self.voters(election_id, &caller).set(true);
+        code_data.is_used = true;
+        code_data.used_by = Some(caller.clone());
+        legacy_code.set(&code_data);
+    }
 
-        // Stocker le vote chiffré
-        self.votes(election_id).push(&encrypted_vote);
-        election.total_votes += 1;
-        self.elections(election_id).set(&election);
+    /// Indique si les inscriptions sont ouvertes pour une élection
+    ///
+    /// Les inscriptions sont ouvertes tant que:
+    /// - elles n'ont pas été explicitement fermées (closeRegistration)
+    /// - la date limite d'inscription (si définie) n'est pas dépassée
+    /// - l'élection est Pending, ou Active si l'organisateur l'a autorisé
+    fn is_registration_open_for(&self, election: &Election<Self::Api>) -> bool {
+        if self.registration_closed(election.id).get() {
+            return false;
+        }
 
-        // TODO: Révoquer le token dans voter-registry
+        if let Some(deadline) = election.registration_deadline {
+            if self.blockchain().get_block_timestamp() > deadline {
+                return false;
+            }
+        }
 
-        self.vote_cast_event(election_id, current_time);
+        match election.status {
+            ElectionStatus::Pending => true,
+            ElectionStatus::Active => self.registration_during_voting(election.id).get(),
+            _ => false,
+        }
     }
 
-    /// Soumet un vote privé avec preuve zk-SNARK vérifiée off-chain
-    ///
-    /// # Arguments
-    /// * `election_id` - ID de l'élection
+    /// Helper: échoue si les inscriptions sont fermées
+    fn require_registration_open(&self, election: &Election<Self::Api>) {
+        if let Some(deadline) = election.registration_deadline {
+            require!(
+                self.blockchain().get_block_timestamp() <= deadline,
+                "La date limite d'inscription est dépassée"
+            );
+        }
+
+        require!(
+            self.is_registration_open_for(election),
+            "Les inscriptions sont fermées"
+        );
+    }
+
+    /// Ferme les inscriptions d'une élection
+    ///
+    /// L'organisateur peut fermer les inscriptions à tout moment.
+    /// N'importe qui peut les fermer une fois la date limite dépassée,
+    /// afin que l'événement `registrationClosed` soit émis.
+    #[endpoint(closeRegistration)]
+    fn close_registration(&self, election_id: u64) {
+        require!(
+            !self.elections(election_id).is_empty(),
+            "Élection inexistante"
+        );
+
+        let election = self.elections(election_id).get();
+        require!(
+            election.requires_registration,
+            "Cette élection ne requiert pas d'inscription"
+        );
+        require!(
+            !self.registration_closed(election_id).get(),
+            "Les inscriptions sont déjà fermées"
+        );
+
+        let is_organizer = self.blockchain().get_caller() == election.organizer;
+        require!(
+            is_organizer || !self.is_registration_open_for(&election),
+            "Les inscriptions sont encore ouvertes"
+        );
+
+        self.mark_registration_closed(&election);
+    }
+
+    /// Helper: marque les inscriptions comme fermées et émet l'événement
+    fn mark_registration_closed(&self, election: &Election<Self::Api>) {
+        if !election.requires_registration || self.registration_closed(election.id).get() {
+            return;
+        }
+
+        self.registration_closed(election.id).set(true);
+        self.registration_closed_event(
+            election.id,
+            self.blockchain().get_block_timestamp(),
+            election.registered_voters_count,
+        );
+    }
+
+    /// Soumet un vote chiffré
+    ///
+    /// Le token est un credential anonyme : l'électeur l'a fait signer en aveugle par
+    /// l'autorité du voter-registry, puis l'a désaveuglé. Le voter-registry vérifie la
+    /// signature RSA et consomme le token ; aucune donnée liée à l'appelant n'est
+    /// enregistrée, l'éligibilité étant portée par le token lui-même.
+    ///
+    /// # Arguments
+    /// * `election_id` - ID de l'élection
+    /// * `voting_token` - Token de vote désaveuglé
+    /// * `token_signature` - Signature RSA désaveuglée de l'autorité sur le token
+    /// * `encrypted_vote` - Vote chiffré
+    #[endpoint(castVote)]
+    fn cast_vote(
+        &self,
+        election_id: u64,
+        voting_token: ManagedBuffer,
+        token_signature: ManagedBuffer,
+        encrypted_vote: EncryptedVote<Self::Api>,
+    ) {
+        require!(!self.elections(election_id).is_empty(), "Élection inexistante");
+
+        let mut election = self.elections(election_id).get();
+
+        let current_time = self.blockchain().get_block_timestamp();
+        require!(
+            current_time >= election.start_time && current_time <= election.end_time,
+            "Élection non active"
+        );
+        require!(election.status == ElectionStatus::Active, "Élection non active");
+
+        // Vérifier le token (signature de l'autorité, non utilisé) avec le voter-registry
+        require!(
+            !self.voter_registry_address().is_empty(),
+            "Contrat voter-registry non configuré"
+        );
+        let voter_registry = self.voter_registry_address().get();
+        let is_token_valid: bool = self
+            .voter_registry_proxy(voter_registry.clone())
+            .is_token_valid(election_id, voting_token.clone(), token_signature.clone())
+            .returns(ReturnsResult)
+            .sync_call();
+        require!(is_token_valid, "Token de vote invalide");
+
+        // Vérifier la preuve de validité du vote chiffré
+        require!(
+            self.proof_verifier().verify_encrypted_vote(
+                &encrypted_vote.encrypted_choice,
+                &encrypted_vote.proof
+            ),
+            "Preuve de vote invalide"
+        );
+
+        // Stocker le vote chiffré
+        self.votes(election_id).push(&encrypted_vote);
+        election.total_votes += 1;
+        self.elections(election_id).set(&election);
+
+        // Révoquer le token dans voter-registry (un token = un vote)
+        self.voter_registry_proxy(voter_registry)
+            .revoke_token(election_id, voting_token, token_signature)
+            .sync_call();
+
+        self.vote_cast_event(election_id, current_time);
+    }
+
+    /// Helper: vérificateur de preuves, attesté par le backend vérificateur
+    fn proof_verifier(&self) -> DefaultVerifier<Self::Api> {
+        require!(
+            !self.backend_verifier_address().is_empty(),
+            "Vérificateur de preuves non configuré"
+        );
+
+        DefaultVerifier::new(self.backend_verifier_address().get())
+    }
+
+    /// Soumet un vote privé avec preuve zk-SNARK vérifiée off-chain
+    ///
+    /// # Arguments
+    /// * `election_id` - ID de l'élection
     /// * `vote_commitment` - Commitment Poseidon du vote
     /// * `nullifier` - Nullifier unique pour empêcher le double vote
-    /// * `backend_signature` - Signature du backend après vérification de la preuve
+    /// * `backend_signature` - Attestation du backend :
+    ///   `preuve || sig(sha256(preuve || keccak256(election_id || vote_commitment || nullifier)))`
     ///
     /// # Sécurité
     /// - La preuve zk-SNARK est vérifiée off-chain par le backend
-    /// - Le backend signe les données pour autoriser la transaction
+    /// - L'attestation du backend est vérifiée par le vérificateur de preuves configuré ;
+    ///   sans vérificateur, le vote est refusé
     /// - Le nullifier empêche tout double vote
     #[endpoint(submitPrivateVote)]
     fn submit_private_vote(
@@ -591,23 +1075,13 @@ pub trait VotingContract {
             "Élection non active"
         );
 
-        // 2. Vérifier la signature du backend
-        // Pour le POC, on vérifie simplement que la signature correspond au hash attendu
-        // TODO: Implémenter une vraie signature cryptographique Ed25519 en production
-        let _expected_hash = self.hash_vote_data(&election_id, &vote_commitment, &nullifier);
-
-        // La signature du backend devrait commencer par le hash des données
+        // 2. Vérifier l'attestation du backend sur les données du vote
+        let vote_data = self.hash_vote_data(&election_id, &vote_commitment, &nullifier);
         require!(
-            backend_signature.len() >= 64,
-            "Signature backend invalide (longueur)"
+            self.proof_verifier().verify_zk_snark_proof(&backend_signature, &vote_data),
+            "Signature backend invalide"
         );
 
-        // Pour le POC, on accepte toute signature de longueur correcte
-        // En production, il faudrait vérifier la signature Ed25519 du backend
-        // avec self.crypto().verify_ed25519() et l'adresse du backend
-        let _backend_address = self.backend_verifier_address().get();
-        // Vérification simplifiée pour le POC
-
         // 3. Vérifier que le nullifier n'est pas déjà utilisé (double vote)
         require!(
             !self.used_nullifiers(election_id).contains(&nullifier),
@@ -690,7 +1164,7 @@ pub trait VotingContract {
 
         // Vérifier que la clé publique n'est pas vide
         require!(
-            public_key.len() > 0,
+            !public_key.is_empty(),
             "Clé publique invalide"
         );
 
@@ -767,14 +1241,14 @@ pub trait VotingContract {
         // 4. Vérifier l'inscription si l'élection le requiert
         if election.requires_registration {
             require!(
-                !self.registered_voters(election_id, &caller).is_empty(),
+                self.registered_voters(election_id, &caller).get(),
                 "Vous devez vous inscrire avant de voter"
             );
         }
 
         // 5. Vérifier que les composantes du vote chiffré ne sont pas vides
         require!(
-            c1.len() > 0 && c2.len() > 0,
+            !c1.is_empty() && !c2.is_empty(),
             "Vote chiffré invalide"
         );
 
@@ -805,7 +1279,7 @@ pub trait VotingContract {
     /// - Chiffrement ElGamal pour la confidentialité
     /// - Preuve zk-SNARK pour garantir la validité
     /// - Nullifier pour empêcher le double vote
-    /// - Vérification on-chain de la preuve
+    /// - Attestation de la preuve par le vérificateur configuré
     ///
     /// # Arguments
     /// * `election_id` - ID de l'élection
@@ -816,6 +1290,8 @@ pub trait VotingContract {
     /// * `pi_b` - Deuxième composante de la preuve Groth16 (point G2)
     /// * `pi_c` - Troisième composante de la preuve Groth16 (point G1)
     /// * `public_signals` - Signaux publics pour vérification [numCandidates, c1, c2, publicKey, nullifier, electionId]
+    /// * `backend_attestation` - Signature Ed25519 du vérificateur sur
+    ///   `sha256(preuve encodée || signaux publics encodés)`
     ///
     /// # Sécurité
     /// - Le vote est chiffré ElGamal (seul l'organisateur peut déchiffrer)
@@ -823,13 +1299,14 @@ pub trait VotingContract {
     ///   1. Le candidateId est valide (< numCandidates)
     ///   2. Le chiffrement ElGamal est correct
     ///   3. Le nullifier est bien formé
-    /// - Le smart contract vérifie la preuve ON-CHAIN via pairing check
+    /// - La preuve est vérifiée off-chain par le vérificateur, dont l'attestation est
+    ///   contrôlée on-chain ; sans vérificateur configuré, le vote est refusé
     /// - Le nullifier empêche le double vote de manière anonyme
     ///
     /// # Workflow
     /// 1. Frontend génère la preuve zk-SNARK (2-3 secondes)
     /// 2. Transaction soumise au smart contract
-    /// 3. Smart contract vérifie la preuve Groth16
+    /// 3. Smart contract vérifie l'attestation de la preuve Groth16
     /// 4. Si valide, vote accepté et stocké
     /// 5. Nullifier enregistré pour empêcher double vote
     #[endpoint(submitPrivateVoteWithProof)]
@@ -843,6 +1320,7 @@ pub trait VotingContract {
         pi_b: G2Point<Self::Api>,
         pi_c: G1Point<Self::Api>,
         public_signals: ManagedVec<ManagedBuffer>,
+        backend_attestation: ManagedBuffer,
     ) {
         // 1. Vérifier que l'élection existe et est active
         require!(
@@ -910,20 +1388,17 @@ pub trait VotingContract {
             "Public signal electionId ne correspond pas"
         );
 
-        // 6. Vérifier la preuve Groth16
-        // NOTE: Pour le POC, on implémente une vérification simplifiée
-        // La vérification complète nécessite les pairing checks BN254
-        // qui seront implémentés dans une version future du smart contract
-
+        // 6. Vérifier l'attestation de la preuve Groth16
         let proof = Groth16Proof {
             pi_a: pi_a.clone(),
             pi_b: pi_b.clone(),
             pi_c: pi_c.clone(),
         };
 
-        let is_proof_valid = self.verify_groth16_proof_simplified(
+        let is_proof_valid = self.verify_groth16_proof(
             &proof,
             &public_signals,
+            &backend_attestation,
         );
 
         require!(
@@ -933,7 +1408,7 @@ pub trait VotingContract {
 
         // 7. Vérifier que les composantes du vote chiffré ne sont pas vides
         require!(
-            c1.len() > 0 && c2.len() > 0 && nullifier.len() > 0,
+            !c1.is_empty() && !c2.is_empty() && !nullifier.is_empty(),
             "Vote chiffré invalide"
         );
 
@@ -963,64 +1438,41 @@ pub trait VotingContract {
         );
     }
 
-    /// Vérification simplifiée de la preuve Groth16
-    ///
-    /// NOTE: Cette fonction est une SIMPLIFICATION pour le POC.
-    /// La vérification complète nécessite:
-    /// 1. Charger la verification key depuis le storage
-    /// 2. Effectuer les pairing checks BN254:
-    ///    e(pi_a, pi_b) = e(alpha, beta) * e(vk_x, gamma) * e(pi_c, delta)
-    /// 3. Utiliser une precompiled contract ou bibliothèque crypto
-    ///
-    /// Pour l'instant, on fait des vérifications de base:
-    /// - Format des points G1 et G2
-    /// - Longueur des coordonnées
-    /// - Non-nullité des éléments
+    /// Vérifie une preuve Groth16 via le vérificateur de preuves
     ///
-    /// TODO: Implémenter la vérification complète avec pairing checks
-    fn verify_groth16_proof_simplified(
+    /// Les pairing checks BN254 sont effectués off-chain par le vérificateur, qui signe
+    /// la preuve et ses signaux publics (encodage MultiversX imbriqué). Le contrat ne
+    /// vérifie on-chain que la forme des points et cette attestation.
+    fn verify_groth16_proof(
         &self,
         proof: &Groth16Proof<Self::Api>,
         public_signals: &ManagedVec<ManagedBuffer>,
+        backend_attestation: &ManagedBuffer,
     ) -> bool {
-        // 1. Vérifier que pi_a (G1) n'est pas vide
-        if proof.pi_a.x.len() == 0 || proof.pi_a.y.len() == 0 {
-            return false;
-        }
-
-        // 2. Vérifier que pi_b (G2) n'est pas vide
-        if proof.pi_b.x1.len() == 0 || proof.pi_b.x2.len() == 0 ||
-           proof.pi_b.y1.len() == 0 || proof.pi_b.y2.len() == 0 {
+        if backend_attestation.len() != ED25519_SIGNATURE_LEN {
             return false;
         }
 
-        // 3. Vérifier que pi_c (G1) n'est pas vide
-        if proof.pi_c.x.len() == 0 || proof.pi_c.y.len() == 0 {
+        // Points G1 et G2 non vides
+        if proof.pi_a.x.is_empty() || proof.pi_a.y.is_empty() ||
+           proof.pi_b.x1.is_empty() || proof.pi_b.x2.is_empty() ||
+           proof.pi_b.y1.is_empty() || proof.pi_b.y2.is_empty() ||
+           proof.pi_c.x.is_empty() || proof.pi_c.y.is_empty() {
             return false;
         }
 
-        // 4. Vérifier que les public signals ne sont pas vides
-        if public_signals.len() == 0 {
+        let mut attested_proof = ManagedBuffer::new();
+        if proof.dep_encode(&mut attested_proof).is_err() {
             return false;
         }
+        attested_proof.append(backend_attestation);
 
-        // 5. Vérifications supplémentaires
-        // Les coordonnées doivent avoir une taille raisonnable (32-64 bytes)
-        let min_size = 10;
-        let max_size = 128;
-
-        if proof.pi_a.x.len() < min_size || proof.pi_a.x.len() > max_size {
-            return false;
-        }
-
-        if proof.pi_a.y.len() < min_size || proof.pi_a.y.len() > max_size {
+        let mut public_inputs = ManagedBuffer::new();
+        if public_signals.dep_encode(&mut public_inputs).is_err() {
             return false;
         }
 
-        // TODO: Ajouter vérification complète Groth16 avec pairing check
-        // Pour l'instant, on accepte si le format est correct
-
-        true
+        self.proof_verifier().verify_zk_snark_proof(&attested_proof, &public_inputs)
     }
 
     /// Utilitaire: Convertir u64 en ManagedBuffer
@@ -1029,35 +1481,48 @@ pub trait VotingContract {
         ManagedBuffer::from(value_str.as_bytes())
     }
 
-    /// Récupère tous les votes chiffrés ElGamal d'une élection
+    /// Récupère une page des votes chiffrés ElGamal d'une élection
     ///
     /// # Arguments
     /// * `election_id` - ID de l'élection
+    /// * `cursor` - Position du premier vote (0 pour commencer)
+    /// * `limit` - Nombre maximum de votes à retourner (plafonné à MAX_PAGE_SIZE)
     ///
     /// # Returns
-    /// Vecteur de tous les votes chiffrés
+    /// (curseur suivant, votes); le curseur suivant vaut 0 en fin de liste
     ///
     /// # Note
     /// Cette view est utilisée par l'organisateur pour récupérer les votes
     /// et les déchiffrer off-chain après la clôture de l'élection
     #[view(getEncryptedVotes)]
-    fn get_encrypted_votes(&self, election_id: u64) -> MultiValueEncoded<ElGamalVote<Self::Api>> {
+    fn get_encrypted_votes(
+        &self,
+        election_id: u64,
+        cursor: u64,
+        limit: u64,
+    ) -> MultiValue2<u64, MultiValueEncoded<ElGamalVote<Self::Api>>> {
+        let votes = self.elgamal_votes(election_id);
+        let (start, end, next_cursor) = page_bounds(cursor, limit, votes.len());
+
         let mut result = MultiValueEncoded::new();
-        for vote in self.elgamal_votes(election_id).iter() {
-            result.push(vote);
+        for index in start..end {
+            // VecMapper est indexé à partir de 1
+            result.push(votes.get(index + 1));
         }
-        result
+        MultiValue2::from((next_cursor, result))
     }
 
     /// **OPTION 2: RÉCUPÉRER VOTES CHIFFRÉS AVEC PREUVE ZK-SNARK**
     ///
-    /// Récupère tous les votes chiffrés ElGamal avec preuves zk-SNARK d'une élection
+    /// Récupère une page des votes chiffrés ElGamal avec preuves zk-SNARK d'une élection
     ///
     /// # Arguments
     /// * `election_id` - ID de l'élection
+    /// * `cursor` - Position du premier vote (0 pour commencer)
+    /// * `limit` - Nombre maximum de votes à retourner (plafonné à MAX_PAGE_SIZE)
     ///
     /// # Returns
-    /// Vecteur de tous les votes chiffrés avec leurs preuves Groth16
+    /// (curseur suivant, votes avec leurs preuves Groth16); le curseur suivant vaut 0 en fin de liste
     ///
     /// # Note
     /// Cette view est utilisée par:
@@ -1068,35 +1533,96 @@ pub trait VotingContract {
     fn get_encrypted_votes_with_proof(
         &self,
         election_id: u64,
-    ) -> MultiValueEncoded<ElGamalVoteWithProof<Self::Api>> {
+        cursor: u64,
+        limit: u64,
+    ) -> MultiValue2<u64, MultiValueEncoded<ElGamalVoteWithProof<Self::Api>>> {
+        let votes = self.elgamal_votes_with_proof(election_id);
+        let (start, end, next_cursor) = page_bounds(cursor, limit, votes.len());
+
         let mut result = MultiValueEncoded::new();
-        for vote in self.elgamal_votes_with_proof(election_id).iter() {
-            result.push(vote);
+        for index in start..end {
+            result.push(votes.get(index + 1));
         }
-        result
+        MultiValue2::from((next_cursor, result))
     }
 
-    /// Récupère les nullifiers utilisés pour une élection (Option 2)
+    /// Récupère une page des nullifiers utilisés pour une élection (Option 2)
     ///
     /// # Arguments
     /// * `election_id` - ID de l'élection
+    /// * `cursor` - Position du premier nullifier (0 pour commencer)
+    /// * `limit` - Nombre maximum de nullifiers à retourner (plafonné à MAX_PAGE_SIZE)
     ///
     /// # Returns
-    /// Ensemble des nullifiers déjà utilisés
+    /// (curseur suivant, nullifiers); le curseur suivant vaut 0 en fin de liste
     ///
     /// # Note
     /// Permet de vérifier qu'un vote n'a pas déjà été soumis
-    /// Sans révéler l'identité du voteur
+    /// Sans révéler l'identité du voteur.
+    /// Chaque vote Option 2 enregistre exactement un nullifier: la pagination
+    /// suit donc l'ordre de `elgamalVotesWithProof`.
     #[view(getOption2Nullifiers)]
     fn get_option2_nullifiers(
         &self,
         election_id: u64,
-    ) -> MultiValueEncoded<ManagedBuffer> {
+        cursor: u64,
+        limit: u64,
+    ) -> MultiValue2<u64, MultiValueEncoded<ManagedBuffer>> {
+        let votes = self.elgamal_votes_with_proof(election_id);
+        let (start, end, next_cursor) = page_bounds(cursor, limit, votes.len());
+
         let mut result = MultiValueEncoded::new();
-        for nullifier in self.option2_nullifiers(election_id).iter() {
-            result.push(nullifier);
+        for index in start..end {
+            result.push(votes.get(index + 1).nullifier);
         }
-        result
+        MultiValue2::from((next_cursor, result))
+    }
+
+    /// Récupère une page des votes privés zk-SNARK (vérifiés par le backend)
+    ///
+    /// # Arguments
+    /// * `election_id` - ID de l'élection
+    /// * `cursor` - Position du premier vote (0 pour commencer)
+    /// * `limit` - Nombre maximum de votes à retourner (plafonné à MAX_PAGE_SIZE)
+    #[view(getPrivateVotes)]
+    fn get_private_votes(
+        &self,
+        election_id: u64,
+        cursor: u64,
+        limit: u64,
+    ) -> MultiValue2<u64, MultiValueEncoded<PrivateVote<Self::Api>>> {
+        let votes = self.private_votes(election_id);
+        let (start, end, next_cursor) = page_bounds(cursor, limit, votes.len());
+
+        let mut result = MultiValueEncoded::new();
+        for index in start..end {
+            result.push(votes.get(index + 1));
+        }
+        MultiValue2::from((next_cursor, result))
+    }
+
+    /// Récupère une page des nullifiers des votes privés (submitPrivateVote)
+    ///
+    /// # Arguments
+    /// * `election_id` - ID de l'élection
+    /// * `cursor` - Position du premier nullifier (0 pour commencer)
+    /// * `limit` - Nombre maximum de nullifiers à retourner (plafonné à MAX_PAGE_SIZE)
+    #[view(getUsedNullifiers)]
+    fn get_used_nullifiers(
+        &self,
+        election_id: u64,
+        cursor: u64,
+        limit: u64,
+    ) -> MultiValue2<u64, MultiValueEncoded<ManagedBuffer>> {
+        let nullifiers = self.used_nullifiers(election_id);
+        let (start, end, next_cursor) = page_bounds(cursor, limit, nullifiers.len());
+
+        let mut result = MultiValueEncoded::new();
+        for index in start..end {
+            // UnorderedSetMapper est indexé à partir de 1
+            result.push(nullifiers.get_by_index(index + 1));
+        }
+        MultiValue2::from((next_cursor, result))
     }
 
     /// Active une élection (changement de statut Pending -> Active)
@@ -1115,6 +1641,10 @@ pub trait VotingContract {
 
         election.status = ElectionStatus::Active;
         self.elections(election_id).set(&election);
+
+        if !self.registration_during_voting(election_id).get() {
+            self.mark_registration_closed(&election);
+        }
     }
 
     /// Ferme une élection (changement de statut Active -> Closed)
@@ -1125,6 +1655,7 @@ pub trait VotingContract {
             self.blockchain().get_caller() == election.organizer,
             "Seul l'organisateur peut fermer"
         );
+        require!(election.status == ElectionStatus::Active, "Élection non active");
         require!(
             self.blockchain().get_block_timestamp() >= election.end_time,
             "Élection pas encore terminée"
@@ -1133,12 +1664,126 @@ pub trait VotingContract {
         election.status = ElectionStatus::Closed;
         self.elections(election_id).set(&election);
 
+        self.mark_registration_closed(&election);
+
         self.election_closed_event(election_id, election.total_votes);
     }
 
+    /// Dépouille par lots les bulletins en clair d'une élection fermée (encryption_type == 0)
+    ///
+    /// Endpoint sans permission: n'importe qui peut faire avancer le dépouillement.
+    /// Chaque appel traite au plus `max_ballots` bulletins à partir du curseur stocké,
+    /// et s'arrête plus tôt si le gas restant descend sous `TALLY_GAS_RESERVE`
+    /// (au moins un bulletin est toujours traité). Le curseur et les compteurs
+    /// partiels sont stockés: l'appel suivant reprend là où celui-ci s'est arrêté.
+    /// Lorsque le dernier bulletin est traité, les résultats sont écrits dans
+    /// `finalResults` et l'élection passe en `Finalized`, sans intervention
+    /// de l'organisateur.
+    ///
+    /// # Arguments
+    /// * `election_id` - ID de l'élection
+    /// * `max_ballots` - Nombre maximum de bulletins à traiter dans cet appel
+    ///
+    /// # Returns
+    /// Nombre de bulletins restant à dépouiller
+    #[endpoint(tallyPlaintext)]
+    fn tally_plaintext(&self, election_id: u64, max_ballots: u64) -> u64 {
+        require!(
+            !self.elections(election_id).is_empty(),
+            "Élection inexistante"
+        );
+
+        let election = self.elections(election_id).get();
+        require!(
+            election.encryption_type == 0,
+            "Le dépouillement on-chain est réservé aux élections sans chiffrement"
+        );
+        require!(
+            election.status == ElectionStatus::Closed,
+            "L'élection doit être fermée avant le dépouillement"
+        );
+        require!(max_ballots > 0, "max_ballots doit être > 0");
+
+        let votes = self.votes(election_id);
+        let total = votes.len() as u64;
+        let start = self.tally_cursor(election_id).get();
+        let limit = (start + max_ballots).min(total);
+        let mut end = start;
+
+        while end < limit {
+            if end > start && self.blockchain().get_gas_left() < TALLY_GAS_RESERVE {
+                break;
+            }
+
+            // VecMapper est indexé à partir de 1
+            let vote = votes.get(end as usize + 1);
+            match self.decode_plaintext_choice(&vote.encrypted_choice, election.num_candidates) {
+                Some(candidate_id) => {
+                    self.tally_counts(election_id, candidate_id).update(|count| *count += 1);
+                },
+                None => {
+                    self.tally_invalid_ballots(election_id).update(|count| *count += 1);
+                },
+            }
+
+            end += 1;
+        }
+
+        self.tally_cursor(election_id).set(end);
+        self.tally_progress_event(election_id, total, end);
+
+        if end == total {
+            self.finalize_plaintext_tally(election);
+        }
+
+        total - end
+    }
+
+    /// Décode un bulletin en clair: identifiant du candidat sur 4 bytes big-endian
+    ///
+    /// Retourne `None` pour un bulletin mal formé ou un candidat inexistant.
+    fn decode_plaintext_choice(&self, choice: &ManagedBuffer, num_candidates: u32) -> Option<u32> {
+        if choice.len() != 4 {
+            return None;
+        }
+
+        let mut bytes = [0u8; 4];
+        choice.load_slice(0, &mut bytes);
+        let candidate_id = u32::from_be_bytes(bytes);
+
+        if candidate_id > 0 && candidate_id <= num_candidates {
+            Some(candidate_id)
+        } else {
+            None
+        }
+    }
+
+    /// Écrit les résultats du dépouillement on-chain et finalise l'élection
+    fn finalize_plaintext_tally(&self, mut election: Election<Self::Api>) {
+        let election_id = election.id;
+        let mut final_results_mapper = self.final_results(election_id);
+        final_results_mapper.clear();
+
+        for candidate_id in 1..=election.num_candidates {
+            final_results_mapper.push(&CandidateResult {
+                candidate_id,
+                vote_count: self.tally_counts(election_id, candidate_id).get(),
+            });
+        }
+
+        election.status = ElectionStatus::Finalized;
+        self.elections(election_id).set(&election);
+        self.finalized_at(election_id).set(self.blockchain().get_block_timestamp());
+
+        self.election_finalized_event(election_id, election.total_votes);
+    }
+
     /// Finalise une élection fermée avec les résultats finaux
     /// Cette fonction stocke les résultats on-chain de manière immuable et optionnellement le hash IPFS
     ///
+    /// Réservée aux élections chiffrées (dépouillement off-chain): les élections
+    /// sans chiffrement sont finalisées automatiquement par `tallyPlaintext`.
+    ///
     /// # Arguments
     /// * `election_id` - ID de l'élection
     /// * `results_ipfs_hash` - Hash IPFS des résultats détaillés (vide si pas d'IPFS)
@@ -1159,6 +1804,10 @@ pub trait VotingContract {
             election.status == ElectionStatus::Closed,
             "L'élection doit être fermée avant d'être finalisée"
         );
+        require!(
+            election.encryption_type != 0,
+            "Les élections sans chiffrement sont dépouillées on-chain via tallyPlaintext"
+        );
 
         // Vérifier que le nombre de résultats correspond au nombre de candidats
         let results_count = results.len();
@@ -1202,11 +1851,267 @@ pub trait VotingContract {
 
         election.status = ElectionStatus::Finalized;
         self.elections(election_id).set(&election);
+        self.finalized_at(election_id).set(self.blockchain().get_block_timestamp());
 
         self.election_finalized_event(election_id, election.total_votes);
     }
 
+    // === CONTESTATION ET RECOMPTAGE ===
+
+    /// Conteste le résultat d'une élection finalisée
+    ///
+    /// Ouvert pendant `disputePeriod` secondes après la finalisation, aux électeurs
+    /// inscrits (ou ayant voté) et aux représentants des candidats. La caution
+    /// `disputeBond` (EGLD) est remboursée si la contestation aboutit à un recomptage
+    /// ou à une annulation, et conservée si le résultat est confirmé.
+    ///
+    /// # Arguments
+    /// * `election_id` - ID de l'élection
+    /// * `evidence_hash` - Hash des éléments de preuve (stockés hors-chaîne)
+    ///
+    /// # Returns
+    /// ID de la contestation
+    #[payable("EGLD")]
+    #[endpoint(fileDispute)]
+    fn file_dispute(&self, election_id: u64, evidence_hash: ManagedBuffer) -> u64 {
+        require!(!self.elections(election_id).is_empty(), "Élection inexistante");
+        require!(!evidence_hash.is_empty(), "Hash des preuves requis");
+
+        let election = self.elections(election_id).get();
+        require!(
+            election.status == ElectionStatus::Finalized,
+            "Seule une élection finalisée peut être contestée"
+        );
+
+        let current_time = self.blockchain().get_block_timestamp();
+        require!(
+            current_time <= self.finalized_at(election_id).get() + self.dispute_period().get(),
+            "Délai de contestation expiré"
+        );
+
+        let caller = self.blockchain().get_caller();
+        require!(
+            self.registered_voters(election_id, &caller).get()
+                || self.voters(election_id, &caller).get()
+                || !self.represented_candidate(election_id, &caller).is_empty(),
+            "Seuls les électeurs inscrits et les candidats peuvent contester"
+        );
+
+        let bond = self.call_value().egld().clone();
+        require!(bond == self.dispute_bond().get(), "Caution de contestation incorrecte");
+
+        let mut disputes = self.disputes(election_id);
+        let dispute_id = disputes.len() as u64 + 1;
+        disputes.push(&Dispute {
+            id: dispute_id,
+            filer: caller.clone(),
+            evidence_hash: evidence_hash.clone(),
+            bond: bond.clone(),
+            filed_at: current_time,
+            status: DisputeStatus::Open,
+            resolved_by: None,
+            resolved_at: 0,
+        });
+
+        self.dispute_filed_event(election_id, dispute_id, &caller, &evidence_hash, &bond);
+
+        dispute_id
+    }
+
+    /// Tranche une contestation (rôle d'arbitre)
+    ///
+    /// - `ConfirmResult` : le résultat est maintenu, la caution est conservée
+    /// - `OrderRecount` : le résultat est archivé et l'élection repasse en `Closed`
+    /// - `AnnulElection` : l'élection passe en `Annulled`
+    ///
+    /// Un recomptage ou une annulation clôt aussi les autres contestations ouvertes de
+    /// l'élection, dont les cautions sont remboursées.
+    ///
+    /// # Arguments
+    /// * `election_id` - ID de l'élection
+    /// * `dispute_id` - ID de la contestation
+    /// * `resolution` - Décision d'arbitrage
+    #[endpoint(resolveDispute)]
+    fn resolve_dispute(&self, election_id: u64, dispute_id: u64, resolution: DisputeResolution) {
+        let caller = self.blockchain().get_caller();
+        require!(
+            self.arbitrators().contains(&caller),
+            "Seul un arbitre peut trancher une contestation"
+        );
+
+        let disputes = self.disputes(election_id);
+        require!(
+            dispute_id > 0 && dispute_id <= disputes.len() as u64,
+            "Contestation inexistante"
+        );
+        let dispute = disputes.get(dispute_id as usize);
+        require!(dispute.status == DisputeStatus::Open, "Contestation déjà tranchée");
+
+        let mut election = self.elections(election_id).get();
+        require!(
+            election.status == ElectionStatus::Finalized,
+            "L'élection n'est plus finalisée"
+        );
+
+        match resolution {
+            DisputeResolution::ConfirmResult => {
+                self.close_dispute(election_id, dispute, DisputeStatus::Confirmed, &caller);
+                self.election_result_confirmed_event(election_id, dispute_id);
+            },
+            DisputeResolution::OrderRecount => {
+                let round = self.archive_final_results(&election);
+                election.status = ElectionStatus::Closed;
+                self.elections(election_id).set(&election);
+
+                self.close_open_disputes(election_id, DisputeStatus::RecountOrdered, &caller);
+                self.election_recount_ordered_event(election_id, dispute_id, round);
+            },
+            DisputeResolution::AnnulElection => {
+                election.status = ElectionStatus::Annulled;
+                self.elections(election_id).set(&election);
+
+                self.close_open_disputes(election_id, DisputeStatus::Annulled, &caller);
+                self.election_annulled_event(election_id, dispute_id);
+            },
+        }
+    }
+
+    /// Helper: indique si une contestation de l'élection attend un arbitrage
+    fn has_open_disputes(&self, election_id: u64) -> bool {
+        self.disputes(election_id)
+            .iter()
+            .any(|dispute| dispute.status == DisputeStatus::Open)
+    }
+
+    /// Helper: clôt toutes les contestations ouvertes d'une élection
+    fn close_open_disputes(&self, election_id: u64, status: DisputeStatus, arbitrator: &ManagedAddress) {
+        for dispute in self.disputes(election_id).iter() {
+            if dispute.status == DisputeStatus::Open {
+                self.close_dispute(election_id, dispute, status, arbitrator);
+            }
+        }
+    }
+
+    /// Helper: enregistre la décision, puis rembourse ou conserve la caution
+    fn close_dispute(
+        &self,
+        election_id: u64,
+        mut dispute: Dispute<Self::Api>,
+        status: DisputeStatus,
+        arbitrator: &ManagedAddress,
+    ) {
+        dispute.status = status;
+        dispute.resolved_by = Some(arbitrator.clone());
+        dispute.resolved_at = self.blockchain().get_block_timestamp();
+
+        if dispute.bond > 0u64 {
+            if status == DisputeStatus::Confirmed {
+                self.forfeited_bonds().update(|total| *total += &dispute.bond);
+            } else {
+                self.send().direct_egld(&dispute.filer, &dispute.bond);
+            }
+        }
+
+        self.disputes(election_id).set(dispute.id as usize, &dispute);
+        self.dispute_resolved_event(election_id, dispute.id, status);
+    }
+
+    /// Helper: archive les résultats finaux et remet le dépouillement à zéro
+    ///
+    /// Retourne le numéro de l'archive (1 pour le premier recomptage).
+    fn archive_final_results(&self, election: &Election<Self::Api>) -> u32 {
+        let election_id = election.id;
+        let round = self.recount_count(election_id).get() + 1;
+        self.recount_count(election_id).set(round);
+
+        let mut archive = self.archived_results(election_id, round);
+        for result in self.final_results(election_id).iter() {
+            archive.push(&result);
+        }
+        self.final_results(election_id).clear();
+        self.results_ipfs_hash(election_id).clear();
+
+        // Le dépouillement on-chain reprend depuis le premier bulletin
+        self.tally_cursor(election_id).clear();
+        self.tally_invalid_ballots(election_id).clear();
+        for candidate_id in 1..=election.num_candidates {
+            self.tally_counts(election_id, candidate_id).clear();
+        }
+
+        round
+    }
+
+    /// Ajoute un arbitre des contestations (admin)
+    #[only_owner]
+    #[endpoint(addArbitrator)]
+    fn add_arbitrator(&self, address: ManagedAddress) {
+        self.arbitrators().insert(address);
+    }
+
+    /// Retire un arbitre des contestations (admin)
+    #[only_owner]
+    #[endpoint(removeArbitrator)]
+    fn remove_arbitrator(&self, address: ManagedAddress) {
+        self.arbitrators().swap_remove(&address);
+    }
+
+    /// Configure le délai de contestation (secondes) et la caution en EGLD (admin)
+    ///
+    /// Le délai ne peut pas être nul : les résultats ne sont publiés qu'à son expiration.
+    #[only_owner]
+    #[endpoint(setDisputeSettings)]
+    fn set_dispute_settings(&self, dispute_period: u64, dispute_bond: BigUint) {
+        require!(dispute_period > 0, "Le délai de contestation doit être non nul");
+
+        self.dispute_period().set(dispute_period);
+        self.dispute_bond().set(&dispute_bond);
+    }
+
+    /// Déclare l'adresse représentant un candidat, autorisée à contester
+    ///
+    /// Un candidat a un seul représentant : une nouvelle déclaration remplace la précédente.
+    #[endpoint(setCandidateRepresentative)]
+    fn set_candidate_representative(&self, election_id: u64, candidate_id: u32, representative: ManagedAddress) {
+        require!(!self.elections(election_id).is_empty(), "Élection inexistante");
+
+        let election = self.elections(election_id).get();
+        require!(
+            self.blockchain().get_caller() == election.organizer,
+            "Seul l'organisateur peut déclarer les représentants"
+        );
+        require!(
+            self.candidates(election_id).iter().any(|candidate| candidate.id == candidate_id),
+            "Candidat inexistant"
+        );
+        require!(
+            self.represented_candidate(election_id, &representative).is_empty(),
+            "Cette adresse représente déjà un candidat"
+        );
+
+        let representative_mapper = self.candidate_representative(election_id, candidate_id);
+        if !representative_mapper.is_empty() {
+            self.represented_candidate(election_id, &representative_mapper.get()).clear();
+        }
+        representative_mapper.set(&representative);
+        self.represented_candidate(election_id, &representative).set(candidate_id);
+    }
+
+    /// Retire les cautions conservées vers le propriétaire (admin)
+    #[only_owner]
+    #[endpoint(withdrawForfeitedBonds)]
+    fn withdraw_forfeited_bonds(&self) {
+        let amount = self.forfeited_bonds().get();
+        require!(amount > 0u64, "Aucune caution à retirer");
+
+        self.forfeited_bonds().clear();
+        self.send().direct_egld(&self.blockchain().get_caller(), &amount);
+    }
+
     /// Récupère les résultats finaux on-chain d'une élection finalisée
+    ///
+    /// Disponibles uniquement une fois le délai de contestation expiré sans contestation
+    /// ouverte : un recomptage ou une annulation ne peut plus les modifier, et le contrat
+    /// results ne les importe qu'une fois définitifs.
     #[view(getFinalResults)]
     fn get_final_results(&self, election_id: u64) -> MultiValueEncoded<MultiValue2<u32, u64>> {
         let election = self.elections(election_id).get();
@@ -1214,6 +2119,14 @@ pub trait VotingContract {
             election.status == ElectionStatus::Finalized,
             "L'élection doit être finalisée"
         );
+        require!(
+            self.blockchain().get_block_timestamp() > self.get_dispute_deadline(election_id),
+            "Délai de contestation en cours"
+        );
+        require!(
+            !self.has_open_disputes(election_id),
+            "Contestation en cours d'arbitrage"
+        );
 
         let mut results_encoded = MultiValueEncoded::new();
         let final_results_mapper = self.final_results(election_id);
@@ -1225,6 +2138,59 @@ pub trait VotingContract {
         results_encoded
     }
 
+    /// Représentant déclaré d'un candidat (aucun si non déclaré)
+    #[view(getCandidateRepresentative)]
+    fn get_candidate_representative(&self, election_id: u64, candidate_id: u32) -> OptionalValue<ManagedAddress> {
+        let representative = self.candidate_representative(election_id, candidate_id);
+        if representative.is_empty() {
+            OptionalValue::None
+        } else {
+            OptionalValue::Some(representative.get())
+        }
+    }
+
+    /// Contestations déposées pour une élection
+    #[view(getDisputes)]
+    fn get_disputes(&self, election_id: u64) -> MultiValueEncoded<Dispute<Self::Api>> {
+        self.disputes(election_id).iter().collect()
+    }
+
+    /// Résultats archivés lors du recomptage `round` (1-based)
+    #[view(getArchivedResults)]
+    fn get_archived_results(&self, election_id: u64, round: u32) -> MultiValueEncoded<MultiValue2<u32, u64>> {
+        let mut results_encoded = MultiValueEncoded::new();
+        for result in self.archived_results(election_id, round).iter() {
+            results_encoded.push(MultiValue2::from((result.candidate_id, result.vote_count)));
+        }
+        results_encoded
+    }
+
+    /// Date limite de contestation d'une élection finalisée (0 si non finalisée)
+    #[view(getDisputeDeadline)]
+    fn get_dispute_deadline(&self, election_id: u64) -> u64 {
+        if self.finalized_at(election_id).is_empty() {
+            return 0;
+        }
+
+        self.finalized_at(election_id).get() + self.dispute_period().get()
+    }
+
+    /// Avancement du dépouillement on-chain (élections sans chiffrement)
+    #[view(getTallyProgress)]
+    fn get_tally_progress(&self, election_id: u64) -> TallyProgress {
+        let processed_ballots = self.tally_cursor(election_id).get();
+        let total_ballots = self.votes(election_id).len() as u64;
+        let election = self.elections(election_id).get();
+
+        TallyProgress {
+            processed_ballots,
+            total_ballots,
+            invalid_ballots: self.tally_invalid_ballots(election_id).get(),
+            is_complete: election.encryption_type == 0
+                && election.status == ElectionStatus::Finalized,
+        }
+    }
+
     /// Récupère le hash IPFS des résultats détaillés
     #[view(getResultsIpfsHash)]
     fn get_results_ipfs_hash(&self, election_id: u64) -> OptionalValue<ManagedBuffer> {
@@ -1243,6 +2209,24 @@ pub trait VotingContract {
         self.backend_verifier_address().set(address);
     }
 
+    /// Configure l'adresse du contrat voter-registry utilisé pour valider
+    /// et révoquer les tokens de vote (admin seulement)
+    #[only_owner]
+    #[endpoint(setVoterRegistry)]
+    fn set_voter_registry(&self, address: ManagedAddress) {
+        require!(
+            self.blockchain().is_smart_contract(&address),
+            "L'adresse doit être un smart contract"
+        );
+        self.voter_registry_address().set(address);
+    }
+
+    /// Obtenir l'adresse du contrat voter-registry
+    #[view(getVoterRegistry)]
+    fn get_voter_registry(&self) -> ManagedAddress {
+        self.voter_registry_address().get()
+    }
+
     /// Obtenir l'adresse du backend vérificateur zk-SNARK
     #[view(getBackendVerifier)]
     fn get_backend_verifier(&self) -> ManagedAddress {
@@ -1276,36 +2260,25 @@ pub trait VotingContract {
         result
     }
 
-    /// Fonction temporaire pour obtenir le nombre de votes par candidat
-    /// NOTE: Dans un vrai système de vote chiffré, cette fonction ne devrait
-    /// être disponible qu'après déchiffrement (après closeElection)
+    /// Obtient le nombre de votes d'un candidat à partir des résultats finaux
+    ///
+    /// Disponible uniquement après finalisation, quel que soit le mode de vote:
+    /// aucun résultat partiel n'est exposé pendant le scrutin.
     #[view(getCandidateVotes)]
     fn get_candidate_votes(&self, election_id: u64, candidate_id: u32) -> u64 {
-        let votes = self.votes(election_id);
-        let mut count = 0u64;
-
-        // Compter les votes pour ce candidat
-        // Pour la POC, le "chiffrement" est juste l'ID encodé en bytes
-        for vote in votes.iter() {
-            // Vérifier la longueur
-            if vote.encrypted_choice.len() != 4 {
-                // Ignorer les votes mal formés
-                continue;
-            }
-
-            // Utiliser copy_to_array pour extraire les 4 bytes directement
-            let mut bytes = [0u8; 4];
-            let _ = vote.encrypted_choice.load_slice(0, &mut bytes);
-
-            // Convertir 4 bytes en u32 (big-endian)
-            let voted_id = u32::from_be_bytes(bytes);
+        let election = self.elections(election_id).get();
+        require!(
+            election.status == ElectionStatus::Finalized,
+            "L'élection doit être finalisée"
+        );
 
-            if voted_id == candidate_id {
-                count += 1;
+        for result in self.final_results(election_id).iter() {
+            if result.candidate_id == candidate_id {
+                return result.vote_count;
             }
         }
 
-        count
+        sc_panic!("candidate_id invalide");
     }
 
     /// Vérifie si une adresse a déjà voté pour une élection
@@ -1320,44 +2293,74 @@ pub trait VotingContract {
         !self.registered_voters(election_id, &voter).is_empty()
     }
 
+    /// Vérifie si les inscriptions sont ouvertes pour une élection
+    #[view(isRegistrationOpen)]
+    fn is_registration_open(&self, election_id: u64) -> bool {
+        if self.elections(election_id).is_empty() {
+            return false;
+        }
+        let election = self.elections(election_id).get();
+        election.requires_registration && self.is_registration_open_for(&election)
+    }
+
+    /// Indique si l'organisateur autorise les inscriptions pendant la phase Active
+    #[view(isRegistrationAllowedDuringVoting)]
+    fn is_registration_allowed_during_voting(&self, election_id: u64) -> bool {
+        self.registration_during_voting(election_id).get()
+    }
+
     /// Obtient le nombre d'électeurs inscrits pour une élection
+    ///
+    /// # Returns
+    /// (total inscrits, adresses engagées via la racine de Merkle, inscriptions réclamées par preuve)
     #[view(getRegisteredVotersCount)]
-    fn get_registered_voters_count(&self, election_id: u64) -> u64 {
-        self.elections(election_id).get().registered_voters_count
+    fn get_registered_voters_count(&self, election_id: u64) -> MultiValue3<u64, u64, u64> {
+        MultiValue3::from((
+            self.elections(election_id).get().registered_voters_count,
+            self.whitelist_committed_count(election_id).get(),
+            self.whitelist_claimed_count(election_id).get(),
+        ))
     }
 
-    /// Récupère la liste des électeurs inscrits (avec pagination)
+    /// Récupère la racine de Merkle engagée pour la liste blanche
+    #[view(getWhitelistRoot)]
+    fn get_whitelist_root(&self, election_id: u64) -> OptionalValue<ManagedBuffer> {
+        if self.whitelist_merkle_root(election_id).is_empty() {
+            OptionalValue::None
+        } else {
+            OptionalValue::Some(self.whitelist_merkle_root(election_id).get())
+        }
+    }
+
+    /// Récupère une page de la liste des électeurs inscrits
     ///
     /// # Arguments
     /// * `election_id` - ID de l'élection
-    /// * `offset` - Position de départ
-    /// * `limit` - Nombre maximum d'adresses à retourner
+    /// * `cursor` - Position de départ (0 pour commencer)
+    /// * `limit` - Nombre maximum d'adresses à retourner (plafonné à MAX_PAGE_SIZE)
+    ///
+    /// # Returns
+    /// (curseur suivant, adresses); le curseur suivant vaut 0 en fin de liste
+    ///
+    /// # Note
+    /// Un retrait de la liste blanche déplace le dernier inscrit à la place
+    /// de l'adresse retirée: un export doit être relancé s'il a lieu pendant
+    /// le parcours.
     #[view(getRegisteredVoters)]
     fn get_registered_voters(
         &self,
         election_id: u64,
-        offset: usize,
-        limit: usize,
-    ) -> MultiValueEncoded<ManagedAddress> {
-        let mut voters = MultiValueEncoded::new();
+        cursor: u64,
+        limit: u64,
+    ) -> MultiValue2<u64, MultiValueEncoded<ManagedAddress>> {
         let registered = self.all_registered_voters(election_id);
+        let (start, end, next_cursor) = page_bounds(cursor, limit, registered.len());
 
-        let mut count = 0usize;
-        let mut index = 0usize;
-
-        for voter in registered.iter() {
-            if index >= offset {
-                if count < limit {
-                    voters.push(voter);
-                    count += 1;
-                } else {
-                    break;
-                }
-            }
-            index += 1;
+        let mut voters = MultiValueEncoded::new();
+        for index in start..end {
+            voters.push(registered.get_by_index(index + 1));
         }
-
-        voters
+        MultiValue2::from((next_cursor, voters))
     }
 
     /// Obtient les statistiques d'inscription pour une élection
@@ -1378,6 +2381,67 @@ pub trait VotingContract {
         self.registrations_per_day(election_id, day_start).get()
     }
 
+    /// Récupère les informations d'un code d'invitation à partir de son hash
+    ///
+    /// Les codes émis avant les lots sont retournés avec `batch_id` et `code_index` à 0.
+    #[view(getInvitationCode)]
+    fn get_invitation_code(&self, election_id: u64, code_hash: ManagedBuffer) -> InvitationCode<Self::Api> {
+        if !self.invitation_codes(election_id, &code_hash).is_empty() {
+            return self.invitation_codes(election_id, &code_hash).get();
+        }
+
+        require!(
+            !self.legacy_invitation_codes(election_id, &code_hash).is_empty(),
+            "Code d'invitation invalide"
+        );
+        self.legacy_invitation_codes(election_id, &code_hash).get().into()
+    }
+
+    /// Récupère un lot de codes d'invitation avec ses compteurs (utilisés, révoqués, restants)
+    #[view(getInvitationBatch)]
+    fn get_invitation_batch(&self, election_id: u64, batch_id: u64) -> InvitationBatch {
+        require!(
+            !self.invitation_batches(election_id, batch_id).is_empty(),
+            "Lot de codes inexistant"
+        );
+        self.invitation_batches(election_id, batch_id).get()
+    }
+
+    /// Nombre de lots de codes d'invitation générés pour une élection
+    #[view(getInvitationBatchCount)]
+    fn get_invitation_batch_count(&self, election_id: u64) -> u64 {
+        self.invitation_batch_counter(election_id).get()
+    }
+
+    /// Récupère une page des codes encore utilisables d'un lot (ni utilisés, ni révoqués)
+    ///
+    /// # Arguments
+    /// * `election_id` - ID de l'élection
+    /// * `batch_id` - Identifiant du lot
+    /// * `cursor` - Position de départ (0 pour commencer)
+    /// * `limit` - Nombre maximum de codes à retourner (plafonné à MAX_PAGE_SIZE)
+    ///
+    /// # Returns
+    /// (curseur suivant, codes); le curseur suivant vaut 0 en fin de liste
+    #[view(getUnusedInvitationCodes)]
+    fn get_unused_invitation_codes(
+        &self,
+        election_id: u64,
+        batch_id: u64,
+        cursor: u64,
+        limit: u64,
+    ) -> MultiValue2<u64, MultiValueEncoded<InvitationCode<Self::Api>>> {
+        let unused_codes = self.invitation_batch_unused_codes(election_id, batch_id);
+        let (start, end, next_cursor) = page_bounds(cursor, limit, unused_codes.len());
+
+        let mut result = MultiValueEncoded::new();
+        for index in start..end {
+            let code_hash = unused_codes.get_by_index(index + 1);
+            result.push(self.invitation_codes(election_id, &code_hash).get());
+        }
+        MultiValue2::from((next_cursor, result))
+    }
+
     // === STORAGE ===
 
     #[storage_mapper("electionCounter")]
@@ -1404,14 +2468,62 @@ pub trait VotingContract {
     #[storage_mapper("allRegisteredVoters")]
     fn all_registered_voters(&self, election_id: u64) -> UnorderedSetMapper<ManagedAddress>;
 
-    /// NOUVEAU: Codes d'invitation pour une élection
-    #[storage_mapper("invitationCodes")]
+    /// Codes d'invitation pour une élection, indexés par le hash du secret
+    #[storage_mapper("invitationCodesV2")]
     fn invitation_codes(
         &self,
         election_id: u64,
         code_hash: &ManagedBuffer,
     ) -> SingleValueMapper<InvitationCode<Self::Api>>;
 
+    /// Codes d'invitation émis avant les lots (ancienne disposition, lecture et inscription)
+    #[storage_mapper("invitationCodes")]
+    fn legacy_invitation_codes(
+        &self,
+        election_id: u64,
+        code: &ManagedBuffer,
+    ) -> SingleValueMapper<LegacyInvitationCode<Self::Api>>;
+
+    /// Lots de codes d'invitation
+    #[storage_mapper("invitationBatches")]
+    fn invitation_batches(&self, election_id: u64, batch_id: u64) -> SingleValueMapper<InvitationBatch>;
+
+    /// Compteur de lots de codes par élection (dernier ID attribué)
+    #[storage_mapper("invitationBatchCounter")]
+    fn invitation_batch_counter(&self, election_id: u64) -> SingleValueMapper<u64>;
+
+    /// Hashes des codes non utilisés et non révoqués, par lot
+    #[storage_mapper("invitationBatchUnusedCodes")]
+    fn invitation_batch_unused_codes(
+        &self,
+        election_id: u64,
+        batch_id: u64,
+    ) -> UnorderedSetMapper<ManagedBuffer>;
+
+    /// Racine de Merkle des adresses éligibles (import en masse)
+    #[storage_mapper("whitelistMerkleRoot")]
+    fn whitelist_merkle_root(&self, election_id: u64) -> SingleValueMapper<ManagedBuffer>;
+
+    /// Nombre d'adresses incluses dans l'arbre de Merkle engagé
+    #[storage_mapper("whitelistCommittedCount")]
+    fn whitelist_committed_count(&self, election_id: u64) -> SingleValueMapper<u64>;
+
+    /// Nombre d'inscriptions réclamées par preuve de Merkle
+    #[storage_mapper("whitelistClaimedCount")]
+    fn whitelist_claimed_count(&self, election_id: u64) -> SingleValueMapper<u64>;
+
+    /// Adresses ayant déjà réclamé leur inscription par preuve de Merkle
+    #[storage_mapper("whitelistClaimed")]
+    fn whitelist_claimed(&self, election_id: u64, voter: &ManagedAddress) -> SingleValueMapper<bool>;
+
+    /// Inscriptions explicitement fermées (closeRegistration, activation ou clôture)
+    #[storage_mapper("registrationClosed")]
+    fn registration_closed(&self, election_id: u64) -> SingleValueMapper<bool>;
+
+    /// Inscriptions autorisées pendant la phase Active (false pour les élections antérieures)
+    #[storage_mapper("registrationDuringVoting")]
+    fn registration_during_voting(&self, election_id: u64) -> SingleValueMapper<bool>;
+
     /// NOUVEAU: Timestamp de la dernière inscription (pour dashboard)
     #[storage_mapper("lastRegistrationTime")]
     fn last_registration_time(&self, election_id: u64) -> SingleValueMapper<u64>;
@@ -1432,7 +2544,11 @@ pub trait VotingContract {
     #[storage_mapper("backendVerifierAddress")]
     fn backend_verifier_address(&self) -> SingleValueMapper<ManagedAddress>;
 
-    /// === STORAGE POUR CHIFFREMENT ELGAMAL (OPTION 1) ===
+    /// Adresse du contrat voter-registry (validation et révocation des tokens)
+    #[storage_mapper("voterRegistryAddress")]
+    fn voter_registry_address(&self) -> SingleValueMapper<ManagedAddress>;
+
+    // === STORAGE POUR CHIFFREMENT ELGAMAL (OPTION 1) ===
 
     /// Clé publique ElGamal pour une élection (hex string de la clé publique secp256k1)
     #[storage_mapper("electionElGamalPublicKey")]
@@ -1450,24 +2566,76 @@ pub trait VotingContract {
     #[storage_mapper("option2Nullifiers")]
     fn option2_nullifiers(&self, election_id: u64) -> SetMapper<ManagedBuffer>;
 
-    /// Tracker pour éviter le double vote avec ElGamal (utilise le même mapper que les votes standards)
-    /// Note: voters() est déjà utilisé pour tracker tous les types de votes
+    // Tracker pour éviter le double vote avec ElGamal (utilise le même mapper que les votes standards)
+    // Note: voters() est déjà utilisé pour tracker tous les types de votes
 
     /// Compteur global de codes d'invitation générés (pour garantir l'unicité entre les batches)
     #[storage_mapper("invitationCodeCounter")]
     fn invitation_code_counter(&self) -> SingleValueMapper<u64>;
 
-    /// === STORAGE POUR LES RÉSULTATS FINAUX ===
+    // === STORAGE POUR LES RÉSULTATS FINAUX ===
 
     /// Résultats finaux on-chain (candidate_id -> vote_count)
     /// Stocké lors de la finalisation pour garantir l'immuabilité et la vérifiabilité
     #[storage_mapper("finalResults")]
     fn final_results(&self, election_id: u64) -> VecMapper<CandidateResult>;
 
+    /// Position du prochain bulletin à dépouiller (tallyPlaintext)
+    #[storage_mapper("tallyCursor")]
+    fn tally_cursor(&self, election_id: u64) -> SingleValueMapper<u64>;
+
+    /// Compteurs partiels du dépouillement on-chain, par candidat
+    #[storage_mapper("tallyCounts")]
+    fn tally_counts(&self, election_id: u64, candidate_id: u32) -> SingleValueMapper<u64>;
+
+    /// Bulletins mal formés ou pour un candidat inexistant
+    #[storage_mapper("tallyInvalidBallots")]
+    fn tally_invalid_ballots(&self, election_id: u64) -> SingleValueMapper<u64>;
+
     /// Hash IPFS des résultats détaillés (métadonnées complètes, timestamps, etc.)
     #[storage_mapper("resultsIpfsHash")]
     fn results_ipfs_hash(&self, election_id: u64) -> SingleValueMapper<ManagedBuffer>;
 
+    // === STORAGE POUR LES CONTESTATIONS ===
+
+    /// Date de la dernière finalisation (point de départ du délai de contestation)
+    #[storage_mapper("finalizedAt")]
+    fn finalized_at(&self, election_id: u64) -> SingleValueMapper<u64>;
+
+    #[storage_mapper("disputes")]
+    fn disputes(&self, election_id: u64) -> VecMapper<Dispute<Self::Api>>;
+
+    /// Résultats finaux archivés avant chaque recomptage
+    #[storage_mapper("archivedResults")]
+    fn archived_results(&self, election_id: u64, round: u32) -> VecMapper<CandidateResult>;
+
+    #[view(getRecountCount)]
+    #[storage_mapper("recountCount")]
+    fn recount_count(&self, election_id: u64) -> SingleValueMapper<u32>;
+
+    #[storage_mapper("arbitrators")]
+    fn arbitrators(&self) -> UnorderedSetMapper<ManagedAddress>;
+
+    /// Représentant déclaré d'un candidat, autorisé à contester
+    #[storage_mapper("candidateRepresentative")]
+    fn candidate_representative(&self, election_id: u64, candidate_id: u32) -> SingleValueMapper<ManagedAddress>;
+
+    /// Candidat représenté par une adresse (index inverse de `candidateRepresentative`)
+    #[storage_mapper("representedCandidate")]
+    fn represented_candidate(&self, election_id: u64, representative: &ManagedAddress) -> SingleValueMapper<u32>;
+
+    #[view(getDisputePeriod)]
+    #[storage_mapper("disputePeriod")]
+    fn dispute_period(&self) -> SingleValueMapper<u64>;
+
+    #[view(getDisputeBond)]
+    #[storage_mapper("disputeBond")]
+    fn dispute_bond(&self) -> SingleValueMapper<BigUint>;
+
+    /// Cautions des contestations rejetées, en attente de retrait
+    #[storage_mapper("forfeitedBonds")]
+    fn forfeited_bonds(&self) -> SingleValueMapper<BigUint>;
+
     // === EVENTS ===
 
     #[event("electionCreated")]
@@ -1509,9 +2677,100 @@ pub trait VotingContract {
         #[indexed] voter: &ManagedAddress,
     );
 
+    #[event("invitationCodesGenerated")]
+    fn invitation_codes_generated_event(
+        &self,
+        #[indexed] election_id: u64,
+        #[indexed] batch_id: u64,
+        count: u32,
+    );
+
+    #[event("invitationCodeRevoked")]
+    fn invitation_code_revoked_event(
+        &self,
+        #[indexed] election_id: u64,
+        #[indexed] batch_id: u64,
+        code_hash: &ManagedBuffer,
+    );
+
+    #[event("whitelistRootCommitted")]
+    fn whitelist_root_committed_event(
+        &self,
+        #[indexed] election_id: u64,
+        #[indexed] eligible_count: u64,
+        merkle_root: &ManagedBuffer,
+    );
+
+    #[event("registrationClosed")]
+    fn registration_closed_event(
+        &self,
+        #[indexed] election_id: u64,
+        #[indexed] timestamp: u64,
+        registered_voters_count: u64,
+    );
+
+    #[event("tallyProgress")]
+    fn tally_progress_event(
+        &self,
+        #[indexed] election_id: u64,
+        #[indexed] total_ballots: u64,
+        processed_ballots: u64,
+    );
+
     #[event("electionClosed")]
     fn election_closed_event(&self, #[indexed] election_id: u64, total_votes: u64);
 
     #[event("electionFinalized")]
     fn election_finalized_event(&self, #[indexed] election_id: u64, total_votes: u64);
+
+    #[event("disputeFiled")]
+    fn dispute_filed_event(
+        &self,
+        #[indexed] election_id: u64,
+        #[indexed] dispute_id: u64,
+        #[indexed] filer: &ManagedAddress,
+        #[indexed] evidence_hash: &ManagedBuffer,
+        bond: &BigUint,
+    );
+
+    #[event("disputeResolved")]
+    fn dispute_resolved_event(
+        &self,
+        #[indexed] election_id: u64,
+        #[indexed] dispute_id: u64,
+        status: DisputeStatus,
+    );
+
+    #[event("electionResultConfirmed")]
+    fn election_result_confirmed_event(&self, #[indexed] election_id: u64, #[indexed] dispute_id: u64);
+
+    #[event("electionRecountOrdered")]
+    fn election_recount_ordered_event(
+        &self,
+        #[indexed] election_id: u64,
+        #[indexed] dispute_id: u64,
+        archive_round: u32,
+    );
+
+    #[event("electionAnnulled")]
+    fn election_annulled_event(&self, #[indexed] election_id: u64, #[indexed] dispute_id: u64);
+
+    // === PROXY ===
+
+    #[proxy]
+    fn voter_registry_proxy(&self, sc_address: ManagedAddress) -> voter_registry_proxy::Proxy<Self::Api>;
+}
+
+/// Proxy pour appels cross-contract au contrat voter-registry
+mod voter_registry_proxy {
+    multiversx_sc::imports!();
+
+    #[multiversx_sc::proxy]
+    pub trait VoterRegistryProxy {
+        #[view(isTokenValid)]
+        fn is_token_valid(&self, election_id: u64, token: ManagedBuffer, signature: ManagedBuffer) -> bool;
+
+        #[endpoint(revokeToken)]
+        fn revoke_token(&self, election_id: u64, token: ManagedBuffer, signature: ManagedBuffer);
+    }
 }
@@ -1,478 +1,1419 @@
-use multiversx_sc_scenario::*;
+use multiversx_sc_scenario::imports::*;
+use multiversx_sc_scenario::multiversx_chain_vm::crypto_functions::{keccak256, sha256};
+use voter_registry::VoterRegistry;
+use voting::VotingContract;
+
+const VOTING_CODE: MxscPath = MxscPath::new("output/voting.mxsc.json");
+const VOTER_REGISTRY_CODE: MxscPath = MxscPath::new("../voter-registry/output/voter-registry.mxsc.json");
+
+const VOTING_ADDRESS: TestSCAddress = TestSCAddress::new("voting");
+const VOTER_REGISTRY_ADDRESS: TestSCAddress = TestSCAddress::new("voter-registry");
+
+const OWNER_ADDRESS: TestAddress = TestAddress::new("owner");
+const VERIFIER_ADDRESS: TestAddress = TestAddress::new("verifier");
+const VOTER_ADDRESS: TestAddress = TestAddress::new("voter");
+const VOTER1_ADDRESS: TestAddress = TestAddress::new("voter1");
+const VOTER2_ADDRESS: TestAddress = TestAddress::new("voter2");
+const VOTER3_ADDRESS: TestAddress = TestAddress::new("voter3");
+const VOTER4_ADDRESS: TestAddress = TestAddress::new("voter4");
+const VOTER5_ADDRESS: TestAddress = TestAddress::new("voter5");
+const OUTSIDER_ADDRESS: TestAddress = TestAddress::new("outsider");
+const ARBITRATOR_ADDRESS: TestAddress = TestAddress::new("arbitrator");
+const REPRESENTATIVE_ADDRESS: TestAddress = TestAddress::new("representative");
 
 fn world() -> ScenarioWorld {
     let mut blockchain = ScenarioWorld::new();
-    blockchain.register_contract("mxsc:output/voting.mxsc.json", voting::ContractBuilder);
+    blockchain.register_contract(VOTING_CODE, voting::ContractBuilder);
+    blockchain.register_contract(VOTER_REGISTRY_CODE, voter_registry::ContractBuilder);
     blockchain
 }
 
+/// Crée le compte de l'organisateur et déploie le contrat de vote
+fn deploy_voting(world: &mut ScenarioWorld) {
+    world.account(OWNER_ADDRESS).nonce(1);
+
+    world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .raw_deploy()
+        .code(VOTING_CODE)
+        .new_address(VOTING_ADDRESS)
+        .whitebox(voting::contract_obj, |sc| {
+            sc.init();
+        });
+}
+
+/// Déploie le voter-registry, le connecte au contrat de vote et configure le vérificateur
+fn setup_voter_registry(world: &mut ScenarioWorld) {
+    world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .raw_deploy()
+        .code(VOTER_REGISTRY_CODE)
+        .new_address(VOTER_REGISTRY_ADDRESS)
+        .whitebox(voter_registry::contract_obj, |sc| {
+            sc.init();
+        });
+
+    world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(VOTER_REGISTRY_ADDRESS)
+        .whitebox(voter_registry::contract_obj, |sc| {
+            sc.add_voting_contract(VOTING_ADDRESS.to_managed_address());
+            sc.set_eligibility_verifier(VERIFIER_ADDRESS.to_managed_address());
+
+            // The owner acts as the blind signature authority
+            sc.set_blind_signature_authority(
+                OWNER_ADDRESS.to_managed_address(),
+                BigUint::from(RSA_MODULUS),
+                RSA_PUBLIC_EXPONENT,
+            );
+        });
+
+    world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(VOTING_ADDRESS)
+        .whitebox(voting::contract_obj, |sc| {
+            sc.set_backend_verifier(VERIFIER_ADDRESS.to_managed_address());
+            sc.set_voter_registry(VOTER_REGISTRY_ADDRESS.to_managed_address());
+        });
+}
+
+/// Crée l'élection 1 sans inscription ni chiffrement
+fn create_election(world: &mut ScenarioWorld, start_time: u64, end_time: u64) {
+    world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(VOTING_ADDRESS)
+        .whitebox(voting::contract_obj, |sc| {
+            let election_id = sc.create_election(
+                managed_buffer!(b"Test Election"),
+                managed_buffer!(b"QmTest"),
+                start_time,
+                end_time,
+                false,
+                0u8,
+                false,
+                OptionalValue::None,
+            );
+            assert_eq!(election_id, 1u64);
+        });
+}
+
+fn add_candidates(world: &mut ScenarioWorld, election_id: u64) {
+    for (candidate_id, name) in [(1u32, &b"Candidate A"[..]), (2u32, &b"Candidate B"[..])] {
+        world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .to(VOTING_ADDRESS)
+            .whitebox(voting::contract_obj, |sc| {
+                sc.add_candidate(election_id, candidate_id, managed_buffer!(name), managed_buffer!(b"QmCandidate"));
+            });
+    }
+}
+
+fn activate_election(world: &mut ScenarioWorld, election_id: u64) {
+    world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(VOTING_ADDRESS)
+        .whitebox(voting::contract_obj, |sc| {
+            sc.activate_election(election_id);
+        });
+}
+
+fn close_election(world: &mut ScenarioWorld, election_id: u64) {
+    world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(VOTING_ADDRESS)
+        .whitebox(voting::contract_obj, |sc| {
+            sc.close_election(election_id);
+        });
+}
+
+fn tally_plaintext(world: &mut ScenarioWorld, from: TestAddress, max_ballots: u64, expected_remaining: u64) {
+    world
+        .tx()
+        .from(from)
+        .to(VOTING_ADDRESS)
+        .whitebox(voting::contract_obj, |sc| {
+            assert_eq!(sc.tally_plaintext(1u64, max_ballots), expected_remaining);
+        });
+}
+
+fn check_candidate_votes(world: &mut ScenarioWorld, candidate_id: u32, expected: u64) {
+    world
+        .query()
+        .to(VOTING_ADDRESS)
+        .whitebox(voting::contract_obj, |sc| {
+            assert_eq!(sc.get_candidate_votes(1u64, candidate_id), expected);
+        });
+}
+
+/// Vote en clair : l'ID du candidat sur 4 octets big-endian
+fn cast_plaintext_vote(world: &mut ScenarioWorld, voter: TestAddress, choice: u32, start_time: u64) {
+    let (voting_token, token_signature) = issue_voting_token(world, voter, 1u64, &voter.eval_to_array());
+
+    world
+        .tx()
+        .from(voter)
+        .to(VOTING_ADDRESS)
+        .whitebox(voting::contract_obj, |sc| {
+            sc.cast_vote(
+                1u64,
+                managed_buffer!(&voting_token),
+                managed_buffer!(&token_signature),
+                voting::EncryptedVote {
+                    encrypted_choice: managed_buffer!(&choice.to_be_bytes()[..]),
+                    proof: managed_buffer!(b"zk_snark_proof"),
+                    timestamp: start_time,
+                },
+            );
+        });
+}
+
+fn results_of(results: MultiValueEncoded<DebugApi, MultiValue2<u32, u64>>) -> Vec<(u32, u64)> {
+    results.into_iter().map(|result| result.into_tuple()).collect()
+}
+
+// Toy RSA key of the blind signature authority (n = 61 * 53)
+const RSA_MODULUS: u64 = 3233;
+const RSA_PUBLIC_EXPONENT: u64 = 17;
+const RSA_PRIVATE_EXPONENT: u64 = 2753;
+// Blinding factor r and its inverse modulo n
+const BLINDING_FACTOR: u64 = 7;
+const BLINDING_FACTOR_INVERSE: u64 = 462;
+
+fn mod_pow(mut base: u64, mut exponent: u64, modulus: u64) -> u64 {
+    let mut result = 1u64;
+    base %= modulus;
+    while exponent > 0 {
+        if exponent & 1 == 1 {
+            result = result * base % modulus;
+        }
+        base = base * base % modulus;
+        exponent >>= 1;
+    }
+    result
+}
+
+/// `H(election_id || token) mod n`, as computed by the voting contract
+fn blind_token_message(election_id: u64, token: &[u8]) -> u64 {
+    let mut data = election_id.to_be_bytes().to_vec();
+    data.extend_from_slice(token);
+    sha256(&data)
+        .iter()
+        .fold(0u64, |acc, byte| (acc * 256 + *byte as u64) % RSA_MODULUS)
+}
+
+/// Inscrit un électeur avec un token aveuglé, le fait signer par l'organisateur
+/// (autorité de signature) et retourne le token désaveuglé avec sa signature
+fn issue_voting_token(
+    world: &mut ScenarioWorld,
+    voter: TestAddress,
+    election_id: u64,
+    credential_proof: &[u8],
+) -> (Vec<u8>, Vec<u8>) {
+    let mut token = b"token:".to_vec();
+    token.extend_from_slice(credential_proof);
+
+    // Voter side: blind the message
+    let message = blind_token_message(election_id, &token);
+    let blinded_message = message * mod_pow(BLINDING_FACTOR, RSA_PUBLIC_EXPONENT, RSA_MODULUS) % RSA_MODULUS;
+
+    world
+        .tx()
+        .from(voter)
+        .to(VOTER_REGISTRY_ADDRESS)
+        .whitebox(voter_registry::contract_obj, |sc| {
+            sc.register_voter(
+                election_id,
+                managed_buffer!(credential_proof),
+                managed_buffer!(&blinded_message.to_be_bytes()[..]),
+            );
+        });
+
+    // Authority side: sign the blinded message
+    let blind_signature = mod_pow(blinded_message, RSA_PRIVATE_EXPONENT, RSA_MODULUS);
+    world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(VOTER_REGISTRY_ADDRESS)
+        .whitebox(voter_registry::contract_obj, |sc| {
+            sc.sign_blinded_token(
+                election_id,
+                managed_buffer!(&sha256(credential_proof)[..]),
+                managed_buffer!(&blind_signature.to_be_bytes()[..]),
+            );
+        });
+
+    // Voter side: unblind the signature
+    let signature = blind_signature * BLINDING_FACTOR_INVERSE % RSA_MODULUS;
+
+    (token, signature.to_be_bytes().to_vec())
+}
+
 #[test]
 fn test_init() {
     let mut world = world();
-
-    world
-        .start_trace()
-        .set_state_step(
-            SetStateStep::new()
-                .put_account("address:owner", Account::new().nonce(1))
-                .new_address("address:owner", 1, "sc:voting"),
-        )
-        .sc_deploy(
-            ScDeployStep::new()
-                .from("address:owner")
-                .contract_code("mxsc:output/voting.mxsc.json", &[])
-                .call(voting::contract_obj::<DebugApi>().init())
-                .expect(TxExpect::ok().no_result()),
-        );
+    deploy_voting(&mut world);
 }
 
 #[test]
 fn test_create_election() {
     let mut world = world();
-    let owner_address = "address:owner";
-    let voting_sc_address = "sc:voting";
-
-    // Setup
-    world
-        .start_trace()
-        .set_state_step(
-            SetStateStep::new()
-                .put_account(owner_address, Account::new().nonce(1))
-                .new_address(owner_address, 1, voting_sc_address),
-        )
-        .sc_deploy(
-            ScDeployStep::new()
-                .from(owner_address)
-                .contract_code("mxsc:output/voting.mxsc.json", &[])
-                .call(voting::contract_obj::<DebugApi>().init())
-                .expect(TxExpect::ok().no_result()),
-        );
+    deploy_voting(&mut world);
 
-    // Create candidates
     let current_timestamp = 1000u64;
     let start_time = current_timestamp + 100;
     let end_time = start_time + 3600;
 
-    world.set_state_step(
-        SetStateStep::new()
-            .block_timestamp(current_timestamp)
-    );
+    world.current_block().block_timestamp(current_timestamp);
 
     // Create election
-    world.sc_call_step(
-        ScCallStep::new()
-            .from(owner_address)
-            .to(voting_sc_address)
-            .call(voting::contract_obj::<DebugApi>()
-                .create_election(
-                    managed_buffer!(b"Presidential Election 2025"),
-                    managed_buffer!(b"QmTest123..."),
-                    start_time,
-                    end_time,
-                    ManagedVec::from_iter([
-                        voting::Candidate {
-                            id: 1,
-                            name: managed_buffer!(b"Candidate A"),
-                            description_ipfs: managed_buffer!(b"QmCandidateA"),
-                        },
-                        voting::Candidate {
-                            id: 2,
-                            name: managed_buffer!(b"Candidate B"),
-                            description_ipfs: managed_buffer!(b"QmCandidateB"),
-                        },
-                    ])
-                )
-            )
-            .expect(TxExpect::ok().result("1")),
-    );
+    world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(VOTING_ADDRESS)
+        .whitebox(voting::contract_obj, |sc| {
+            let election_id = sc.create_election(
+                managed_buffer!(b"Presidential Election 2025"),
+                managed_buffer!(b"QmTest123..."),
+                start_time,
+                end_time,
+                false,
+                0u8,
+                false,
+                OptionalValue::None,
+            );
+            assert_eq!(election_id, 1u64);
+        });
+
+    // Add candidates
+    add_candidates(&mut world, 1u64);
 
     // Verify election was created
-    world.sc_query_step(
-        ScQueryStep::new()
-            .to(voting_sc_address)
-            .call(voting::contract_obj::<DebugApi>().get_election(1u64))
-            .expect(TxExpect::ok()),
-    );
+    world
+        .query()
+        .to(VOTING_ADDRESS)
+        .whitebox(voting::contract_obj, |sc| {
+            let election = sc.get_election(1u64);
+            assert_eq!(election.title, managed_buffer!(b"Presidential Election 2025"));
+            assert_eq!(election.num_candidates, 2u32);
+        });
 }
 
 #[test]
 fn test_create_election_invalid_dates() {
     let mut world = world();
-    let owner_address = "address:owner";
-    let voting_sc_address = "sc:voting";
-
-    // Setup
-    world
-        .start_trace()
-        .set_state_step(
-            SetStateStep::new()
-                .put_account(owner_address, Account::new().nonce(1))
-                .new_address(owner_address, 1, voting_sc_address),
-        )
-        .sc_deploy(
-            ScDeployStep::new()
-                .from(owner_address)
-                .contract_code("mxsc:output/voting.mxsc.json", &[])
-                .call(voting::contract_obj::<DebugApi>().init())
-                .expect(TxExpect::ok().no_result()),
-        );
+    deploy_voting(&mut world);
 
     let current_timestamp = 1000u64;
     let end_time = current_timestamp + 100;
     let start_time = end_time + 1000; // start_time > end_time (invalid)
 
-    world.set_state_step(
-        SetStateStep::new()
-            .block_timestamp(current_timestamp)
-    );
+    world.current_block().block_timestamp(current_timestamp);
 
     // Try to create election with invalid dates
-    world.sc_call_step(
-        ScCallStep::new()
-            .from(owner_address)
-            .to(voting_sc_address)
-            .call(voting::contract_obj::<DebugApi>()
-                .create_election(
-                    managed_buffer!(b"Test Election"),
-                    managed_buffer!(b"QmTest"),
-                    start_time,
-                    end_time,
-                    ManagedVec::from_iter([
-                        voting::Candidate {
-                            id: 1,
-                            name: managed_buffer!(b"Candidate A"),
-                            description_ipfs: managed_buffer!(b"QmA"),
-                        },
-                        voting::Candidate {
-                            id: 2,
-                            name: managed_buffer!(b"Candidate B"),
-                            description_ipfs: managed_buffer!(b"QmB"),
-                        },
-                    ])
-                )
-            )
-            .expect(TxExpect::user_error("str:Dates invalides")),
-    );
+    world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(VOTING_ADDRESS)
+        .returns(ExpectError(4, "Dates invalides"))
+        .whitebox(voting::contract_obj, |sc| {
+            sc.create_election(
+                managed_buffer!(b"Test Election"),
+                managed_buffer!(b"QmTest"),
+                start_time,
+                end_time,
+                false,
+                0u8,
+                false,
+                OptionalValue::None,
+            );
+        });
 }
 
 #[test]
-fn test_create_election_insufficient_candidates() {
+fn test_create_election_invalid_encryption_type() {
     let mut world = world();
-    let owner_address = "address:owner";
-    let voting_sc_address = "sc:voting";
-
-    // Setup
-    world
-        .start_trace()
-        .set_state_step(
-            SetStateStep::new()
-                .put_account(owner_address, Account::new().nonce(1))
-                .new_address(owner_address, 1, voting_sc_address),
-        )
-        .sc_deploy(
-            ScDeployStep::new()
-                .from(owner_address)
-                .contract_code("mxsc:output/voting.mxsc.json", &[])
-                .call(voting::contract_obj::<DebugApi>().init())
-                .expect(TxExpect::ok().no_result()),
-        );
+    deploy_voting(&mut world);
 
     let current_timestamp = 1000u64;
     let start_time = current_timestamp + 100;
     let end_time = start_time + 3600;
 
-    world.set_state_step(
-        SetStateStep::new()
-            .block_timestamp(current_timestamp)
-    );
+    world.current_block().block_timestamp(current_timestamp);
 
-    // Try to create election with only 1 candidate
-    world.sc_call_step(
-        ScCallStep::new()
-            .from(owner_address)
-            .to(voting_sc_address)
-            .call(voting::contract_obj::<DebugApi>()
-                .create_election(
-                    managed_buffer!(b"Test Election"),
-                    managed_buffer!(b"QmTest"),
-                    start_time,
-                    end_time,
-                    ManagedVec::from_iter([
-                        voting::Candidate {
-                            id: 1,
-                            name: managed_buffer!(b"Candidate A"),
-                            description_ipfs: managed_buffer!(b"QmA"),
-                        },
-                    ])
-                )
-            )
-            .expect(TxExpect::user_error("str:Minimum 2 candidats requis")),
-    );
+    // Candidates are added afterwards with addCandidate; only 0, 1 and 2 are valid encryption types
+    world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(VOTING_ADDRESS)
+        .returns(ExpectError(4, "Type de chiffrement invalide (doit être 0, 1 ou 2)"))
+        .whitebox(voting::contract_obj, |sc| {
+            sc.create_election(
+                managed_buffer!(b"Test Election"),
+                managed_buffer!(b"QmTest"),
+                start_time,
+                end_time,
+                false,
+                3u8,
+                false,
+                OptionalValue::None,
+            );
+        });
 }
 
 #[test]
 fn test_activate_election() {
     let mut world = world();
-    let owner_address = "address:owner";
-    let voting_sc_address = "sc:voting";
-
-    // Setup and create election
-    world
-        .start_trace()
-        .set_state_step(
-            SetStateStep::new()
-                .put_account(owner_address, Account::new().nonce(1))
-                .new_address(owner_address, 1, voting_sc_address),
-        )
-        .sc_deploy(
-            ScDeployStep::new()
-                .from(owner_address)
-                .contract_code("mxsc:output/voting.mxsc.json", &[])
-                .call(voting::contract_obj::<DebugApi>().init())
-                .expect(TxExpect::ok().no_result()),
-        );
+    deploy_voting(&mut world);
 
     let current_timestamp = 1000u64;
     let start_time = current_timestamp + 100;
     let end_time = start_time + 3600;
 
-    world.set_state_step(
-        SetStateStep::new()
-            .block_timestamp(current_timestamp)
-    );
+    world.current_block().block_timestamp(current_timestamp);
 
-    // Create election
-    world.sc_call_step(
-        ScCallStep::new()
-            .from(owner_address)
-            .to(voting_sc_address)
-            .call(voting::contract_obj::<DebugApi>()
-                .create_election(
-                    managed_buffer!(b"Test Election"),
-                    managed_buffer!(b"QmTest"),
-                    start_time,
-                    end_time,
-                    ManagedVec::from_iter([
-                        voting::Candidate {
-                            id: 1,
-                            name: managed_buffer!(b"Candidate A"),
-                            description_ipfs: managed_buffer!(b"QmA"),
-                        },
-                        voting::Candidate {
-                            id: 2,
-                            name: managed_buffer!(b"Candidate B"),
-                            description_ipfs: managed_buffer!(b"QmB"),
-                        },
-                    ])
-                )
-            )
-            .expect(TxExpect::ok().result("1")),
-    );
+    create_election(&mut world, start_time, end_time);
+    add_candidates(&mut world, 1u64);
 
     // Advance time to start_time
-    world.set_state_step(
-        SetStateStep::new()
-            .block_timestamp(start_time)
-    );
+    world.current_block().block_timestamp(start_time);
 
-    // Activate election
-    world.sc_call_step(
-        ScCallStep::new()
-            .from(owner_address)
-            .to(voting_sc_address)
-            .call(voting::contract_obj::<DebugApi>().activate_election(1u64))
-            .expect(TxExpect::ok().no_result()),
-    );
+    activate_election(&mut world, 1u64);
 }
 
 #[test]
 fn test_cast_vote() {
     let mut world = world();
-    let owner_address = "address:owner";
-    let voter_address = "address:voter";
-    let voting_sc_address = "sc:voting";
-
-    // Setup
-    world
-        .start_trace()
-        .set_state_step(
-            SetStateStep::new()
-                .put_account(owner_address, Account::new().nonce(1))
-                .put_account(voter_address, Account::new().nonce(1))
-                .new_address(owner_address, 1, voting_sc_address),
-        )
-        .sc_deploy(
-            ScDeployStep::new()
-                .from(owner_address)
-                .contract_code("mxsc:output/voting.mxsc.json", &[])
-                .call(voting::contract_obj::<DebugApi>().init())
-                .expect(TxExpect::ok().no_result()),
-        );
+    deploy_voting(&mut world);
+    world.account(VOTER_ADDRESS).nonce(1);
+    setup_voter_registry(&mut world);
 
     let current_timestamp = 1000u64;
     let start_time = current_timestamp + 100;
     let end_time = start_time + 3600;
 
-    world.set_state_step(
-        SetStateStep::new()
-            .block_timestamp(current_timestamp)
-    );
+    world.current_block().block_timestamp(current_timestamp);
 
-    // Create election
-    world.sc_call_step(
-        ScCallStep::new()
-            .from(owner_address)
-            .to(voting_sc_address)
-            .call(voting::contract_obj::<DebugApi>()
-                .create_election(
-                    managed_buffer!(b"Test Election"),
-                    managed_buffer!(b"QmTest"),
-                    start_time,
-                    end_time,
-                    ManagedVec::from_iter([
-                        voting::Candidate {
-                            id: 1,
-                            name: managed_buffer!(b"Candidate A"),
-                            description_ipfs: managed_buffer!(b"QmA"),
-                        },
-                        voting::Candidate {
-                            id: 2,
-                            name: managed_buffer!(b"Candidate B"),
-                            description_ipfs: managed_buffer!(b"QmB"),
-                        },
-                    ])
-                )
-            )
-            .expect(TxExpect::ok().result("1")),
-    );
+    create_election(&mut world, start_time, end_time);
+
+    let (voting_token, token_signature) =
+        issue_voting_token(&mut world, VOTER_ADDRESS, 1u64, b"12345678901234567890123456789012");
 
     // Activate election
-    world.set_state_step(
-        SetStateStep::new()
-            .block_timestamp(start_time)
-    );
+    world.current_block().block_timestamp(start_time);
+    activate_election(&mut world, 1u64);
 
-    world.sc_call_step(
-        ScCallStep::new()
-            .from(owner_address)
-            .to(voting_sc_address)
-            .call(voting::contract_obj::<DebugApi>().activate_election(1u64))
-            .expect(TxExpect::ok().no_result()),
-    );
+    // A token with a forged signature is rejected
+    world
+        .tx()
+        .from(VOTER_ADDRESS)
+        .to(VOTING_ADDRESS)
+        .returns(ExpectError(4, "Token de vote invalide"))
+        .whitebox(voting::contract_obj, |sc| {
+            sc.cast_vote(
+                1u64,
+                managed_buffer!(&voting_token),
+                managed_buffer!(&[0x01, 0x02][..]),
+                voting::EncryptedVote {
+                    encrypted_choice: managed_buffer!(b"encrypted_vote_data"),
+                    proof: managed_buffer!(b"zk_snark_proof"),
+                    timestamp: start_time,
+                },
+            );
+        });
 
     // Cast vote
-    world.sc_call_step(
-        ScCallStep::new()
-            .from(voter_address)
-            .to(voting_sc_address)
-            .call(voting::contract_obj::<DebugApi>()
-                .cast_vote(
-                    1u64,
-                    managed_buffer!(b"voting_token_abc123"),
-                    voting::EncryptedVote {
-                        encrypted_choice: managed_buffer!(b"encrypted_vote_data"),
-                        proof: managed_buffer!(b"zk_snark_proof"),
-                        timestamp: start_time,
-                    }
-                )
-            )
-            .expect(TxExpect::ok().no_result()),
-    );
+    world
+        .tx()
+        .from(VOTER_ADDRESS)
+        .to(VOTING_ADDRESS)
+        .whitebox(voting::contract_obj, |sc| {
+            sc.cast_vote(
+                1u64,
+                managed_buffer!(&voting_token),
+                managed_buffer!(&token_signature),
+                voting::EncryptedVote {
+                    encrypted_choice: managed_buffer!(b"encrypted_vote_data"),
+                    proof: managed_buffer!(b"zk_snark_proof"),
+                    timestamp: start_time,
+                },
+            );
+        });
+
+    // The token has been revoked by the voting contract
+    world
+        .query()
+        .to(VOTER_REGISTRY_ADDRESS)
+        .whitebox(voter_registry::contract_obj, |sc| {
+            assert!(!sc.is_token_valid(1u64, managed_buffer!(&voting_token), managed_buffer!(&token_signature)));
+        });
 
     // Verify vote count increased
-    world.sc_query_step(
-        ScQueryStep::new()
-            .to(voting_sc_address)
-            .call(voting::contract_obj::<DebugApi>().get_total_votes(1u64))
-            .expect(TxExpect::ok().result("1")),
-    );
+    world
+        .query()
+        .to(VOTING_ADDRESS)
+        .whitebox(voting::contract_obj, |sc| {
+            assert_eq!(sc.get_total_votes(1u64), 1u64);
+        });
+}
+
+#[test]
+fn test_add_candidate_requires_sequential_ids() {
+    let mut world = world();
+    world.current_block().block_timestamp(1000);
+    deploy_voting(&mut world);
+
+    create_election(&mut world, 1100, 4700);
+
+    // The tally counts candidates 1..=n, so ids cannot be skipped
+    world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(VOTING_ADDRESS)
+        .returns(ExpectError(4, "Les IDs de candidats doivent être séquentiels à partir de 1"))
+        .whitebox(voting::contract_obj, |sc| {
+            sc.add_candidate(1u64, 2u32, managed_buffer!(b"Candidate B"), managed_buffer!(b"QmB"));
+        });
+
+    add_candidates(&mut world, 1u64);
 }
 
 #[test]
 fn test_close_election() {
     let mut world = world();
-    let owner_address = "address:owner";
-    let voting_sc_address = "sc:voting";
-
-    // Setup and create election
-    world
-        .start_trace()
-        .set_state_step(
-            SetStateStep::new()
-                .put_account(owner_address, Account::new().nonce(1))
-                .new_address(owner_address, 1, voting_sc_address),
-        )
-        .sc_deploy(
-            ScDeployStep::new()
-                .from(owner_address)
-                .contract_code("mxsc:output/voting.mxsc.json", &[])
-                .call(voting::contract_obj::<DebugApi>().init())
-                .expect(TxExpect::ok().no_result()),
-        );
+    deploy_voting(&mut world);
 
     let current_timestamp = 1000u64;
     let start_time = current_timestamp + 100;
     let end_time = start_time + 3600;
 
-    world.set_state_step(
-        SetStateStep::new()
-            .block_timestamp(current_timestamp)
-    );
+    world.current_block().block_timestamp(current_timestamp);
 
-    // Create election
-    world.sc_call_step(
-        ScCallStep::new()
-            .from(owner_address)
-            .to(voting_sc_address)
-            .call(voting::contract_obj::<DebugApi>()
-                .create_election(
-                    managed_buffer!(b"Test Election"),
-                    managed_buffer!(b"QmTest"),
-                    start_time,
-                    end_time,
-                    ManagedVec::from_iter([
-                        voting::Candidate {
-                            id: 1,
-                            name: managed_buffer!(b"Candidate A"),
-                            description_ipfs: managed_buffer!(b"QmA"),
-                        },
-                        voting::Candidate {
-                            id: 2,
-                            name: managed_buffer!(b"Candidate B"),
-                            description_ipfs: managed_buffer!(b"QmB"),
-                        },
-                    ])
-                )
-            )
-            .expect(TxExpect::ok().result("1")),
-    );
+    create_election(&mut world, start_time, end_time);
+    add_candidates(&mut world, 1u64);
 
     // Activate election
-    world.set_state_step(
-        SetStateStep::new()
-            .block_timestamp(start_time)
-    );
+    world.current_block().block_timestamp(start_time);
+    activate_election(&mut world, 1u64);
+
+    // Advance time past end_time
+    world.current_block().block_timestamp(end_time + 1);
+
+    close_election(&mut world, 1u64);
+}
+
+#[test]
+fn test_tally_plaintext_in_chunks() {
+    let mut world = world();
+    deploy_voting(&mut world);
+    for voter in [VOTER1_ADDRESS, VOTER2_ADDRESS, VOTER3_ADDRESS] {
+        world.account(voter).nonce(1);
+    }
+    setup_voter_registry(&mut world);
+
+    let current_timestamp = 1000u64;
+    let start_time = current_timestamp + 100;
+    let end_time = start_time + 3600;
+
+    world.current_block().block_timestamp(current_timestamp);
+
+    // Create plaintext election (encryption_type = 0)
+    create_election(&mut world, start_time, end_time);
+    add_candidates(&mut world, 1u64);
+
+    // Activate election
+    world.current_block().block_timestamp(start_time);
+    activate_election(&mut world, 1u64);
+
+    for (voter, choice) in [(VOTER1_ADDRESS, 1u32), (VOTER2_ADDRESS, 2u32), (VOTER3_ADDRESS, 1u32)] {
+        cast_plaintext_vote(&mut world, voter, choice, start_time);
+    }
+
+    // Results are not available while voting
+    world
+        .query()
+        .to(VOTING_ADDRESS)
+        .returns(ExpectError(4, "L'élection doit être finalisée"))
+        .whitebox(voting::contract_obj, |sc| {
+            sc.get_candidate_votes(1u64, 1u32);
+        });
+
+    // Close election
+    world.current_block().block_timestamp(end_time + 1);
+    close_election(&mut world, 1u64);
+
+    // Organizer-supplied results are rejected for plaintext elections
+    world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(VOTING_ADDRESS)
+        .returns(ExpectError(
+            4,
+            "Les élections sans chiffrement sont dépouillées on-chain via tallyPlaintext",
+        ))
+        .whitebox(voting::contract_obj, |sc| {
+            sc.finalize_election(
+                1u64,
+                managed_buffer!(b""),
+                MultiValueEncoded::from_iter([MultiValue2::from((1u32, 0u64)), MultiValue2::from((2u32, 3u64))]),
+            );
+        });
+
+    // Anyone can tally: first chunk of 2 ballots, then the remaining one
+    tally_plaintext(&mut world, VOTER1_ADDRESS, 2, 1);
+    tally_plaintext(&mut world, VOTER2_ADDRESS, 2, 0);
+
+    check_candidate_votes(&mut world, 1u32, 2);
+    check_candidate_votes(&mut world, 2u32, 1);
+}
+
+#[test]
+fn test_dispute_orders_recount() {
+    let mut world = world();
+    deploy_voting(&mut world);
+    for voter in [VOTER1_ADDRESS, VOTER2_ADDRESS, OUTSIDER_ADDRESS] {
+        world.account(voter).nonce(1).balance(1000);
+    }
+    world.account(ARBITRATOR_ADDRESS).nonce(1);
+    setup_voter_registry(&mut world);
+
+    let current_timestamp = 1000u64;
+    let start_time = current_timestamp + 100;
+    let end_time = start_time + 3600;
+
+    world.current_block().block_timestamp(current_timestamp);
+
+    // Dispute settings: 1 day window, bond of 100
+    world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(VOTING_ADDRESS)
+        .whitebox(voting::contract_obj, |sc| {
+            sc.set_dispute_settings(86_400u64, BigUint::from(100u64));
+            sc.add_arbitrator(ARBITRATOR_ADDRESS.to_managed_address());
+        });
+
+    create_election(&mut world, start_time, end_time);
+    add_candidates(&mut world, 1u64);
+
+    world.current_block().block_timestamp(start_time);
+    activate_election(&mut world, 1u64);
+
+    for (voter, choice) in [(VOTER1_ADDRESS, 1u32), (VOTER2_ADDRESS, 2u32)] {
+        cast_plaintext_vote(&mut world, voter, choice, start_time);
+    }
+
+    world.current_block().block_timestamp(end_time + 1);
+    close_election(&mut world, 1u64);
+
+    // A dispute cannot be filed before finalization
+    world
+        .tx()
+        .from(VOTER1_ADDRESS)
+        .to(VOTING_ADDRESS)
+        .egld(100)
+        .returns(ExpectError(4, "Seule une élection finalisée peut être contestée"))
+        .whitebox(voting::contract_obj, |sc| {
+            sc.file_dispute(1u64, managed_buffer!(b"evidence_hash"));
+        });
+
+    tally_plaintext(&mut world, OWNER_ADDRESS, 10, 0);
+
+    // Votes are anonymous: voter1 files as the representative of candidate 2
+    world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(VOTING_ADDRESS)
+        .whitebox(voting::contract_obj, |sc| {
+            sc.set_candidate_representative(1u64, 2u32, VOTER1_ADDRESS.to_managed_address());
+        });
+
+    // Only voters and candidate representatives can file, with the exact bond
+    world
+        .tx()
+        .from(OUTSIDER_ADDRESS)
+        .to(VOTING_ADDRESS)
+        .egld(100)
+        .returns(ExpectError(4, "Seuls les électeurs inscrits et les candidats peuvent contester"))
+        .whitebox(voting::contract_obj, |sc| {
+            sc.file_dispute(1u64, managed_buffer!(b"evidence_hash"));
+        });
+
+    world
+        .tx()
+        .from(VOTER1_ADDRESS)
+        .to(VOTING_ADDRESS)
+        .egld(50)
+        .returns(ExpectError(4, "Caution de contestation incorrecte"))
+        .whitebox(voting::contract_obj, |sc| {
+            sc.file_dispute(1u64, managed_buffer!(b"evidence_hash"));
+        });
+
+    world
+        .tx()
+        .from(VOTER1_ADDRESS)
+        .to(VOTING_ADDRESS)
+        .egld(100)
+        .whitebox(voting::contract_obj, |sc| {
+            assert_eq!(sc.file_dispute(1u64, managed_buffer!(b"evidence_hash")), 1u64);
+        });
+
+    // Only arbitrators can resolve
+    world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(VOTING_ADDRESS)
+        .returns(ExpectError(4, "Seul un arbitre peut trancher une contestation"))
+        .whitebox(voting::contract_obj, |sc| {
+            sc.resolve_dispute(1u64, 1u64, voting::DisputeResolution::OrderRecount);
+        });
+
+    world
+        .tx()
+        .from(ARBITRATOR_ADDRESS)
+        .to(VOTING_ADDRESS)
+        .whitebox(voting::contract_obj, |sc| {
+            sc.resolve_dispute(1u64, 1u64, voting::DisputeResolution::OrderRecount);
+        });
+
+    // The bond is refunded and the election goes back to Closed for a new tally
+    world.check_account(VOTER1_ADDRESS).balance(1000);
+
+    world
+        .query()
+        .to(VOTING_ADDRESS)
+        .whitebox(voting::contract_obj, |sc| {
+            assert_eq!(results_of(sc.get_archived_results(1u64, 1u32)), vec![(1, 1), (2, 1)]);
+        });
+
+    tally_plaintext(&mut world, OWNER_ADDRESS, 10, 0);
+
+    check_candidate_votes(&mut world, 1u32, 1);
+
+    // Results stay unpublished until the new dispute window has elapsed
+    world
+        .query()
+        .to(VOTING_ADDRESS)
+        .returns(ExpectError(4, "Délai de contestation en cours"))
+        .whitebox(voting::contract_obj, |sc| {
+            sc.get_final_results(1u64);
+        });
+
+    world.current_block().block_timestamp(end_time + 1 + 86_401);
+
+    world
+        .query()
+        .to(VOTING_ADDRESS)
+        .whitebox(voting::contract_obj, |sc| {
+            assert_eq!(results_of(sc.get_final_results(1u64)), vec![(1, 1), (2, 1)]);
+        });
+}
+
+#[test]
+fn test_close_finalized_or_annulled_election_fails() {
+    let mut world = world();
+    deploy_voting(&mut world);
+    world.account(VOTER1_ADDRESS).nonce(1).balance(1000);
+    world.account(ARBITRATOR_ADDRESS).nonce(1);
+    setup_voter_registry(&mut world);
+
+    let current_timestamp = 1000u64;
+    let start_time = current_timestamp + 100;
+    let end_time = start_time + 3600;
+
+    world.current_block().block_timestamp(current_timestamp);
 
-    world.sc_call_step(
-        ScCallStep::new()
-            .from(owner_address)
-            .to(voting_sc_address)
-            .call(voting::contract_obj::<DebugApi>().activate_election(1u64))
-            .expect(TxExpect::ok().no_result()),
+    world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(VOTING_ADDRESS)
+        .whitebox(voting::contract_obj, |sc| {
+            sc.set_dispute_settings(86_400u64, BigUint::from(100u64));
+            sc.add_arbitrator(ARBITRATOR_ADDRESS.to_managed_address());
+        });
+
+    create_election(&mut world, start_time, end_time);
+    add_candidates(&mut world, 1u64);
+
+    world.current_block().block_timestamp(start_time);
+    activate_election(&mut world, 1u64);
+    cast_plaintext_vote(&mut world, VOTER1_ADDRESS, 1u32, start_time);
+
+    world.current_block().block_timestamp(end_time + 1);
+    close_election(&mut world, 1u64);
+    tally_plaintext(&mut world, OWNER_ADDRESS, 10, 0);
+
+    // Closing again would re-open the tally and reset the dispute window
+    world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(VOTING_ADDRESS)
+        .returns(ExpectError(4, "Élection non active"))
+        .whitebox(voting::contract_obj, |sc| {
+            sc.close_election(1u64);
+        });
+
+    world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(VOTING_ADDRESS)
+        .whitebox(voting::contract_obj, |sc| {
+            sc.set_candidate_representative(1u64, 2u32, VOTER1_ADDRESS.to_managed_address());
+        });
+
+    world
+        .tx()
+        .from(VOTER1_ADDRESS)
+        .to(VOTING_ADDRESS)
+        .egld(100)
+        .whitebox(voting::contract_obj, |sc| {
+            assert_eq!(sc.file_dispute(1u64, managed_buffer!(b"evidence_hash")), 1u64);
+        });
+
+    world
+        .tx()
+        .from(ARBITRATOR_ADDRESS)
+        .to(VOTING_ADDRESS)
+        .whitebox(voting::contract_obj, |sc| {
+            sc.resolve_dispute(1u64, 1u64, voting::DisputeResolution::AnnulElection);
+        });
+
+    world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(VOTING_ADDRESS)
+        .returns(ExpectError(4, "Élection non active"))
+        .whitebox(voting::contract_obj, |sc| {
+            sc.close_election(1u64);
+        });
+}
+
+#[test]
+fn test_dispute_settings_validation() {
+    let mut world = world();
+    world.current_block().block_timestamp(1000);
+    deploy_voting(&mut world);
+
+    // A zero window would publish results before anyone can dispute them
+    world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(VOTING_ADDRESS)
+        .returns(ExpectError(4, "Le délai de contestation doit être non nul"))
+        .whitebox(voting::contract_obj, |sc| {
+            sc.set_dispute_settings(0u64, BigUint::from(100u64));
+        });
+
+    create_election(&mut world, 1100, 4700);
+
+    world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(VOTING_ADDRESS)
+        .whitebox(voting::contract_obj, |sc| {
+            sc.add_candidate(1u64, 1u32, managed_buffer!(b"Candidate A"), managed_buffer!(b"QmCandidate"));
+        });
+
+    // Representatives are declared for an existing candidate
+    world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(VOTING_ADDRESS)
+        .returns(ExpectError(4, "Candidat inexistant"))
+        .whitebox(voting::contract_obj, |sc| {
+            sc.set_candidate_representative(1u64, 2u32, REPRESENTATIVE_ADDRESS.to_managed_address());
+        });
+
+    world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(VOTING_ADDRESS)
+        .whitebox(voting::contract_obj, |sc| {
+            sc.set_candidate_representative(1u64, 1u32, REPRESENTATIVE_ADDRESS.to_managed_address());
+        });
+
+    world
+        .query()
+        .to(VOTING_ADDRESS)
+        .whitebox(voting::contract_obj, |sc| {
+            assert_eq!(
+                sc.get_candidate_representative(1u64, 1u32).into_option(),
+                Some(REPRESENTATIVE_ADDRESS.to_managed_address())
+            );
+        });
+}
+
+/// Déploie le contrat de vote à t=1000 et crée une élection avec inscription
+/// (début 2000, fin 5000) organisée par `owner`
+fn setup_registration_election(
+    world: &mut ScenarioWorld,
+    voters: &[TestAddress],
+    allow_registration_during_voting: bool,
+    registration_deadline: Option<u64>,
+) {
+    world.current_block().block_timestamp(1000);
+    for voter in voters {
+        world.account(*voter).nonce(1);
+    }
+    deploy_voting(world);
+
+    world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(VOTING_ADDRESS)
+        .whitebox(voting::contract_obj, |sc| {
+            let election_id = sc.create_election(
+                managed_buffer!(b"Test Election"),
+                managed_buffer!(b"QmTest"),
+                2000u64,
+                5000u64,
+                true,
+                0u8,
+                allow_registration_during_voting,
+                OptionalValue::from(registration_deadline),
+            );
+            assert_eq!(election_id, 1u64);
+        });
+}
+
+/// Hash keccak256 d'un secret d'invitation, tel que stocké par le contrat
+fn invitation_code_hash(secret: &[u8]) -> ManagedBuffer<DebugApi> {
+    managed_buffer!(&keccak256(secret)[..])
+}
+
+fn invitation_code_hashes(secrets: &[&[u8]]) -> MultiValueEncoded<DebugApi, ManagedBuffer<DebugApi>> {
+    MultiValueEncoded::from_iter(secrets.iter().map(|secret| invitation_code_hash(secret)))
+}
+
+fn register_with_invitation_code(
+    world: &mut ScenarioWorld,
+    voter: TestAddress,
+    secret: &[u8],
+    expected_error: Option<&str>,
+) {
+    let tx = world.tx().from(voter).to(VOTING_ADDRESS);
+    let call = |sc: voting::ContractObj<DebugApi>| {
+        sc.register_with_invitation_code(1u64, managed_buffer!(secret));
+    };
+
+    match expected_error {
+        Some(message) => tx.returns(ExpectError(4, message)).whitebox(voting::contract_obj, call),
+        None => tx.whitebox(voting::contract_obj, call),
+    }
+}
+
+#[test]
+fn test_invitation_code_lifecycle() {
+    let mut world = world();
+    setup_registration_election(&mut world, &[VOTER1_ADDRESS, VOTER2_ADDRESS], false, None);
+
+    world
+        .tx()
+        .from(VOTER1_ADDRESS)
+        .to(VOTING_ADDRESS)
+        .returns(ExpectError(4, "Seul l'organisateur peut générer des codes"))
+        .whitebox(voting::contract_obj, |sc| {
+            sc.generate_invitation_codes(1u64, None, invitation_code_hashes(&[b"secret-a"]));
+        });
+
+    world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(VOTING_ADDRESS)
+        .whitebox(voting::contract_obj, |sc| {
+            let code_hashes = invitation_code_hashes(&[b"secret-a", b"secret-b", b"secret-c"]);
+            let batch_id = sc.generate_invitation_codes(1u64, None, code_hashes);
+            assert_eq!(batch_id, 1u64);
+        });
+
+    // A hash can only be registered once, even across batches
+    world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(VOTING_ADDRESS)
+        .returns(ExpectError(4, "Code d'invitation déjà enregistré"))
+        .whitebox(voting::contract_obj, |sc| {
+            sc.generate_invitation_codes(1u64, None, invitation_code_hashes(&[b"secret-a"]));
+        });
+
+    // The stored hash does not redeem the code: only its preimage does
+    register_with_invitation_code(
+        &mut world,
+        VOTER1_ADDRESS,
+        &keccak256(b"secret-a"),
+        Some("Code d'invitation invalide"),
     );
 
-    // Advance time past end_time
-    world.set_state_step(
-        SetStateStep::new()
-            .block_timestamp(end_time + 1)
+    register_with_invitation_code(&mut world, VOTER1_ADDRESS, b"secret-a", None);
+
+    world
+        .query()
+        .to(VOTING_ADDRESS)
+        .whitebox(voting::contract_obj, |sc| {
+            assert!(sc.is_voter_registered(1u64, VOTER1_ADDRESS.to_managed_address()));
+        });
+
+    register_with_invitation_code(&mut world, VOTER2_ADDRESS, b"secret-a", Some("Ce code a déjà été utilisé"));
+
+    // Used codes cannot be revoked, unused ones can
+    world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(VOTING_ADDRESS)
+        .returns(ExpectError(4, "Ce code a déjà été utilisé"))
+        .whitebox(voting::contract_obj, |sc| {
+            sc.revoke_invitation_codes(1u64, invitation_code_hashes(&[b"secret-a"]));
+        });
+
+    world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(VOTING_ADDRESS)
+        .whitebox(voting::contract_obj, |sc| {
+            sc.revoke_invitation_codes(1u64, invitation_code_hashes(&[b"secret-b"]));
+        });
+
+    register_with_invitation_code(&mut world, VOTER2_ADDRESS, b"secret-b", Some("Ce code a été révoqué"));
+
+    // A second batch expiring before the vote starts
+    world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(VOTING_ADDRESS)
+        .whitebox(voting::contract_obj, |sc| {
+            let batch_id = sc.generate_invitation_codes(1u64, Some(1500u64), invitation_code_hashes(&[b"secret-d"]));
+            assert_eq!(batch_id, 2u64);
+        });
+
+    world
+        .query()
+        .to(VOTING_ADDRESS)
+        .whitebox(voting::contract_obj, |sc| {
+            assert_eq!(sc.get_invitation_batch_count(1u64), 2u64);
+        });
+
+    world.current_block().block_timestamp(1501);
+
+    register_with_invitation_code(&mut world, VOTER2_ADDRESS, b"secret-d", Some("Ce code a expiré"));
+    register_with_invitation_code(&mut world, VOTER2_ADDRESS, b"secret-c", None);
+}
+
+/// Inscrit une adresse via la liste blanche de l'organisateur
+fn add_to_whitelist(world: &mut ScenarioWorld, election_id: u64, voter: TestAddress, expected_error: Option<&str>) {
+    let tx = world.tx().from(OWNER_ADDRESS).to(VOTING_ADDRESS);
+    let call = |sc: voting::ContractObj<DebugApi>| {
+        sc.add_to_whitelist(election_id, MultiValueEncoded::from_iter([voter.to_managed_address()]));
+    };
+
+    match expected_error {
+        Some(message) => tx.returns(ExpectError(4, message)).whitebox(voting::contract_obj, call),
+        None => tx.whitebox(voting::contract_obj, call),
+    }
+}
+
+fn check_registration_open(world: &mut ScenarioWorld, expected: bool) {
+    world
+        .query()
+        .to(VOTING_ADDRESS)
+        .whitebox(voting::contract_obj, |sc| {
+            assert_eq!(sc.is_registration_open(1u64), expected);
+        });
+}
+
+#[test]
+fn test_registration_deadline_window() {
+    let mut world = world();
+    setup_registration_election(&mut world, &[VOTER1_ADDRESS, VOTER2_ADDRESS], false, Some(1500));
+
+    // Without registration during voting, the deadline must precede the start
+    world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(VOTING_ADDRESS)
+        .returns(ExpectError(4, "La date limite d'inscription doit être avant le début du vote"))
+        .whitebox(voting::contract_obj, |sc| {
+            sc.create_election(
+                managed_buffer!(b"Test Election"),
+                managed_buffer!(b"QmTest"),
+                2000u64,
+                5000u64,
+                true,
+                0u8,
+                false,
+                OptionalValue::Some(3000u64),
+            );
+        });
+
+    // With it, the deadline may fall inside the vote but not after it
+    world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(VOTING_ADDRESS)
+        .returns(ExpectError(4, "La date limite d'inscription doit être avant la fin du vote"))
+        .whitebox(voting::contract_obj, |sc| {
+            sc.create_election(
+                managed_buffer!(b"Test Election"),
+                managed_buffer!(b"QmTest"),
+                2000u64,
+                5000u64,
+                true,
+                0u8,
+                true,
+                OptionalValue::Some(5001u64),
+            );
+        });
+
+    add_to_whitelist(&mut world, 1, VOTER1_ADDRESS, None);
+    check_registration_open(&mut world, true);
+
+    // The deadline is enforced even while the election is still Pending
+    world.current_block().block_timestamp(1501);
+
+    add_to_whitelist(&mut world, 1, VOTER2_ADDRESS, Some("La date limite d'inscription est dépassée"));
+    check_registration_open(&mut world, false);
+}
+
+#[test]
+fn test_registration_during_voting() {
+    let mut world = world();
+    setup_registration_election(&mut world, &[VOTER1_ADDRESS, VOTER2_ADDRESS], true, Some(4000));
+
+    // Same window, but registration closes when the vote starts
+    world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(VOTING_ADDRESS)
+        .whitebox(voting::contract_obj, |sc| {
+            let election_id = sc.create_election(
+                managed_buffer!(b"Test Election"),
+                managed_buffer!(b"QmTest"),
+                2000u64,
+                5000u64,
+                true,
+                0u8,
+                false,
+                OptionalValue::None,
+            );
+            assert_eq!(election_id, 2u64);
+        });
+
+    world
+        .query()
+        .to(VOTING_ADDRESS)
+        .whitebox(voting::contract_obj, |sc| {
+            assert!(sc.is_registration_allowed_during_voting(1u64));
+        });
+
+    world.current_block().block_timestamp(2000);
+
+    for election_id in [1u64, 2u64] {
+        activate_election(&mut world, election_id);
+    }
+
+    add_to_whitelist(&mut world, 1, VOTER1_ADDRESS, None);
+    add_to_whitelist(&mut world, 2, VOTER1_ADDRESS, Some("Les inscriptions sont fermées"));
+
+    // Past the deadline, registration stops even though the vote goes on
+    world.current_block().block_timestamp(4001);
+
+    add_to_whitelist(&mut world, 1, VOTER2_ADDRESS, Some("La date limite d'inscription est dépassée"));
+}
+
+/// Feuille de la liste blanche: keccak256(adresse)
+fn whitelist_leaf(address: TestAddress) -> [u8; 32] {
+    keccak256(&address.eval_to_array())
+}
+
+/// Noeud de la liste blanche: keccak256(min(a, b) || max(a, b))
+fn whitelist_node(a: &[u8; 32], b: &[u8; 32]) -> [u8; 32] {
+    let (low, high) = if a <= b { (a, b) } else { (b, a) };
+    let mut data = low.to_vec();
+    data.extend_from_slice(high);
+    keccak256(&data)
+}
+
+fn merkle_proof(siblings: &[[u8; 32]]) -> MultiValueEncoded<DebugApi, ManagedBuffer<DebugApi>> {
+    MultiValueEncoded::from_iter(siblings.iter().map(|sibling| managed_buffer!(&sibling[..])))
+}
+
+fn claim_whitelist_registration(
+    world: &mut ScenarioWorld,
+    voter: TestAddress,
+    siblings: &[[u8; 32]],
+    expected_error: Option<&str>,
+) {
+    let tx = world.tx().from(voter).to(VOTING_ADDRESS);
+    let call = |sc: voting::ContractObj<DebugApi>| {
+        sc.claim_whitelist_registration(1u64, merkle_proof(siblings));
+    };
+
+    match expected_error {
+        Some(message) => tx.returns(ExpectError(4, message)).whitebox(voting::contract_obj, call),
+        None => tx.whitebox(voting::contract_obj, call),
+    }
+}
+
+#[test]
+fn test_whitelist_merkle_claim() {
+    let mut world = world();
+    setup_registration_election(&mut world, &[VOTER1_ADDRESS, VOTER2_ADDRESS, VOTER3_ADDRESS], false, None);
+
+    let leaf1 = whitelist_leaf(VOTER1_ADDRESS);
+    let leaf2 = whitelist_leaf(VOTER2_ADDRESS);
+    let root = whitelist_node(&leaf1, &leaf2);
+
+    world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(VOTING_ADDRESS)
+        .whitebox(voting::contract_obj, |sc| {
+            sc.commit_whitelist_root(1u64, managed_buffer!(&root[..]), 2u64);
+        });
+
+    // Pairs are sorted before hashing: both sides of the tree verify with a single sibling
+    claim_whitelist_registration(&mut world, VOTER1_ADDRESS, &[leaf2], None);
+    claim_whitelist_registration(&mut world, VOTER2_ADDRESS, &[leaf1], None);
+    claim_whitelist_registration(&mut world, VOTER1_ADDRESS, &[leaf2], Some("Vous êtes déjà inscrit"));
+
+    // An address outside the tree cannot reuse someone else's proof
+    claim_whitelist_registration(&mut world, VOTER3_ADDRESS, &[leaf1], Some("Preuve de Merkle invalide"));
+
+    world
+        .tx()
+        .from(VOTER3_ADDRESS)
+        .to(VOTING_ADDRESS)
+        .returns(ExpectError(4, "Preuve de Merkle invalide"))
+        .whitebox(voting::contract_obj, |sc| {
+            sc.claim_whitelist_registration(1u64, MultiValueEncoded::from_iter([managed_buffer!(&leaf1[..16])]));
+        });
+
+    // (total registered, committed, claimed)
+    world
+        .query()
+        .to(VOTING_ADDRESS)
+        .whitebox(voting::contract_obj, |sc| {
+            assert_eq!(sc.get_registered_voters_count(1u64).into_tuple(), (2u64, 2u64, 2u64));
+        });
+}
+
+#[test]
+fn test_whitelist_merkle_max_depth() {
+    let mut world = world();
+    setup_registration_election(&mut world, &[VOTER1_ADDRESS], false, None);
+
+    // Path of the maximum depth (32 siblings) from voter1's leaf to the root
+    let siblings: Vec<[u8; 32]> = (0..33u8).map(|i| [i; 32]).collect();
+    let root = siblings[..32]
+        .iter()
+        .fold(whitelist_leaf(VOTER1_ADDRESS), |node, sibling| whitelist_node(&node, sibling));
+
+    world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(VOTING_ADDRESS)
+        .whitebox(voting::contract_obj, |sc| {
+            sc.commit_whitelist_root(1u64, managed_buffer!(&root[..]), 1u64 << 32);
+        });
+
+    claim_whitelist_registration(&mut world, VOTER1_ADDRESS, &siblings, Some("Preuve de Merkle trop longue"));
+    claim_whitelist_registration(&mut world, VOTER1_ADDRESS, &siblings[..32], None);
+
+    world
+        .query()
+        .to(VOTING_ADDRESS)
+        .whitebox(voting::contract_obj, |sc| {
+            assert!(sc.is_voter_registered(1u64, VOTER1_ADDRESS.to_managed_address()));
+        });
+}
+
+fn check_registered_voters_page(
+    world: &mut ScenarioWorld,
+    cursor: u64,
+    limit: u64,
+    expected_cursor: u64,
+    expected_voters: &[TestAddress],
+) {
+    world
+        .query()
+        .to(VOTING_ADDRESS)
+        .whitebox(voting::contract_obj, |sc| {
+            let (next_cursor, voters) = sc.get_registered_voters(1u64, cursor, limit).into_tuple();
+            assert_eq!(next_cursor, expected_cursor);
+            assert_eq!(
+                voters.into_iter().collect::<Vec<_>>(),
+                expected_voters.iter().map(|voter| voter.to_managed_address()).collect::<Vec<_>>()
+            );
+        });
+}
+
+#[test]
+fn test_registered_voters_cursor_pagination() {
+    let mut world = world();
+    let voters = [VOTER1_ADDRESS, VOTER2_ADDRESS, VOTER3_ADDRESS, VOTER4_ADDRESS, VOTER5_ADDRESS];
+
+    setup_registration_election(&mut world, &voters, false, None);
+
+    world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(VOTING_ADDRESS)
+        .whitebox(voting::contract_obj, |sc| {
+            let addresses = voters.iter().map(|voter| voter.to_managed_address());
+            sc.add_to_whitelist(1u64, MultiValueEncoded::from_iter(addresses));
+        });
+
+    // Each page returns the next cursor first, then the addresses
+    check_registered_voters_page(&mut world, 0, 2, 2, &voters[0..2]);
+    check_registered_voters_page(&mut world, 2, 2, 4, &voters[2..4]);
+
+    // The last page resets the cursor to 0
+    check_registered_voters_page(&mut world, 4, 2, 0, &voters[4..]);
+
+    // A cursor past the end yields an empty final page
+    check_registered_voters_page(&mut world, 10, 2, 0, &[]);
+
+    // The page size is capped, so a huge limit still returns everything in one page here
+    check_registered_voters_page(&mut world, 0, u64::MAX, 0, &voters);
+}
+
+/// Crée et active l'élection 1 sans configurer de vérificateur de preuves
+fn setup_private_vote_election(world: &mut ScenarioWorld, public_key: Option<&[u8]>) {
+    deploy_voting(world);
+    world.account(VOTER_ADDRESS).nonce(1);
+    world.current_block().block_timestamp(1000);
+
+    create_election(world, 1100, 1100 + 3600);
+    add_candidates(world, 1u64);
+
+    if let Some(public_key) = public_key {
+        world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .to(VOTING_ADDRESS)
+            .whitebox(voting::contract_obj, |sc| {
+                sc.set_election_public_key(1u64, managed_buffer!(public_key));
+            });
+    }
+
+    world.current_block().block_timestamp(1100);
+    activate_election(world, 1u64);
+}
+
+fn set_backend_verifier(world: &mut ScenarioWorld) {
+    world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(VOTING_ADDRESS)
+        .whitebox(voting::contract_obj, |sc| {
+            sc.set_backend_verifier(VERIFIER_ADDRESS.to_managed_address());
+        });
+}
+
+fn submit_private_vote(world: &mut ScenarioWorld, nullifier: &[u8], backend_signature: &[u8], expected_error: Option<&str>) {
+    let tx = world.tx().from(VOTER_ADDRESS).to(VOTING_ADDRESS);
+    let call = |sc: voting::ContractObj<DebugApi>| {
+        sc.submit_private_vote(
+            1u64,
+            managed_buffer!(b"poseidon_commitment"),
+            managed_buffer!(nullifier),
+            managed_buffer!(backend_signature),
+        );
+    };
+    match expected_error {
+        Some(message) => tx.returns(ExpectError(4, message)).whitebox(voting::contract_obj, call),
+        None => tx.whitebox(voting::contract_obj, call),
+    }
+}
+
+#[test]
+fn test_private_vote_requires_backend_attestation() {
+    let mut world = world();
+    setup_private_vote_election(&mut world, None);
+
+    // Without a configured verifier, private votes are refused
+    submit_private_vote(&mut world, b"nullifier_1", &[1u8; 96], Some("Vérificateur de preuves non configuré"));
+
+    set_backend_verifier(&mut world);
+
+    // A missing attestation is rejected by the verifier
+    submit_private_vote(&mut world, b"nullifier_1", b"", Some("Signature backend invalide"));
+
+    submit_private_vote(&mut world, b"nullifier_1", &[1u8; 96], None);
+    submit_private_vote(
+        &mut world,
+        b"nullifier_1",
+        &[1u8; 96],
+        Some("Nullifier déjà utilisé - double vote détecté"),
     );
 
-    // Close election
-    world.sc_call_step(
-        ScCallStep::new()
-            .from(owner_address)
-            .to(voting_sc_address)
-            .call(voting::contract_obj::<DebugApi>().close_election(1u64))
-            .expect(TxExpect::ok().no_result()),
+    world
+        .query()
+        .to(VOTING_ADDRESS)
+        .whitebox(voting::contract_obj, |sc| {
+            assert_eq!(sc.get_election(1u64).total_votes, 1u64);
+        });
+}
+
+fn submit_private_vote_with_proof(world: &mut ScenarioWorld, backend_attestation: &[u8], expected_error: Option<&str>) {
+    let tx = world.tx().from(VOTER_ADDRESS).to(VOTING_ADDRESS);
+    let call = |sc: voting::ContractObj<DebugApi>| {
+        let g1 = || voting::G1Point {
+            x: managed_buffer!(b"12345678901234567890"),
+            y: managed_buffer!(b"09876543210987654321"),
+        };
+        let pi_b = voting::G2Point {
+            x1: managed_buffer!(b"11"),
+            x2: managed_buffer!(b"12"),
+            y1: managed_buffer!(b"21"),
+            y2: managed_buffer!(b"22"),
+        };
+
+        let mut public_signals = ManagedVec::new();
+        for signal in [&b"2"[..], b"c1", b"c2", b"public_key", b"nullifier_1", b"1"] {
+            public_signals.push(managed_buffer!(signal));
+        }
+
+        sc.submit_private_vote_with_proof(
+            1u64,
+            managed_buffer!(b"c1"),
+            managed_buffer!(b"c2"),
+            managed_buffer!(b"nullifier_1"),
+            g1(),
+            pi_b,
+            g1(),
+            public_signals,
+            managed_buffer!(backend_attestation),
+        );
+    };
+    match expected_error {
+        Some(message) => tx.returns(ExpectError(4, message)).whitebox(voting::contract_obj, call),
+        None => tx.whitebox(voting::contract_obj, call),
+    }
+}
+
+#[test]
+fn test_private_vote_with_proof_requires_backend_attestation() {
+    let mut world = world();
+    setup_private_vote_election(&mut world, Some(b"public_key"));
+
+    submit_private_vote_with_proof(&mut world, &[1u8; 64], Some("Vérificateur de preuves non configuré"));
+
+    set_backend_verifier(&mut world);
+
+    // The attestation must be a full Ed25519 signature
+    submit_private_vote_with_proof(&mut world, &[1u8; 32], Some("Preuve zk-SNARK invalide"));
+
+    submit_private_vote_with_proof(&mut world, &[1u8; 64], None);
+    submit_private_vote_with_proof(
+        &mut world,
+        &[1u8; 64],
+        Some("Ce nullifier a déjà été utilisé (double vote détecté)"),
     );
 }
@@ -35,6 +35,8 @@ export const InvitationCodesGeneratorModal = ({
   const [expandedCodes, setExpandedCodes] = useState<Set<number>>(new Set());
   const processedTxHashes = useRef<Set<string>>(new Set());
   const processedReturnData = useRef<Set<string>>(new Set());
+  // Secrets generated off-chain for each pending batch, revealed once the batch is confirmed
+  const pendingCodes = useRef<string[][]>([]);
 
   // Email sending state
   const [emailText, setEmailText] = useState('');
@@ -78,20 +80,10 @@ export const InvitationCodesGeneratorModal = ({
       console.log('📦 Codes Generator - Return data length:', txResult.returnData.length);
 
       try {
-        // Extract invitation codes from transaction return data
-        const codesHex: string[] = [];
+        // The contract only returns the batch id: the secrets of this batch were generated off-chain
+        const codesHex = pendingCodes.current[currentBatchIndex] ?? [];
 
-        for (const code of txResult.returnData) {
-          const codeString = typeof code === 'string' ? code : '';
-          if (!codeString) continue;
-
-          // Codes are already in hex-string format
-          if (codeString.length >= 64) {
-            codesHex.push(codeString.toLowerCase());
-          }
-        }
-
-        console.log(`✅ Codes Generator - Batch ${currentBatchIndex + 1}/${totalBatches} - Extracted ${codesHex.length} codes from transaction`);
+        console.log(`✅ Codes Generator - Batch ${currentBatchIndex + 1}/${totalBatches} confirmed with ${codesHex.length} codes`);
 
         // Accumulate codes and deduplicate
         const allCodes = [...accumulatedCodes, ...codesHex];
@@ -193,7 +185,8 @@ export const InvitationCodesGeneratorModal = ({
       // If only one batch, use the single transaction function
       if (numBatches === 1) {
         console.log('🔄 Codes Generator - Single batch, using direct method');
-        const sessionId = await generateCodes(electionId, batchSizes[0]);
+        const { sessionId, codes } = await generateCodes(electionId, batchSizes[0]);
+        pendingCodes.current = [codes];
         console.log('✅ Codes Generator - Transaction sent. Session ID:', sessionId);
 
         // Wait for indexing and get transaction hash
@@ -227,7 +220,8 @@ export const InvitationCodesGeneratorModal = ({
       } else {
         // Multiple batches - sign all transactions together
         console.log('🔄 Codes Generator - Multiple batches, signing all together');
-        const sessionId = await generateCodesBatch(electionId, batchSizes);
+        const { sessionId, codes } = await generateCodesBatch(electionId, batchSizes);
+        pendingCodes.current = codes;
         console.log('✅ Codes Generator - All transactions sent. Session ID:', sessionId);
 
         // Wait for indexing and get all transaction hashes
@@ -39,6 +39,8 @@ export const QRCodeGeneratorModal = ({
   const [accumulatedCodes, setAccumulatedCodes] = useState<string[]>([]);
   const processedTxHashes = useRef<Set<string>>(new Set());
   const processedReturnData = useRef<Set<string>>(new Set());
+  // Secrets generated off-chain for each pending batch, revealed once the batch is confirmed
+  const pendingCodes = useRef<string[][]>([]);
 
   const { result: txResult } = useTransactionWatcher(pendingTxHash);
 
@@ -77,20 +79,10 @@ export const QRCodeGeneratorModal = ({
       console.log('📦 QR Generator - Return data length:', txResult.returnData.length);
 
       try {
-        // Extract invitation codes from transaction return data
-        const codesHex: string[] = [];
+        // The contract only returns the batch id: the secrets of this batch were generated off-chain
+        const codesHex = pendingCodes.current[currentBatchIndex] ?? [];
 
-        for (const code of txResult.returnData) {
-          const codeString = typeof code === 'string' ? code : '';
-          if (!codeString) continue;
-
-          // Codes are already in hex-string format
-          if (codeString.length >= 64) {
-            codesHex.push(codeString.toLowerCase());
-          }
-        }
-
-        console.log(`✅ QR Generator - Batch ${currentBatchIndex + 1}/${totalBatches} - Extracted ${codesHex.length} codes from transaction`);
+        console.log(`✅ QR Generator - Batch ${currentBatchIndex + 1}/${totalBatches} confirmed with ${codesHex.length} codes`);
 
         // Accumulate codes and deduplicate
         const allCodes = [...accumulatedCodes, ...codesHex];
@@ -204,7 +196,8 @@ export const QRCodeGeneratorModal = ({
       // If only one batch, use the single transaction function
       if (numBatches === 1) {
         console.log('🔄 QR Generator - Single batch, using direct method');
-        const sessionId = await generateCodes(electionId, batchSizes[0]);
+        const { sessionId, codes } = await generateCodes(electionId, batchSizes[0]);
+        pendingCodes.current = [codes];
         console.log('✅ QR Generator - Transaction sent. Session ID:', sessionId);
 
         // Wait for indexing and get transaction hash
@@ -238,7 +231,8 @@ export const QRCodeGeneratorModal = ({
       } else {
         // Multiple batches - sign all transactions together
         console.log('🔄 QR Generator - Multiple batches, signing all together');
-        const sessionId = await generateCodesBatch(electionId, batchSizes);
+        const { sessionId, codes } = await generateCodesBatch(electionId, batchSizes);
+        pendingCodes.current = codes;
         console.log('✅ QR Generator - All transactions sent. Session ID:', sessionId);
 
         // Wait for indexing and get all transaction hashes
@@ -40,43 +40,44 @@ export const useGetPrivateVotesOption2 = (electionId: number | null) => {
         // Convertir l'ID en hex (u64 = 8 bytes = 16 caractères)
         const idHex = electionId.toString(16).padStart(16, '0');
 
-        const response = await fetch(`${apiUrl}/vm-values/query`, {
-          method: 'POST',
-          headers: { 'Content-Type': 'application/json' },
-          body: JSON.stringify({
-            scAddress: votingContract,
-            funcName: 'getEncryptedVotesWithProof',
-            args: [idHex]
-          })
-        });
-
-        const data = await response.json();
-
-        if (!data.data?.data?.returnData || data.data.data.returnData.length === 0) {
-          console.log('✅ No Option 2 private votes found for election:', electionId);
-          setPrivateVotesOption2([]);
-          setIsLoading(false);
-          return;
-        }
-
-        // Parser les votes Option 2
+        // Pagination par curseur : chaque page retourne le curseur suivant (0 en fin de liste)
+        const limitHex = (500).toString(16).padStart(16, '0'); // MAX_PAGE_SIZE du contrat
         const votes: PrivateVoteOption2[] = [];
-
-        for (let i = 0; i < data.data.data.returnData.length; i++) {
-          try {
-            const voteBase64 = data.data.data.returnData[i];
-            const voteHex = base64ToHex(voteBase64);
-
-            if (voteHex.length > 0) {
-              const vote = decodePrivateVoteOption2(voteHex);
-              if (vote) {
-                votes.push(vote);
+        let cursor = 0;
+
+        do {
+          const response = await fetch(`${apiUrl}/vm-values/query`, {
+            method: 'POST',
+            headers: { 'Content-Type': 'application/json' },
+            body: JSON.stringify({
+              scAddress: votingContract,
+              funcName: 'getEncryptedVotesWithProof',
+              args: [idHex, cursor.toString(16).padStart(16, '0'), limitHex]
+            })
+          });
+
+          const data = await response.json();
+          const [nextCursorBase64, ...page] = data.data?.data?.returnData || [];
+
+          // Parser les votes Option 2
+          for (const voteBase64 of page) {
+            try {
+              const voteHex = base64ToHex(voteBase64);
+
+              if (voteHex.length > 0) {
+                const vote = decodePrivateVoteOption2(voteHex);
+                if (vote) {
+                  votes.push(vote);
+                }
               }
+            } catch (parseError) {
+              console.warn('⚠️ Error parsing Option 2 private vote:', parseError);
             }
-          } catch (parseError) {
-            console.warn('⚠️ Error parsing Option 2 private vote:', parseError);
           }
-        }
+
+          const cursorHex = nextCursorBase64 ? base64ToHex(nextCursorBase64) : '';
+          cursor = cursorHex ? parseInt(cursorHex, 16) : 0;
+        } while (cursor !== 0);
 
         console.log(`✅ Fetched ${votes.length} Option 2 private votes`);
         setPrivateVotesOption2(votes);
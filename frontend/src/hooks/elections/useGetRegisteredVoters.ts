@@ -121,11 +121,11 @@ export const useGetRegisteredVoters = (electionId: number) => {
       try {
         const apiUrl = network.apiAddress;
         const allVoters: string[] = [];
-        let offset = 0;
-        const limit = 100;
+        let cursor = 0;
+        const limit = 500; // MAX_PAGE_SIZE du contrat
 
-        // Pagination pour récupérer tous les inscrits
-        while (true) {
+        // Pagination par curseur : chaque page retourne le curseur suivant (0 en fin de liste)
+        do {
           const response = await fetch(
             `${apiUrl}/vm-values/query`,
             {
@@ -136,20 +136,18 @@ export const useGetRegisteredVoters = (electionId: number) => {
                 funcName: 'getRegisteredVoters',
                 args: [
                   electionId.toString(16).padStart(16, '0'),  // election_id en hex
-                  offset.toString(16).padStart(8, '0'),        // offset en hex
-                  limit.toString(16).padStart(8, '0')          // limit en hex
+                  cursor.toString(16).padStart(16, '0'),      // cursor (u64) en hex
+                  limit.toString(16).padStart(16, '0')        // limit (u64) en hex
                 ]
               })
             }
           );
 
           const data = await response.json();
-          const returnData = data.data?.data?.returnData || [];
-
-          if (returnData.length === 0) break;
+          const [nextCursorBase64, ...addresses] = data.data?.data?.returnData || [];
 
           // Décoder chaque adresse (base64 -> hex -> bech32)
-          for (const addressBase64 of returnData) {
+          for (const addressBase64 of addresses) {
             const hex = base64ToHex(addressBase64);
             if (hex.length === 64) {  // 32 bytes = 64 hex chars
               const bech32 = decodeAddress(hex);
@@ -157,11 +155,9 @@ export const useGetRegisteredVoters = (electionId: number) => {
             }
           }
 
-          offset += limit;
-
-          // Si moins de `limit` résultats, on a tout récupéré
-          if (returnData.length < limit) break;
-        }
+          const cursorHex = nextCursorBase64 ? base64ToHex(nextCursorBase64) : '';
+          cursor = cursorHex ? parseInt(cursorHex, 16) : 0;
+        } while (cursor !== 0);
 
         console.log(`✅ ${allVoters.length} adresses récupérées pour l'élection ${electionId}`);
         setVoters(allVoters);
@@ -27,7 +27,8 @@ export const useCreateElection = () => {
     end_time: number,
     requires_registration: boolean = false,
     encryption_type: number = 0,
-    registration_deadline: number | null = null
+    registration_deadline: number | null = null,
+    allow_registration_during_voting: boolean = false
   ) => {
     try {
       // 1. Créer la factory avec l'ABI
@@ -46,7 +47,8 @@ export const useCreateElection = () => {
         start_time,             // u64
         end_time,               // u64
         requires_registration,  // bool
-        encryption_type         // u8
+        encryption_type,        // u8
+        allow_registration_during_voting  // bool (avant la deadline optionnelle)
       ];
 
       // Ajouter registration_deadline si présent (OptionalValue<u64>)
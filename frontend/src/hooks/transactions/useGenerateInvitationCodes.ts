@@ -1,3 +1,5 @@
+import { keccak_256 } from '@noble/hashes/sha3';
+import { bytesToHex, randomBytes } from '@noble/hashes/utils';
 import { votingContract } from 'config';
 import votingAbi from 'contracts/voting.abi.json';
 import { signAndSendTransactions } from 'helpers';
@@ -16,79 +18,90 @@ const GENERATE_CODES_INFO = {
   successMessage: 'Codes générés avec succès!'
 };
 
+/**
+ * Génère des secrets d'invitation off-chain.
+ * Seul le hash keccak256 de chaque secret est envoyé au contrat : les secrets
+ * (64 caractères hex) sont à distribuer aux électeurs et ne sont jamais on-chain.
+ */
+const generateInvitationSecrets = (count: number) => {
+  const secrets: string[] = [];
+  const hashes: Buffer[] = [];
+
+  for (let i = 0; i < count; i++) {
+    const secret = randomBytes(32);
+    secrets.push(bytesToHex(secret));
+    hashes.push(Buffer.from(keccak_256(secret)));
+  }
+
+  return { secrets, hashes };
+};
+
 export const useGenerateInvitationCodes = () => {
   const { network } = useGetNetworkConfig();
   const { address } = useGetAccount();
 
-  const generateCodes = async (electionId: number, count: number) => {
-    try {
-      const abi = AbiRegistry.create(votingAbi);
-      const scFactory = new SmartContractTransactionsFactory({
-        config: new TransactionsFactoryConfig({
-          chainID: network.chainId
-        }),
-        abi
-      });
+  const createGenerateTransaction = async (
+    electionId: number,
+    hashes: Buffer[],
+    expiresAt?: number
+  ) => {
+    const abi = AbiRegistry.create(votingAbi);
+    const scFactory = new SmartContractTransactionsFactory({
+      config: new TransactionsFactoryConfig({
+        chainID: network.chainId
+      }),
+      abi
+    });
 
-      const transaction = await scFactory.createTransactionForExecute(
-        new Address(address),
-        {
-          gasLimit: BigInt(30000000 + (count * 2000000)),
-          function: 'generateInvitationCodes',
-          contract: new Address(votingContract),
-          arguments: [
-            electionId,
-            count,
-            0  // batch_offset = 0 pour une génération en un seul batch
-          ]
-        }
-      );
+    return scFactory.createTransactionForExecute(
+      new Address(address),
+      {
+        gasLimit: BigInt(30000000 + (hashes.length * 2000000)),
+        function: 'generateInvitationCodes',
+        contract: new Address(votingContract),
+        arguments: [
+          electionId,
+          expiresAt ?? null,
+          ...hashes
+        ]
+      }
+    );
+  };
+
+  /**
+   * Enregistre un lot de codes. Le contrat retourne l'ID du lot ;
+   * les secrets à distribuer sont retournés par le hook.
+   */
+  const generateCodes = async (electionId: number, count: number, expiresAt?: number) => {
+    try {
+      const { secrets, hashes } = generateInvitationSecrets(count);
+      const transaction = await createGenerateTransaction(electionId, hashes, expiresAt);
 
       const sessionId = await signAndSendTransactions({
         transactions: [transaction],
         transactionsDisplayInfo: GENERATE_CODES_INFO
       });
 
-      return sessionId;
+      return { sessionId, codes: secrets };
     } catch (err) {
       console.error('Error generating invitation codes:', err);
       throw err;
     }
   };
 
-  const generateCodesBatch = async (electionId: number, batchSizes: number[]) => {
+  /**
+   * Enregistre plusieurs lots en une seule signature.
+   * `codes[i]` contient les secrets du lot `i`.
+   */
+  const generateCodesBatch = async (electionId: number, batchSizes: number[], expiresAt?: number) => {
     try {
-      const abi = AbiRegistry.create(votingAbi);
-      const scFactory = new SmartContractTransactionsFactory({
-        config: new TransactionsFactoryConfig({
-          chainID: network.chainId
-        }),
-        abi
-      });
-
-      // Créer toutes les transactions pour tous les batches
       const transactions = [];
-      let cumulativeOffset = 0;
+      const codes: string[][] = [];
 
       for (const batchSize of batchSizes) {
-        console.log(`🔍 Creating transaction with offset=${cumulativeOffset}, batchSize=${batchSize}`);
-        const transaction = await scFactory.createTransactionForExecute(
-          new Address(address),
-          {
-            gasLimit: BigInt(30000000 + (batchSize * 2000000)),
-            function: 'generateInvitationCodes',
-            contract: new Address(votingContract),
-            arguments: [
-              electionId,
-              batchSize,
-              cumulativeOffset  // Passer l'offset pour éviter les doublons
-            ]
-          }
-        );
-        console.log(`✅ Transaction created with data: ${transaction.data}`);
-        transactions.push(transaction);
-        // Incrémenter l'offset pour le prochain batch
-        cumulativeOffset += batchSize;
+        const { secrets, hashes } = generateInvitationSecrets(batchSize);
+        transactions.push(await createGenerateTransaction(electionId, hashes, expiresAt));
+        codes.push(secrets);
       }
 
       console.log(`📦 Creating ${transactions.length} transactions for batch signing`);
@@ -102,7 +115,7 @@ export const useGenerateInvitationCodes = () => {
         }
       });
 
-      return sessionId;
+      return { sessionId, codes };
     } catch (err) {
       console.error('Error generating invitation codes in batch:', err);
       throw err;
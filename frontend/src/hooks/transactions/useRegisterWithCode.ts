@@ -20,31 +20,22 @@ export const useRegisterWithCode = () => {
   const { network } = useGetNetworkConfig();
   const { address } = useGetAccount();
 
+  /**
+   * Inscription avec un code d'invitation.
+   * Le code est le secret distribué par l'organisateur (32 bytes, 64 caractères hex) :
+   * le contrat en recalcule le hash keccak256 pour retrouver le code enregistré.
+   */
   const registerWithCode = async (electionId: number, invitationCode: string) => {
     try {
-      console.log('🔐 Code reçu (hex string):', invitationCode);
-      console.log('🔐 Code length:', invitationCode.length);
-
       // Nettoyer le code: enlever espaces, tirets, etc.
       const cleanCode = invitationCode.replace(/[^0-9a-fA-F]/g, '').toLowerCase();
-      console.log('🔐 Code nettoyé:', cleanCode);
-      console.log('🔐 Code nettoyé length:', cleanCode.length);
 
-      // Vérifier que c'est bien un hash de 32 bytes (64 caractères hex)
       if (cleanCode.length !== 64) {
         throw new Error(`Code invalide: attendu 64 caractères hex, reçu ${cleanCode.length}`);
       }
 
-      // Convertir en bytes en utilisant Uint8Array au lieu de Buffer
-      const bytes = new Uint8Array(32);
-      for (let i = 0; i < 32; i++) {
-        bytes[i] = parseInt(cleanCode.substr(i * 2, 2), 16);
-      }
-
-      const codeBuffer = Buffer.from(bytes);
-      console.log('🔐 Code Buffer:', codeBuffer);
-      console.log('🔐 Code Buffer hex:', Buffer.from(codeBuffer).toString('hex'));
-      console.log('🔐 Code Buffer is Buffer?:', Buffer.isBuffer(codeBuffer));
+      // Le secret est envoyé tel quel, jamais son hash
+      const invitationSecret = Buffer.from(cleanCode, 'hex');
 
       const abi = AbiRegistry.create(votingAbi);
       const scFactory = new SmartContractTransactionsFactory({
@@ -62,13 +53,11 @@ export const useRegisterWithCode = () => {
           contract: new Address(votingContract),
           arguments: [
             electionId,
-            codeBuffer
+            invitationSecret
           ]
         }
       );
 
-      console.log('🔐 Transaction créée, data:', transaction.data ? transaction.data.toString() : 'N/A');
-
       const sessionId = await signAndSendTransactions({
         transactions: [transaction],
         transactionsDisplayInfo: REGISTER_WITH_CODE_INFO
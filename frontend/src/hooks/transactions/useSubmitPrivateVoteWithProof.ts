@@ -37,6 +37,11 @@ export interface SubmitPrivateVoteWithProofParams {
   electionId: number;
   candidateId: number;
   numCandidates: number;
+  /**
+   * Fait attester la preuve par le vérificateur backend, qui retourne sa
+   * signature Ed25519 (64 bytes, hex). Le contrat refuse le vote sans elle.
+   */
+  getBackendAttestation?: (proof: EncryptedVoteProof) => Promise<string>;
 }
 
 export interface SubmitPrivateVoteWithProofResult {
@@ -112,7 +117,13 @@ export function useSubmitPrivateVoteWithProof(electionId: number | null) {
         hasProof: !!proof.proof,
       });
 
-      // 6. Créer la transaction blockchain
+      // 6. Faire attester la preuve par le vérificateur
+      if (!params.getBackendAttestation) {
+        throw new Error('Attestation du vérificateur indisponible : le contrat refuse les votes avec preuve non attestés');
+      }
+      const backendAttestation = Buffer.from(await params.getBackendAttestation(proof), 'hex');
+
+      // 7. Créer la transaction blockchain
       console.log('🔨 Préparation de la transaction blockchain...');
 
       const abi = AbiRegistry.create(votingAbi);
@@ -154,7 +165,7 @@ export function useSubmitPrivateVoteWithProof(electionId: number | null) {
         y2: pi_b[1][1],
       };
 
-      // 7. Créer la transaction
+      // 8. Créer la transaction
       const transaction = await scFactory.createTransactionForExecute(
         new Address(address),
         {
@@ -170,6 +181,7 @@ export function useSubmitPrivateVoteWithProof(electionId: number | null) {
             pi_b_encoded,
             pi_c_encoded,
             proof.publicSignals,
+            backendAttestation,
           ]
         }
       );
@@ -178,7 +190,7 @@ export function useSubmitPrivateVoteWithProof(electionId: number | null) {
       console.log('📄 Transaction data:', transaction.data ? transaction.data.toString() : 'N/A');
       console.log('⛽ Transaction gas limit:', transaction.gasLimit ? transaction.gasLimit.toString() : 'N/A');
 
-      // 8. Signer et envoyer la transaction
+      // 9. Signer et envoyer la transaction
       console.log('✍️ Signature et envoi de la transaction...');
 
       const sessionId = await signAndSendTransactions({
@@ -16,13 +16,53 @@ const VOTE_INFO = {
   successMessage: 'Vote enregistré avec succès!'
 };
 
+/**
+ * Token de vote anonyme : signé en aveugle par l'autorité du voter-registry
+ * puis désaveuglé par l'électeur (valeurs hex)
+ */
+export interface VotingCredential {
+  token: string;
+  signature: string;
+}
+
+const votingCredentialKey = (electionId: number, walletAddress: string) =>
+  `democratix_voting_credential_${walletAddress}_${electionId}`;
+
+/**
+ * Sauvegarde le token désaveuglé et sa signature après l'émission par le voter-registry
+ */
+export const saveVotingCredential = (
+  electionId: number,
+  walletAddress: string,
+  credential: VotingCredential
+): void => {
+  localStorage.setItem(votingCredentialKey(electionId, walletAddress), JSON.stringify(credential));
+};
+
+/**
+ * Récupère le token de vote de l'électeur pour une élection, s'il a été émis
+ */
+export const loadVotingCredential = (
+  electionId: number,
+  walletAddress: string
+): VotingCredential | null => {
+  try {
+    const stored = localStorage.getItem(votingCredentialKey(electionId, walletAddress));
+    return stored ? JSON.parse(stored) : null;
+  } catch (error) {
+    console.error('❌ Failed to load voting credential:', error);
+    return null;
+  }
+};
+
 export const useVote = () => {
   const { network } = useGetNetworkConfig();
   const { address } = useGetAccount();
 
   const castVote = async (
     electionId: number,
-    candidateId: number
+    candidateId: number,
+    credential: VotingCredential
   ) => {
     console.log('🗳️ ========== START VOTING ==========');
     console.log('🗳️ Election ID:', electionId);
@@ -47,8 +87,9 @@ export const useVote = () => {
 
       // 2. Préparer les arguments pour castVote
       console.log('📦 Step 2: Preparing arguments');
-      const votingToken = 'mock_token_' + Date.now();
-      console.log('🎫 Voting token:', votingToken);
+      // Le voter-registry vérifie la signature RSA de l'autorité sur le token, puis le consomme
+      const votingToken = Buffer.from(credential.token, 'hex');
+      const tokenSignature = Buffer.from(credential.signature, 'hex');
 
       // Encoder le candidateId en 4 bytes (u32 big-endian) pour correspondre au format attendu par le SC
       const candidateIdBytes = new Uint8Array(4);
@@ -85,7 +126,6 @@ export const useVote = () => {
 
       // 3. Créer la transaction
       console.log('🔨 Step 3: Creating transaction');
-      console.log('🔨 Arguments array:', [electionId, votingToken, encryptedVote]);
 
       const transaction = await scFactory.createTransactionForExecute(
         new Address(address),
@@ -96,6 +136,7 @@ export const useVote = () => {
           arguments: [
             electionId,      // u64
             votingToken,     // ManagedBuffer (voting_token)
+            tokenSignature,  // ManagedBuffer (token_signature)
             encryptedVote    // EncryptedVote (structure avec champs nommés)
           ]
         }
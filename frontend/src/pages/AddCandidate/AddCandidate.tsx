@@ -35,7 +35,7 @@ export const AddCandidate = () => {
         const election = await getElection(parseInt(electionId));
         if (election) {
           // L'ID du prochain candidat = nombre actuel de candidats
-          setNextCandidateId(election.num_candidates);
+          setNextCandidateId(election.num_candidates + 1);
         }
       } catch (error) {
         console.error('Erreur lors de la récupération de l\'élection:', error);
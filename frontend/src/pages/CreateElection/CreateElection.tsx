@@ -422,7 +422,7 @@ export const CreateElection = () => {
 
           // Ajouter le candidat à l'élection
           console.log(`Ajout candidat ${i + 1} à l'élection #${nextElectionId}...`);
-          await addCandidate(nextElectionId, i, candidate.name, candidateIpfsHash);
+          await addCandidate(nextElectionId, i + 1, candidate.name, candidateIpfsHash);
           console.log(`Candidat ${i + 1} ajouté avec succès à l'élection #${nextElectionId}!`);
 
           updateStep(stepId, 'completed', t('createElection.progress.successfullyAdded'));
@@ -1,4 +1,4 @@
-import { useEffect, useState } from 'react';
+import { useEffect, useRef, useState } from 'react';
 import { useParams, useNavigate } from 'react-router-dom';
 import { useTranslation } from 'react-i18next';
 import { useGetAccount, useGetNetworkConfig } from 'lib';
@@ -184,6 +184,8 @@ export const ElectionDetail = () => {
   // Invitation codes
   const [invitationCodeCount, setInvitationCodeCount] = useState('1');
   const [generatedCodes, setGeneratedCodes] = useState<string[]>([]);
+  // Secrets générés off-chain, affichés une fois la transaction confirmée
+  const pendingCodes = useRef<string[]>([]);
   const [showInvitationSection, setShowInvitationSection] = useState(false);
   const [showCodesModal, setShowCodesModal] = useState(false);
 
@@ -372,8 +374,11 @@ export const ElectionDetail = () => {
         }
 
         // Méthode standard pour élections non-finalisées
+        // getCandidateVotes n'expose aucun résultat partiel : le contrat le rejette
+        // tant que l'élection n'est pas finalisée
+        const isFinalized = election.status === 'Finalized';
         const votesPromises = candidates.map(async (candidate) => {
-          const standardVotes = await getCandidateVotes(election.id, candidate.id);
+          const standardVotes = isFinalized ? await getCandidateVotes(election.id, candidate.id) : 0;
           // Add ElGamal decrypted votes if available
           const elgamalVotes = elgamalDecryptedVotes?.[candidate.id] || 0;
           const totalVotes = standardVotes + elgamalVotes;
@@ -460,26 +465,11 @@ export const ElectionDetail = () => {
       console.log('📦 Données retournées:', txResult.returnData);
 
       try {
-        // Le smart contract retourne un MultiValueEncoded
-        // Le hook useTransactionWatcher a déjà extrait et décodé les codes
-        // returnData contient directement les codes en format hex-string
-        const codesHex: string[] = [];
+        // Le smart contract ne retourne que l'ID du lot : les secrets ont été générés off-chain
+        const codesHex = pendingCodes.current;
+        pendingCodes.current = [];
 
-        for (const code of txResult.returnData) {
-          const codeString = typeof code === 'string' ? code : '';
-
-          if (!codeString) continue;
-
-          // Les codes sont déjà en format hex-string, on les garde tels quels
-          if (codeString.length >= 64) {
-            codesHex.push(codeString.toLowerCase());
-          }
-        }
-
-        console.log(`✅ ${codesHex.length} codes extraits avec succès`);
-        if (codesHex.length > 0) {
-          console.log('📋 Premier code:', codesHex[0]);
-        }
+        console.log(`✅ ${codesHex.length} codes confirmés on-chain`);
 
         setGeneratedCodes(codesHex);
         setShowTxSentModal(false); // Fermer la modale de chargement
@@ -758,7 +748,8 @@ export const ElectionDetail = () => {
     }
 
     try {
-      const sessionId = await generateCodes(election.id, count);
+      const { sessionId, codes } = await generateCodes(election.id, count);
+      pendingCodes.current = codes;
       console.log('✅ Transaction envoyée. Session ID:', sessionId);
 
       // Afficher la belle modale de confirmation
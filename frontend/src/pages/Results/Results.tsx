@@ -305,8 +305,11 @@ export const Results = () => {
         // Méthode standard pour élections non-finalisées
         console.log('🔍 Fetching votes with elgamalDecryptedVotes state:', elgamalDecryptedVotes);
 
+        // getCandidateVotes n'expose aucun résultat partiel : le contrat le rejette
+        // tant que l'élection n'est pas finalisée
+        const isFinalized = electionData.status === 'Finalized';
         const votesPromises = candidates.map(async (candidate) => {
-          const standardVotes = await getCandidateVotes(electionId, candidate.id);
+          const standardVotes = isFinalized ? await getCandidateVotes(electionId, candidate.id) : 0;
           // Add ElGamal decrypted votes if available
           const elgamalVotes = elgamalDecryptedVotes?.[candidate.id] || 0;
           const totalVotes = standardVotes + elgamalVotes;
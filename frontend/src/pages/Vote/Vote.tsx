@@ -2,7 +2,7 @@ import { useState, useEffect } from 'react';
 import { useParams, useNavigate } from 'react-router-dom';
 import { useTranslation } from 'react-i18next';
 import { RouteNamesEnum } from 'localConstants';
-import { useVote, useSubmitPrivateVote, useSubmitEncryptedVote, useSubmitPrivateVoteWithProof } from 'hooks/transactions';
+import { useVote, loadVotingCredential, useSubmitPrivateVote, useSubmitEncryptedVote, useSubmitPrivateVoteWithProof } from 'hooks/transactions';
 import { useGetElection, useGetCandidates, useHasVoted, useHasVotedPrivately, useCandidateMetadata, useIPFSImage, useIsVoterRegistered, useGetElectionPublicKey, type Election, type Candidate } from 'hooks/elections';
 import { ConfirmModal, PrivateVoteModal } from 'components';
 import { useGetAccount, useGetNetworkConfig } from 'lib';
@@ -546,8 +546,15 @@ export const Vote = () => {
 
       // Appeler le smart contract avec l'ID de l'élection et l'ID du candidat
       // Le hook useVote gère le chiffrement et la création de la preuve
+      // Le vote exige le token signé en aveugle par le voter-registry lors de l'inscription
+      const credential = loadVotingCredential(electionId, address);
+      if (!credential) {
+        alert(t('vote.missingVotingToken'));
+        return;
+      }
+
       console.log('📞 Calling castVote hook...');
-      const result = await castVote(electionId, selectedCandidate!);
+      const result = await castVote(electionId, selectedCandidate!, credential);
       console.log('✅ castVote returned:', result);
 
       console.log('🎉 Vote submitted successfully!');